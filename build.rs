@@ -0,0 +1,86 @@
+//! 从 `icons/` 目录下的 SVG 文件生成图标常量
+//!
+//! 目录结构与 `@ant-design/icons-svg` 一致：`icons/{outlined,filled,twotone}/<name>.svg`，
+//! 每个文件生成一个常量，例如 `icons/outlined/check-circle.svg` 生成
+//! `CHECK_CIRCLE_OUTLINED`。双色图标中填充为 `#333` 的路径使用主色，
+//! `#E6E6E6`/`#D9D9D9` 的路径使用辅色。
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+/// `(目录, 主题变体, 常量后缀)`
+const THEMES: [(&str, &str, &str); 3] = [
+    ("outlined", "Outlined", "OUTLINED"),
+    ("filled", "Filled", "FILLED"),
+    ("twotone", "TwoTone", "TWO_TONE"),
+];
+
+fn attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(" {}=\"", name);
+    let start = tag.find(&pattern)? + pattern.len();
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+fn path_fill(fill: Option<&str>) -> &'static str {
+    match fill.map(str::to_ascii_uppercase).as_deref() {
+        Some("#333" | "#333333") => "Primary",
+        Some("#E6E6E6" | "#D9D9D9") => "Secondary",
+        _ => "Current",
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=icons");
+
+    let mut out = String::new();
+    for (dir, theme, suffix) in THEMES {
+        let Ok(entries) = fs::read_dir(Path::new("icons").join(dir)) else {
+            continue;
+        };
+        let mut files: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "svg"))
+            .collect();
+        files.sort();
+
+        for file in files {
+            let name = file
+                .file_stem()
+                .expect("icon file name")
+                .to_string_lossy()
+                .into_owned();
+            let svg = fs::read_to_string(&file).expect("readable icon file");
+            let view_box = attr(&svg, "viewBox").unwrap_or("64 64 896 896");
+
+            let mut paths = String::new();
+            for tag in svg.split("<path").skip(1) {
+                let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+                let Some(d) = attr(tag, "d") else {
+                    continue;
+                };
+                write!(
+                    paths,
+                    "IconPath {{ d: {:?}, fill: PathFill::{} }}, ",
+                    d,
+                    path_fill(attr(tag, "fill"))
+                )
+                .unwrap();
+            }
+
+            let const_name = format!("{}_{}", name.replace('-', "_").to_uppercase(), suffix);
+            writeln!(
+                out,
+                "/// `{name}` icon, {dir} theme\n\
+                 pub const {const_name}: IconDefinition = IconDefinition {{ \
+                 name: {name:?}, theme: IconTheme::{theme}, view_box: {view_box:?}, \
+                 paths: &[{paths}] }};",
+            )
+            .unwrap();
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("icons.rs"), out).expect("writable OUT_DIR");
+}
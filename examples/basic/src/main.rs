@@ -1,39 +1,130 @@
 use ant_leptos::components::{
+    button::{Button, ButtonHtmlType, ButtonType},
     config_provider::{ConfigProvider, ThemeMode},
+    form::{use_form_field, Form, FormItem, FormLayout, FormList, Rule, RuleType, ValidateTrigger},
+    icon::{icons::SEARCH_OUTLINED, Icon},
     locale::{Language, LocaleProvider},
-    style::{Style, StyleManager},
-    theme::Theme,
+    modal::{use_modal, ConfirmConfig, Modal},
+    style::StyleManager,
+    theme::{use_stored_theme_mode, Theme},
     version::VERSION,
 };
-use leptos::prelude::*;
+use leptos::{ev::MouseEvent, prelude::*};
+use serde_json::{json, Value};
+
+const THEME_STORAGE_KEY: &str = "ant-leptos-theme";
+
+/// 演示用的文本输入框，读写所在表单项的值
+#[component]
+fn TextInput(#[prop(optional, into)] placeholder: String) -> impl IntoView {
+    let field = use_form_field();
+    let value = move || {
+        field
+            .and_then(|field| field.value().as_str().map(str::to_string))
+            .unwrap_or_default()
+    };
+    view! {
+        <input
+            id=field.map(|field| field.id())
+            placeholder=placeholder
+            prop:value=value
+            on:input=move |event| {
+                if let Some(field) = field {
+                    field.set_value(Value::String(event_target_value(&event)));
+                }
+            }
+            on:blur=move |_| {
+                if let Some(field) = field {
+                    field.blur();
+                }
+            }
+        />
+    }
+}
+
+/// 演示对话框与可等待的确认框
+#[component]
+fn ModalDemo() -> impl IntoView {
+    let open = RwSignal::new(false);
+    let saving = RwSignal::new(false);
+    let deleted = RwSignal::new(0);
+    let modal = use_modal();
+
+    let save = move |_| {
+        saving.set(true);
+        set_timeout(
+            move || {
+                saving.set(false);
+                open.set(false);
+            },
+            std::time::Duration::from_secs(1),
+        );
+    };
+    let delete = move |_: MouseEvent| {
+        leptos::task::spawn_local(async move {
+            let confirmed = modal
+                .confirm(
+                    ConfirmConfig::new("Delete this item?")
+                        .with_content("This cannot be undone.")
+                        .with_ok_danger(),
+                )
+                .await;
+            if confirmed {
+                deleted.update(|count| *count += 1);
+            }
+        });
+    };
+
+    view! {
+        <div class="button-group">
+            <Button button_type=ButtonType::Primary on_click=Callback::new(move |_: MouseEvent| open.set(true))>
+                "Open modal"
+            </Button>
+            <Button danger=true on_click=Callback::new(delete)>
+                "Delete"
+            </Button>
+            <p>"Deleted: " {deleted}</p>
+        </div>
+        <Modal
+            open=open
+            title=|| "Basic modal"
+            draggable=true
+            confirm_loading=saving
+            on_ok=Callback::new(save)
+            on_cancel=Callback::new(move |_| open.set(false))
+        >
+            <p>"Drag the title to move the dialog, press Esc or click the mask to close it."</p>
+        </Modal>
+    }
+}
 
 #[component]
 fn App() -> impl IntoView {
     // 初始化全局样式
     StyleManager::init_global_style();
 
-    // 创建主题状态
-    let (theme_mode, set_theme_mode) = create_signal(ThemeMode::Light);
+    // 创建主题状态，从 localStorage 恢复
+    let theme_mode = use_stored_theme_mode(THEME_STORAGE_KEY, ThemeMode::System);
     // 创建语言状态
-    let (language, set_language) = create_signal(Language::ZhCN);
+    let (language, set_language) = signal(Language::ZhCN);
 
     // 切换主题的处理函数
-    let toggle_theme = move |_| {
-        set_theme_mode.update(|mode| {
+    let toggle_theme = move |_: MouseEvent| {
+        theme_mode.update(|mode| {
             *mode = match mode {
                 ThemeMode::Light => ThemeMode::Dark,
-                ThemeMode::Dark => ThemeMode::Light,
-                ThemeMode::Compact => ThemeMode::Light,
+                ThemeMode::Dark => ThemeMode::System,
+                ThemeMode::Compact | ThemeMode::System => ThemeMode::Light,
             }
         });
     };
 
     // 切换语言的处理函数
-    let toggle_language = move |_| {
+    let toggle_language = move |_: MouseEvent| {
         set_language.update(|lang| {
             *lang = match lang {
                 Language::ZhCN => Language::EnUS,
-                Language::EnUS => Language::ZhCN,
+                _ => Language::ZhCN,
             }
         });
     };
@@ -44,7 +135,7 @@ fn App() -> impl IntoView {
             // 国际化配置
             <LocaleProvider current_language=language>
                 // 主题配置
-                <Theme theme_mode=theme_mode>
+                <Theme theme_mode=theme_mode storage_key=THEME_STORAGE_KEY>
                     <div class="demo-container">
                         <h1>"Ant Leptos Demo"</h1>
 
@@ -57,17 +148,19 @@ fn App() -> impl IntoView {
                         <div>
                             <h2>"Theme Demo"</h2>
                             <div class="button-group">
-                                <button class="ant-btn ant-btn-primary">"Primary Button"</button>
-                                <button
-                                    class="ant-btn"
-                                    on:click=toggle_theme
+                                <Button
+                                    button_type=ButtonType::Primary
+                                    icon=|| view! { <Icon icon=SEARCH_OUTLINED /> }
                                 >
+                                    "Primary Button"
+                                </Button>
+                                <Button on_click=Callback::new(toggle_theme)>
                                     "Switch to " {move || match theme_mode.get() {
                                         ThemeMode::Light => "Dark",
-                                        ThemeMode::Dark => "Light",
-                                        ThemeMode::Compact => "Compact",
+                                        ThemeMode::Dark => "System",
+                                        ThemeMode::Compact | ThemeMode::System => "Light",
                                     }} " Theme"
-                                </button>
+                                </Button>
                             </div>
                         </div>
 
@@ -77,19 +170,94 @@ fn App() -> impl IntoView {
                             <div class="button-group">
                                 <p>"Current language: " {move || match language.get() {
                                     Language::ZhCN => "中文",
-                                    Language::EnUS => "English",
+                                    _ => "English",
                                 }}</p>
-                                <button
-                                    class="ant-btn"
-                                    on:click=toggle_language
-                                >
+                                <Button on_click=Callback::new(toggle_language)>
                                     "Switch to " {move || match language.get() {
                                         Language::ZhCN => "English",
-                                        Language::EnUS => "中文",
+                                        _ => "中文",
                                     }}
-                                </button>
+                                </Button>
                             </div>
                         </div>
+
+                        // 表单演示
+                        <div>
+                            <h2>"Form Demo"</h2>
+                            <Form
+                                name="signup"
+                                layout=FormLayout::Vertical
+                                validate_debounce=300
+                                on_finish=Callback::new(|values: Value| leptos::logging::log!("{}", values))
+                            >
+                                <FormItem
+                                    name="email"
+                                    label="Email"
+                                    rules=vec![
+                                        Rule::required(),
+                                        Rule::type_of(RuleType::Email).with_trigger([ValidateTrigger::Blur]),
+                                    ]
+                                >
+                                    <TextInput placeholder="name@example.com" />
+                                </FormItem>
+                                <FormItem
+                                    name="password"
+                                    label="Password"
+                                    rules=vec![
+                                        Rule::range(6.0, 16.0).with_required(),
+                                        Rule::min(10.0).with_warning_only().with_message("A longer password is safer"),
+                                    ]
+                                >
+                                    <TextInput />
+                                </FormItem>
+                                <FormItem
+                                    name="confirm"
+                                    label="Confirm password"
+                                    dependencies=vec!["password".into()]
+                                    rules=vec![
+                                        Rule::required(),
+                                        Rule::validator(|value, values| async move {
+                                            if value == values["password"] {
+                                                Ok(())
+                                            } else {
+                                                Err("The passwords do not match".to_string())
+                                            }
+                                        }),
+                                    ]
+                                >
+                                    <TextInput />
+                                </FormItem>
+                                <FormList name="tags" initial_value=vec![json!("")] let:list>
+                                    {list
+                                        .fields
+                                        .into_iter()
+                                        .map(|field| {
+                                            let index = field.index;
+                                            view! {
+                                                <FormItem name=field.name label=format!("Tag {}", index + 1) rules=vec![Rule::required()]>
+                                                    <TextInput />
+                                                    <Button on_click=Callback::new(move |_: MouseEvent| list.operations.remove(index))>
+                                                        "Remove"
+                                                    </Button>
+                                                </FormItem>
+                                            }
+                                        })
+                                        .collect_view()}
+                                    <Button on_click=Callback::new(move |_: MouseEvent| list.operations.add(json!("")))>
+                                        "Add tag"
+                                    </Button>
+                                </FormList>
+                                <Button button_type=ButtonType::Primary html_type=ButtonHtmlType::Submit>
+                                    "Submit"
+                                </Button>
+                            </Form>
+                        </div>
+
+                        // 对话框演示
+                        <div>
+                            <h2>"Modal Demo"</h2>
+                            <ModalDemo />
+                        </div>
                     </div>
                 </Theme>
             </LocaleProvider>
@@ -0,0 +1,446 @@
+//! 按钮组件
+//!
+//! 支持 primary/default/dashed/text/link 五种类型，危险、幽灵、块级、圆形与
+//! 圆角形状，加载状态（可延迟显示）和图标。设置 `href` 时渲染为 `<a>`。
+//! 尺寸与禁用状态从 [`SizeContextProvider`] 和 [`DisabledContextProvider`]
+//! 继承，按钮自身的属性优先。
+//!
+//! [`SizeContextProvider`]: crate::components::config_provider::SizeContextProvider
+//! [`DisabledContextProvider`]: crate::components::config_provider::DisabledContextProvider
+
+mod wave;
+
+use crate::components::config_provider::{
+    get_component_cls, get_prefix_cls, use_config, use_direction, use_merged_disabled,
+    use_merged_size, ComponentSize, Config, Direction,
+};
+use crate::components::icon::{icons::LOADING_OUTLINED, Icon};
+use crate::styles::apply_button_style;
+use leptos::ev::MouseEvent;
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use wave::{show_wave, WaveTiming};
+
+/// Button type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ButtonType {
+    /// Main action, filled with the primary color
+    Primary,
+    /// Secondary action
+    #[default]
+    Default,
+    /// Default button with a dashed border
+    Dashed,
+    /// Borderless button with a background on hover
+    Text,
+    /// Button looking like a link
+    Link,
+}
+
+impl ButtonType {
+    /// Class suffix of the type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ButtonType::Primary => "primary",
+            ButtonType::Default => "default",
+            ButtonType::Dashed => "dashed",
+            ButtonType::Text => "text",
+            ButtonType::Link => "link",
+        }
+    }
+
+    /// Whether the type has no border, which disables the wave and ghost styles
+    pub fn is_borderless(&self) -> bool {
+        matches!(self, ButtonType::Text | ButtonType::Link)
+    }
+}
+
+/// Button shape
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ButtonShape {
+    /// Rounded rectangle
+    #[default]
+    Default,
+    /// Circle, for icon-only buttons
+    Circle,
+    /// Fully rounded ends
+    Round,
+}
+
+/// Native `type` attribute of the button element
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ButtonHtmlType {
+    /// Plain button
+    #[default]
+    Button,
+    /// Submits its form
+    Submit,
+    /// Resets its form
+    Reset,
+}
+
+impl ButtonHtmlType {
+    /// Value of the `type` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ButtonHtmlType::Button => "button",
+            ButtonHtmlType::Submit => "submit",
+            ButtonHtmlType::Reset => "reset",
+        }
+    }
+}
+
+/// Whether a text is exactly two Chinese characters
+///
+/// Such button labels get a space between the characters, e.g. `确 定`.
+pub fn is_two_cn_char(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!(
+        (chars.next(), chars.next(), chars.next()),
+        (Some(first), Some(second), None)
+            if is_cn_char(first) && is_cn_char(second)
+    )
+}
+
+fn is_cn_char(ch: char) -> bool {
+    ('\u{4e00}'..='\u{9fa5}').contains(&ch)
+}
+
+/// Text of rendered HTML, with tags and hydration markers removed
+#[cfg(feature = "ssr")]
+fn html_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Check the content text after mount and whenever it changes
+#[cfg(not(feature = "ssr"))]
+fn watch_content(content_ref: NodeRef<leptos::html::Span>, two_cn_chars: RwSignal<bool>) {
+    use wasm_bindgen::closure::Closure;
+    use web_sys::{MutationObserver, MutationObserverInit};
+
+    let check = move |content: &web_sys::HtmlSpanElement| {
+        let text = content.text_content().unwrap_or_default();
+        two_cn_chars.set(is_two_cn_char(text.trim()));
+    };
+
+    let observer = StoredValue::new_local(None::<(MutationObserver, Closure<dyn Fn()>)>);
+    Effect::new(move |_| {
+        let Some(content) = content_ref.get() else {
+            return;
+        };
+        check(&content);
+
+        let callback = {
+            let content = content.clone();
+            Closure::<dyn Fn()>::new(move || check(&content))
+        };
+        let Ok(mutation_observer) = MutationObserver::new(callback.as_ref().unchecked_ref()) else {
+            return;
+        };
+        let options = MutationObserverInit::new();
+        options.set_character_data(true);
+        options.set_child_list(true);
+        options.set_subtree(true);
+        if mutation_observer
+            .observe_with_options(&content, &options)
+            .is_ok()
+        {
+            observer.update_value(|observer| {
+                if let Some((previous, _)) = observer.replace((mutation_observer, callback)) {
+                    previous.disconnect();
+                }
+            });
+        }
+    });
+    on_cleanup(move || {
+        observer.update_value(|observer| {
+            if let Some((observer, _)) = observer.take() {
+                observer.disconnect();
+            }
+        });
+    });
+}
+
+/// 按钮组件
+#[component]
+pub fn Button(
+    /// 按钮类型
+    #[prop(optional, into)]
+    button_type: Signal<ButtonType>,
+    /// 原生 `type` 属性
+    #[prop(optional)]
+    html_type: ButtonHtmlType,
+    /// 危险按钮
+    #[prop(optional, into)]
+    danger: Signal<bool>,
+    /// 幽灵按钮，背景透明
+    #[prop(optional, into)]
+    ghost: Signal<bool>,
+    /// 宽度撑满父元素
+    #[prop(optional, into)]
+    block: Signal<bool>,
+    /// 按钮形状
+    #[prop(optional, into)]
+    shape: Signal<ButtonShape>,
+    /// 按钮尺寸，未设置时继承上层尺寸
+    #[prop(optional, into)]
+    size: Option<Signal<ComponentSize>>,
+    /// 禁用状态，未设置时继承上层禁用状态
+    #[prop(optional, into)]
+    disabled: Option<Signal<bool>>,
+    /// 加载状态，加载时忽略点击
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+    /// 延迟显示加载状态的毫秒数，避免请求很快结束时闪烁
+    #[prop(optional)]
+    loading_delay: u32,
+    /// 图标
+    #[prop(optional, into)]
+    icon: Option<ViewFn>,
+    /// 链接地址，设置后渲染为 `<a>`
+    #[prop(optional, into)]
+    href: Option<String>,
+    /// 链接的 `target` 属性
+    #[prop(optional, into)]
+    target: Option<String>,
+    /// 是否在两个汉字之间插入空格，未设置时使用 `Config.auto_insert_space_in_button`
+    #[prop(optional)]
+    auto_insert_space: Option<bool>,
+    /// 额外的类名
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// 点击回调，例如 `on_click=Callback::new(move |event| ...)`
+    #[prop(optional, into)]
+    on_click: Option<Callback<MouseEvent>>,
+    /// 按钮内容
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let hash_cls = apply_button_style();
+    let cls = get_component_cls("btn");
+    let prefix_cls = get_prefix_cls().unwrap_or_else(|| Config::default().prefix_cls);
+    let config = use_config();
+    let direction = use_direction();
+    let size = use_merged_size(size);
+    let disabled = use_merged_disabled(disabled);
+    let auto_insert_space = Signal::derive(move || {
+        auto_insert_space.unwrap_or_else(|| {
+            config.is_none_or(|config| config.with(|config| config.auto_insert_space_in_button))
+        })
+    });
+
+    // 延迟显示加载状态
+    let inner_loading = RwSignal::new(loading_delay == 0 && loading.get_untracked());
+    let delay_timeout = StoredValue::new(None::<TimeoutHandle>);
+    Effect::new(move |_| {
+        let loading = loading.get();
+        if let Some(handle) = delay_timeout.get_value() {
+            handle.clear();
+            delay_timeout.set_value(None);
+        }
+        if loading && loading_delay > 0 {
+            let handle = set_timeout_with_handle(
+                move || inner_loading.set(true),
+                Duration::from_millis(loading_delay.into()),
+            );
+            delay_timeout.set_value(handle.ok());
+        } else {
+            inner_loading.set(loading);
+        }
+    });
+    on_cleanup(move || {
+        if let Some(handle) = delay_timeout.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+
+    // 内容是否为两个汉字：服务端根据渲染出的内容判断，客户端挂载后检查，
+    // 内容变化时重新检查
+    let content_ref = NodeRef::<leptos::html::Span>::new();
+    let two_cn_chars = RwSignal::new(false);
+    #[cfg(not(feature = "ssr"))]
+    watch_content(content_ref, two_cn_chars);
+
+    let has_icon = icon.is_some();
+    let has_children = children.is_some();
+    let is_link = href.is_some();
+    let insert_space = move || {
+        auto_insert_space.get()
+            && two_cn_chars.get()
+            && !has_icon
+            && !inner_loading.get()
+            && !button_type.get().is_borderless()
+    };
+
+    let class = {
+        let cls = cls.clone();
+        move || {
+            let button_type = button_type.get();
+            let mut classes = vec![
+                cls.clone(),
+                hash_cls.get(),
+                format!("{}-{}", cls, button_type.as_str()),
+            ];
+            match shape.get() {
+                ButtonShape::Circle => classes.push(format!("{}-circle", cls)),
+                ButtonShape::Round => classes.push(format!("{}-round", cls)),
+                ButtonShape::Default => {}
+            }
+            match size.get() {
+                ComponentSize::Small => classes.push(format!("{}-sm", cls)),
+                ComponentSize::Large => classes.push(format!("{}-lg", cls)),
+                ComponentSize::Middle => {}
+            }
+            let flags = [
+                (
+                    !has_children && (has_icon || inner_loading.get()),
+                    "icon-only",
+                ),
+                (danger.get(), "dangerous"),
+                (
+                    ghost.get() && !button_type.is_borderless(),
+                    "background-ghost",
+                ),
+                (block.get(), "block"),
+                (inner_loading.get(), "loading"),
+                (insert_space(), "two-chinese-chars"),
+                (is_link && disabled.get(), "disabled"),
+                (
+                    direction.is_some_and(|direction| direction.get() == Direction::Rtl),
+                    "rtl",
+                ),
+            ];
+            classes.extend(
+                flags
+                    .into_iter()
+                    .filter(|(enabled, _)| *enabled)
+                    .map(|(_, suffix)| format!("{}-{}", cls, suffix)),
+            );
+            classes.extend(class.clone());
+            classes.join(" ")
+        }
+    };
+
+    let handle_click = move |event: MouseEvent| {
+        if inner_loading.get_untracked() || disabled.get_untracked() {
+            event.prevent_default();
+            return;
+        }
+        if !button_type.get_untracked().is_borderless() {
+            let timing = config
+                .map(|config| {
+                    config.with_untracked(|config| WaveTiming::new(&config.component_token))
+                })
+                .unwrap_or_else(|| WaveTiming::new(&Config::default().component_token));
+            if let Some(target) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            {
+                show_wave(&target, &prefix_cls, &timing);
+            }
+        }
+        if let Some(on_click) = on_click {
+            on_click.run(event);
+        }
+    };
+
+    let icon_view = {
+        let cls = cls.clone();
+        move || {
+            if inner_loading.get() {
+                Some(
+                    view! {
+                        <span class=format!("{0}-icon {0}-loading-icon", cls)>
+                            <Icon icon=LOADING_OUTLINED />
+                        </span>
+                    }
+                    .into_any(),
+                )
+            } else {
+                icon.as_ref().map(|icon| {
+                    view! { <span class=format!("{}-icon", cls)>{icon.run()}</span> }.into_any()
+                })
+            }
+        }
+    };
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            // 先把内容渲染成 HTML，按钮的类名才能在输出前确定
+            let content = children.map(|children| {
+                let html = children().to_html();
+                two_cn_chars.set(is_two_cn_char(html_text(&html).trim()));
+                view! { <span node_ref=content_ref inner_html=html></span> }
+            });
+        } else {
+            let content =
+                children.map(|children| view! { <span node_ref=content_ref>{children()}</span> });
+        }
+    }
+
+    match href {
+        Some(href) => view! {
+            <a
+                class=class
+                href=move || (!disabled.get()).then(|| href.clone())
+                target=target
+                aria-disabled=move || disabled.get().then_some("true")
+                tabindex=move || disabled.get().then_some("-1")
+                on:click=handle_click
+            >
+                {icon_view}
+                {content}
+            </a>
+        }
+        .into_any(),
+        None => view! {
+            <button
+                type=html_type.as_str()
+                class=class
+                disabled=move || disabled.get()
+                on:click=handle_click
+            >
+                {icon_view}
+                {content}
+            </button>
+        }
+        .into_any(),
+    }
+}
+
+// 客户端检查依赖 DOM，只在服务端下测试
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_text_drops_tags_and_markers() {
+        assert_eq!(html_text("确定"), "确定");
+        assert_eq!(html_text("<b>确</b><!>定"), "确定");
+        assert_eq!(html_text("<span class=\"a\">OK</span>"), "OK");
+    }
+
+    #[test]
+    fn server_render_spaces_two_chinese_chars() {
+        let owner = Owner::new();
+        let (spaced, plain) = owner.with(|| {
+            (
+                view! { <Button>"确定"</Button> }.to_html(),
+                view! { <Button>"确定吗"</Button> }.to_html(),
+            )
+        });
+        assert!(spaced.contains("ant-btn-two-chinese-chars"), "{spaced}");
+        assert!(spaced.contains(">确定</span>"), "{spaced}");
+        assert!(!plain.contains("ant-btn-two-chinese-chars"), "{plain}");
+    }
+}
@@ -0,0 +1,68 @@
+//! 点击波纹效果
+//!
+//! 点击时在目标元素内插入一个绝对定位的波纹元素，通过 CSS 过渡扩散并淡出，
+//! 过渡结束后移除。时长取自 `Config.component_token` 的动画参数。
+
+use crate::components::config_provider::ComponentToken;
+use crate::utils::dom::document;
+use leptos::prelude::set_timeout;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Durations of the wave transitions, in milliseconds
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct WaveTiming {
+    /// Duration of the shadow spreading out
+    pub spread: u32,
+    /// Duration of the wave fading out
+    pub fade: u32,
+    /// Easing of both transitions
+    pub easing: String,
+}
+
+impl WaveTiming {
+    /// Derive the wave timing from the motion settings
+    pub fn new(motion: &ComponentToken) -> Self {
+        let spread = motion.motion_duration_mid * 2;
+        Self {
+            spread,
+            fade: spread + motion.motion_base * 4,
+            easing: motion.motion_ease_out.clone(),
+        }
+    }
+
+    fn style(&self) -> String {
+        format!(
+            "transition-duration:{}ms,{}ms;transition-timing-function:{}",
+            self.spread, self.fade, self.easing
+        )
+    }
+}
+
+/// Show a click wave inside `target`
+///
+/// Does nothing when there is no DOM, e.g. during server rendering.
+pub(crate) fn show_wave(target: &Element, prefix_cls: &str, timing: &WaveTiming) {
+    let Some(wave) = document().and_then(|document| document.create_element("span").ok()) else {
+        return;
+    };
+    let wave_cls = format!("{}-wave", prefix_cls);
+    wave.set_class_name(&wave_cls);
+    let _ = wave.set_attribute("style", &timing.style());
+    if target.append_child(&wave).is_err() {
+        return;
+    }
+
+    // 强制重排，让过渡从初始状态开始
+    if let Some(element) = wave.dyn_ref::<HtmlElement>() {
+        let _ = element.offset_width();
+    }
+    let _ = wave.class_list().add_1(&format!("{}-active", wave_cls));
+
+    let duration = timing.spread.max(timing.fade);
+    set_timeout(
+        move || wave.remove(),
+        Duration::from_millis(duration.into()),
+    );
+}
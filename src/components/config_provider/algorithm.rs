@@ -1,4 +1,6 @@
-use super::theme::{ComponentToken, ThemeToken};
+use super::context::ANT_PREFIX;
+use super::theme::{typed_component, ComponentToken, ThemeConfig, ThemeToken};
+use serde_json::Value;
 use std::collections::HashMap;
 
 /// CSS variables generator
@@ -11,6 +13,8 @@ pub struct CSSVariablesGenerator {
     pub selector: String,
     /// Prefix for variables
     pub prefix: String,
+    /// Theme used to resolve typed component tokens
+    pub theme: Option<ThemeConfig>,
 }
 
 impl CSSVariablesGenerator {
@@ -20,7 +24,8 @@ impl CSSVariablesGenerator {
             token,
             components: HashMap::new(),
             selector: ":root".to_string(),
-            prefix: "ant".to_string(),
+            prefix: ANT_PREFIX.to_string(),
+            theme: None,
         }
     }
 
@@ -42,6 +47,13 @@ impl CSSVariablesGenerator {
         self
     }
 
+    /// Use a theme's component tokens, algorithms and overrides
+    pub fn with_theme(mut self, theme: &ThemeConfig) -> Self {
+        self.components = theme.components.clone();
+        self.theme = Some(theme.clone());
+        self
+    }
+
     /// Generate CSS variables
     pub fn generate(&self) -> String {
         let mut css = String::new();
@@ -52,114 +64,97 @@ impl CSSVariablesGenerator {
         // 生成组件变量
         css.push_str(&self.generate_component_vars());
 
-        // 生成暗色主题变量
-        css.push_str(&self.generate_dark_theme_vars());
-
-        // 生成紧凑主题变量
-        css.push_str(&self.generate_compact_theme_vars());
-
         css
     }
 
     fn generate_global_vars(&self) -> String {
-        format!(
-            r#"
-            {} {{
-                --{}-primary-color: {};
-                --{}-success-color: {};
-                --{}-warning-color: {};
-                --{}-error-color: {};
-                --{}-font-size-base: {};
-                --{}-border-radius-base: {};
-                --{}-line-height: {};
-                --{}-background: {};
-                --{}-text-color: {};
-                --{}-border-color: {};
-                --{}-box-shadow: {};
-                --{}-disabled-opacity: {};
-                --{}-disabled-bg: {};
-                --{}-disabled-color: {};
-
-                /* 派生变量 */
-                --{}-primary-color-hover: {};
-                --{}-primary-color-active: {};
-                --{}-primary-color-outline: {};
-                --{}-primary-1: {};
-                --{}-primary-2: {};
-                --{}-primary-3: {};
-                --{}-primary-4: {};
-                --{}-primary-5: {};
-                --{}-primary-6: {};
-                --{}-primary-7: {};
-                --{}-primary-8: {};
-                --{}-primary-9: {};
-                --{}-primary-10: {};
-            }}
-            "#,
-            self.selector,
-            self.prefix,
-            self.token.primary_color,
-            self.prefix,
-            self.token.success_color,
-            self.prefix,
-            self.token.warning_color,
-            self.prefix,
-            self.token.error_color,
-            self.prefix,
-            self.token.font_size_base,
-            self.prefix,
-            self.token.border_radius_base,
-            self.prefix,
-            self.token.line_height,
-            self.prefix,
-            self.token.background,
-            self.prefix,
-            self.token.text_color,
-            self.prefix,
-            self.token.border_color,
-            self.prefix,
-            self.token.box_shadow,
-            self.prefix,
-            self.token.disabled_opacity,
-            self.prefix,
-            self.token.disabled_bg,
-            self.prefix,
-            self.token.disabled_color,
+        let map = &self.token.map;
+        let alias = &self.token.alias;
+        let colors = &map.colors;
+
+        let mut vars = vec![
+            ("primary-color".to_string(), colors.color_primary.clone()),
+            ("info-color".to_string(), colors.color_info.clone()),
+            ("success-color".to_string(), colors.color_success.clone()),
+            ("warning-color".to_string(), colors.color_warning.clone()),
+            ("error-color".to_string(), colors.color_error.clone()),
+            (
+                "font-size-base".to_string(),
+                format!("{}px", map.font.font_size),
+            ),
+            (
+                "border-radius-base".to_string(),
+                format!("{}px", map.common.border_radius),
+            ),
+            ("background".to_string(), colors.color_bg_container.clone()),
+            ("text-color".to_string(), colors.color_text.clone()),
+            ("border-color".to_string(), colors.color_border.clone()),
+            (
+                "disabled-bg".to_string(),
+                alias.color_bg_container_disabled.clone(),
+            ),
+            (
+                "disabled-color".to_string(),
+                alias.color_text_disabled.clone(),
+            ),
             // 派生变量
-            self.prefix,
-            self.generate_hover_color(&self.token.primary_color),
-            self.prefix,
-            self.generate_active_color(&self.token.primary_color),
-            self.prefix,
-            self.generate_outline_color(&self.token.primary_color),
-            // 色板变量
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 1),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 2),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 3),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 4),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 5),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 6),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 7),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 8),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 9),
-            self.prefix,
-            self.generate_palette_color(&self.token.primary_color, 10),
-        )
+            (
+                "primary-color-hover".to_string(),
+                colors.color_primary_hover.clone(),
+            ),
+            (
+                "primary-color-active".to_string(),
+                colors.color_primary_active.clone(),
+            ),
+            (
+                "primary-color-outline".to_string(),
+                alias.control_outline.clone(),
+            ),
+        ];
+
+        // 色板变量
+        let palette = [
+            &colors.color_primary_bg,
+            &colors.color_primary_bg_hover,
+            &colors.color_primary_border,
+            &colors.color_primary_border_hover,
+            &colors.color_primary_hover,
+            &colors.color_primary,
+            &colors.color_primary_active,
+            &colors.color_primary_text_hover,
+            &colors.color_primary_text,
+            &colors.color_primary_text_active,
+        ];
+        for (index, color) in palette.into_iter().enumerate() {
+            vars.push((format!("primary-{}", index + 1), color.clone()));
+        }
+
+        // 令牌变量
+        for (key, value) in self.token.flatten() {
+            if let Some(value) = css_value(&key, value) {
+                vars.push((token_to_css_var(&key), value));
+            }
+        }
+
+        let mut css = format!("\n{} {{\n", self.selector);
+        for (name, value) in vars {
+            css.push_str(&format!("    --{}-{}: {};\n", self.prefix, name, value));
+        }
+        css.push_str("}\n");
+        css
     }
 
     fn generate_component_vars(&self) -> String {
         let mut css = String::new();
+        let theme = ThemeConfig {
+            components: self.components.clone(),
+            ..self.theme.clone().unwrap_or_default()
+        }
+        .with_seed(&self.token.seed);
 
-        for (component, token) in &self.components {
+        let mut components: Vec<_> = self.components.iter().collect();
+        components.sort_by(|a, b| a.0.cmp(b.0));
+        for (component, token) in components {
             css.push_str(&format!(
                 r#"
                 /* {} Component Variables */
@@ -168,11 +163,27 @@ impl CSSVariablesGenerator {
                 component, self.selector
             ));
 
-            for (key, value) in &token.token {
-                css.push_str(&format!(
-                    "    --{}-{}-{}: {};\n",
-                    self.prefix, component, key, value
-                ));
+            // 已知组件输出完整的组件令牌，其余组件只输出覆盖值
+            let values = typed_component(component)
+                .map(|typed| (typed.values)(&theme, &self.token))
+                .unwrap_or_else(|| token.token.clone());
+            let mut chars = component.chars();
+            let component_var = chars
+                .next()
+                .map(|first| {
+                    token_to_css_var(&format!("{}{}", first.to_ascii_lowercase(), chars.as_str()))
+                })
+                .unwrap_or_default();
+            for (key, value) in values {
+                if let Some(value) = css_value(&key, value) {
+                    css.push_str(&format!(
+                        "    --{}-{}-{}: {};\n",
+                        self.prefix,
+                        component_var,
+                        token_to_css_var(&key),
+                        value
+                    ));
+                }
             }
 
             css.push_str("}\n");
@@ -180,87 +191,61 @@ impl CSSVariablesGenerator {
 
         css
     }
+}
 
-    fn generate_dark_theme_vars(&self) -> String {
-        format!(
-            r#"
-            [data-theme='dark'] {{
-                --{}-body-background: #141414;
-                --{}-component-background: #1f1f1f;
-                --{}-text-color: rgba(255, 255, 255, 0.85);
-                --{}-text-color-secondary: rgba(255, 255, 255, 0.45);
-                --{}-border-color-base: #434343;
-                --{}-border-color-split: #303030;
-                --{}-background-color-light: rgba(255, 255, 255, 0.04);
-                --{}-background-color-base: rgba(255, 255, 255, 0.08);
-            }}
-            "#,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix
-        )
-    }
-
-    fn generate_compact_theme_vars(&self) -> String {
-        format!(
-            r#"
-            [data-theme='compact'] {{
-                --{}-font-size-base: 12px;
-                --{}-font-size-lg: 14px;
-                --{}-font-size-sm: 12px;
-                --{}-padding-lg: 16px;
-                --{}-padding-md: 12px;
-                --{}-padding-sm: 8px;
-                --{}-padding-xs: 4px;
-                --{}-border-radius-base: 2px;
-            }}
-            "#,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix,
-            self.prefix
-        )
-    }
-
-    // 颜色处理辅助函数
-    fn generate_hover_color(&self, color: &str) -> String {
-        // 实现颜色变亮逻辑
-        format!("color-mix(in srgb, {}, white 15%)", color)
+/// Convert a camelCase token name to its kebab-case CSS variable name
+///
+/// Follows antd's naming, e.g. `colorPrimaryBg` → `color-primary-bg`,
+/// `sizeXXL` → `size-xxl` and `blue6` → `blue-6`.
+pub fn token_to_css_var(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    let mut name = String::with_capacity(token.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let boundary = if c.is_ascii_uppercase() {
+                // fooBar / XLMin
+                prev.is_ascii_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_ascii_uppercase()
+                        && next.is_some_and(|n| n.is_ascii_lowercase() || n.is_ascii_digit()))
+            } else {
+                // blue6 / heading1
+                c.is_ascii_digit() && prev.is_ascii_lowercase()
+            };
+            if boundary {
+                name.push('-');
+            }
+        }
+        name.push(c.to_ascii_lowercase());
     }
 
-    fn generate_active_color(&self, color: &str) -> String {
-        // 实现颜色变暗逻辑
-        format!("color-mix(in srgb, {}, black 15%)", color)
-    }
+    name
+}
 
-    fn generate_outline_color(&self, color: &str) -> String {
-        // 实现轮廓颜色逻辑
-        format!("color-mix(in srgb, {}, transparent 80%)", color)
-    }
+/// Format a token value for a CSS variable, skipping values CSS can't hold
+fn css_value(key: &str, value: Value) -> Option<String> {
+    let value = match value {
+        Value::String(value) => value,
+        Value::Number(number) if is_unitless(key) => number.to_string(),
+        Value::Number(number) => format!("{}px", number.as_f64().unwrap_or_default()),
+        Value::Bool(_) | Value::Null | Value::Array(_) | Value::Object(_) => return None,
+    };
+    (!value.is_empty()).then_some(value)
+}
 
-    fn generate_palette_color(&self, color: &str, index: u8) -> String {
-        // 实现色板颜色生成逻辑
-        match index {
-            1 => format!("color-mix(in srgb, {}, white 90%)", color),
-            2 => format!("color-mix(in srgb, {}, white 80%)", color),
-            3 => format!("color-mix(in srgb, {}, white 70%)", color),
-            4 => format!("color-mix(in srgb, {}, white 60%)", color),
-            5 => format!("color-mix(in srgb, {}, white 40%)", color),
-            6 => color.to_string(),
-            7 => format!("color-mix(in srgb, {}, black 20%)", color),
-            8 => format!("color-mix(in srgb, {}, black 40%)", color),
-            9 => format!("color-mix(in srgb, {}, black 60%)", color),
-            10 => format!("color-mix(in srgb, {}, black 80%)", color),
-            _ => color.to_string(),
-        }
-    }
+/// Whether a numeric token is emitted without a `px` unit
+fn is_unitless(token: &str) -> bool {
+    [
+        "lineHeight",
+        "fontWeight",
+        "opacity",
+        "zIndex",
+        "motionUnit",
+        "motionBase",
+    ]
+    .iter()
+    .any(|prefix| token.starts_with(prefix))
 }
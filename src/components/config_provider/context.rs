@@ -1,19 +1,78 @@
-use crate::components::config_provider::{ComponentSize, Config, Theme};
+use crate::components::config_provider::{
+    ComponentSize, Config, Direction, Theme, ThemeAlgorithm, ThemeConfig, ThemeToken,
+};
 use leptos::prelude::*;
+use std::sync::Arc;
 // use std::collections::HashMap;
 
 /// Default prefix for all components
 pub const ANT_PREFIX: &str = "ant";
 
+/// Default prefix for icons
+pub const ANT_ICON_PREFIX: &str = "anticon";
+
 /// Configuration context type
 pub type ConfigContext = RwSignal<Config>;
 
-/// Theme context type
-pub type ThemeContext = RwSignal<Theme>;
-
 /// Size context type
 pub type SizeContext = RwSignal<Option<ComponentSize>>;
 
+/// Direction context type
+pub type DirectionContext = RwSignal<Direction>;
+
+/// Disabled context type
+///
+/// A newtype so it cannot be mistaken for another `bool` context.
+#[derive(Clone, Copy, Debug)]
+pub struct DisabledContext(pub RwSignal<bool>);
+
+/// Design token resolved by the nearest ConfigProvider
+#[derive(Clone, Debug)]
+pub struct DesignToken {
+    /// Merged theme configuration, including inherited values
+    pub theme: ThemeConfig,
+    /// Token derived from `theme`
+    pub token: ThemeToken,
+    /// Stable hash of `token`
+    pub hash: String,
+}
+
+impl DesignToken {
+    /// Resolve the design token for a theme configuration
+    pub fn new(theme: ThemeConfig) -> Self {
+        let token = theme.derive_token();
+        let hash = token.hash();
+        Self { theme, token, hash }
+    }
+}
+
+impl Default for DesignToken {
+    fn default() -> Self {
+        Self::new(ThemeConfig::default())
+    }
+}
+
+/// Design token context type
+pub type DesignTokenContext = RwSignal<DesignToken>;
+
+/// Class scoping the CSS variables of a theme
+pub fn css_var_cls(prefix_cls: &str, hash: &str) -> String {
+    format!("{}-css-var-{}", prefix_cls, hash)
+}
+
+/// Token of the current subtree, as returned by [`use_token`]
+#[derive(Clone, Debug)]
+pub struct TokenInfo {
+    /// Fully derived token
+    pub token: ThemeToken,
+    /// Active theme algorithms, empty meaning the default algorithm
+    pub algorithm: Vec<Arc<dyn ThemeAlgorithm>>,
+    /// Stable hash of the token
+    pub hash: String,
+    /// Class of the element scoping this theme's CSS variables
+    pub hash_cls: String,
+}
+
 /// Get global prefix
 pub fn get_prefix_cls() -> Option<String> {
     use_context::<ConfigContext>().map(|ctx| ctx.get_untracked().prefix_cls)
@@ -26,12 +85,33 @@ pub fn get_component_cls(suffix: &str) -> String {
         .unwrap_or_else(|| format!("{}-{}", ANT_PREFIX, suffix))
 }
 
-/// Get current theme
-pub fn use_theme() -> Option<ReadSignal<Theme>> {
-    use_context::<ThemeContext>().map(|ctx| ctx.read_only())
+/// Get the current theme colors, derived from the design token
+pub fn use_theme() -> Option<Signal<Theme>> {
+    use_context::<DesignTokenContext>()
+        .map(|design| Signal::derive(move || design.with(|design| Theme::from(&design.token))))
 }
 
 /// Get current size
 pub fn use_size() -> Option<ReadSignal<Option<ComponentSize>>> {
     use_context::<SizeContext>().map(|ctx| ctx.read_only())
 }
+
+/// Get the fully derived token of the nearest ConfigProvider
+///
+/// Falls back to the default theme outside of any provider.
+pub fn use_token() -> Signal<TokenInfo> {
+    let design = use_context::<DesignTokenContext>();
+    let config = use_context::<ConfigContext>();
+    Signal::derive(move || {
+        let design = design.map(|design| design.get()).unwrap_or_default();
+        let prefix_cls = config
+            .map(|config| config.with(|config| config.prefix_cls.clone()))
+            .unwrap_or_else(|| ANT_PREFIX.to_string());
+        TokenInfo {
+            hash_cls: css_var_cls(&prefix_cls, &design.hash),
+            token: design.token,
+            algorithm: design.theme.algorithm,
+            hash: design.hash,
+        }
+    })
+}
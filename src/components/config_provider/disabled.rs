@@ -0,0 +1,53 @@
+use super::context::DisabledContext;
+use leptos::prelude::*;
+use leptos::tachys::reactive_graph::OwnedView;
+
+/// Provides the disabled state to the components below it
+///
+/// Nested providers override their parent; leaving `disabled` unset keeps the
+/// inherited value.
+#[component]
+pub fn DisabledContextProvider(
+    /// Whether components are disabled
+    #[prop(optional, into)]
+    disabled: Option<Signal<bool>>,
+    /// Child components
+    children: Children,
+) -> impl IntoView {
+    let parent = use_context::<DisabledContext>();
+    let resolve = move || {
+        disabled
+            .map(|disabled| disabled.get())
+            .or_else(|| parent.map(|ctx| ctx.0.get()))
+            .unwrap_or(false)
+    };
+
+    let ctx = RwSignal::new(untrack(resolve));
+    // 上下文只对子树可见
+    let owner = Owner::new();
+    owner.with(|| provide_context(DisabledContext(ctx)));
+    Effect::new(move |_| {
+        let next = resolve();
+        if ctx.get_untracked() != next {
+            ctx.set(next);
+        }
+    });
+
+    let children = owner.with(children);
+    OwnedView::new_with_owner(children, owner)
+}
+
+/// Merge a component's own `disabled` prop with the inherited state
+///
+/// The component's prop wins when set, so a control can be re-enabled inside
+/// a disabled subtree. Controls should bind the result to their real
+/// `disabled` attribute.
+pub fn use_merged_disabled(disabled: Option<Signal<bool>>) -> Signal<bool> {
+    let ctx = use_context::<DisabledContext>();
+    Signal::derive(move || {
+        disabled
+            .map(|disabled| disabled.get())
+            .or_else(|| ctx.map(|ctx| ctx.0.get()))
+            .unwrap_or(false)
+    })
+}
@@ -1,5 +1,5 @@
 use crate::components::config_provider::{
-    context::{get_component_cls, ConfigContext},
+    context::{get_component_cls, ConfigContext, DirectionContext, DisabledContext},
     Direction, SpaceSize,
 };
 use leptos::prelude::*;
@@ -10,15 +10,9 @@ pub fn use_component_cls(suffix: &str) -> String {
     get_component_cls(suffix)
 }
 
-/// Hook to get direction
+/// Hook to get the direction of the current subtree
 pub fn use_direction() -> Option<ReadSignal<Direction>> {
-    use_context::<ConfigContext>().map(|ctx| {
-        let signal = RwSignal::new(ctx.get().direction);
-        Effect::new(move |_| {
-            signal.set(ctx.get().direction);
-        });
-        signal.read_only()
-    })
+    use_context::<DirectionContext>().map(|ctx| ctx.read_only())
 }
 
 /// Hook to get form config
@@ -53,15 +47,9 @@ pub fn use_space_config() -> Option<ReadSignal<f64>> {
     })
 }
 
-/// Hook to get disabled state
+/// Hook to get the inherited disabled state
 pub fn use_disabled() -> Option<ReadSignal<bool>> {
-    use_context::<ConfigContext>().map(|ctx| {
-        let signal = RwSignal::new(ctx.get().component_disabled);
-        Effect::new(move |_| {
-            signal.set(ctx.get().component_disabled);
-        });
-        signal.read_only()
-    })
+    use_context::<DisabledContext>().map(|ctx| ctx.0.read_only())
 }
 
 /// Hook to get popup container
@@ -89,7 +77,5 @@ pub fn use_popup_container() -> Option<ReadSignal<Option<String>>> {
 
 /// Helper function to get Element by ID
 pub fn get_container_element(id: &str) -> Option<Element> {
-    let window = web_sys::window()?;
-    let document = window.document()?;
-    document.get_element_by_id(id)
+    crate::utils::dom::document()?.get_element_by_id(id)
 }
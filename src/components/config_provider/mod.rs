@@ -1,20 +1,33 @@
 mod algorithm;
 /// Context management for configuration
 mod context;
+/// Disabled state propagation
+mod disabled;
 /// Hooks for accessing configuration
 mod hooks;
 /// Theme system implementation and customization
+/// Component size propagation
+mod size;
 pub mod theme;
 
 pub use algorithm::*;
 pub use context::*;
+pub use disabled::*;
 pub use hooks::*;
+pub use size::*;
 pub use theme::*;
 
+use crate::utils::hash::hash_str;
+use crate::utils::style::use_dynamic_style;
 use leptos::prelude::*;
+use leptos::tachys::reactive_graph::OwnedView;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use web_sys::Element;
 
+/// Registry name of the style sheet holding a provider's CSS variables
+const CSS_VAR_STYLE: &str = "css-var";
+
 /// Configuration options for the ConfigProvider component
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -71,7 +84,7 @@ pub struct Config {
 }
 
 /// Text direction options
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Direction {
     /// Left to right
     Ltr,
@@ -79,6 +92,21 @@ pub enum Direction {
     Rtl,
 }
 
+impl Direction {
+    /// Value of the `dir` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    /// Whether the direction is right to left
+    pub fn is_rtl(&self) -> bool {
+        *self == Direction::Rtl
+    }
+}
+
 /// Component size options
 #[derive(Clone, Debug, PartialEq)]
 pub enum ComponentSize {
@@ -90,8 +118,12 @@ pub enum ComponentSize {
     Large,
 }
 
-/// Theme configuration
-#[derive(Clone, Debug)]
+/// Legacy theme colors
+///
+/// A view of the design token's main colors. Inside a ConfigProvider,
+/// `Config.theme` is always resolved from the same token as the CSS variables;
+/// a custom `Theme` passed in through `config` is applied as seed colors.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Theme {
     /// Primary color
     pub primary_color: String,
@@ -115,8 +147,8 @@ pub struct WarningConfig {
 /// Form configuration
 #[derive(Clone, Debug)]
 pub struct FormConfig {
-    /// Validation messages
-    pub validate_messages: ValidateMessages,
+    /// Validation messages, those of the current locale when `None`
+    pub validate_messages: Option<ValidateMessages>,
     /// Whether to scroll to first error field
     pub scroll_to_first_error: bool,
     /// Whether to require fields have a value
@@ -124,7 +156,7 @@ pub struct FormConfig {
 }
 
 /// Input component configuration
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct InputConfig {
     /// Whether to automatically focus the first field
     pub auto_focus: bool,
@@ -187,30 +219,64 @@ pub enum SpaceSize {
 }
 
 /// Form validation messages
-#[derive(Clone, Debug)]
+///
+/// Templates may use `${label}`, `${type}`, `${len}`, `${min}`, `${max}`,
+/// `${enum}` and `${pattern}`, which are replaced when a rule fails. The
+/// serialized form uses the keys of antd's `validateMessages`, so locale
+/// packs can provide them as JSON.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ValidateMessages {
+    /// Fallback message of a failed rule
+    pub default: String,
     /// Required field message
     pub required: String,
+    /// Value not in the allowed list message
+    #[serde(rename = "enum")]
+    pub enumeration: String,
+    /// Whitespace-only value message
+    pub whitespace: String,
     /// Types validation messages
     pub types: ValidateTypeMessages,
     /// Length validation messages
+    #[serde(rename = "string")]
     pub length: ValidateLengthMessages,
+    /// Number range validation messages
+    pub number: ValidateLengthMessages,
+    /// Array length validation messages
+    pub array: ValidateLengthMessages,
+    /// Pattern mismatch message
+    pub pattern: String,
 }
 
 /// Type validation messages
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateTypeMessages {
     /// String type message
     pub string: String,
     /// Number type message
     pub number: String,
+    /// Boolean type message
+    pub boolean: String,
+    /// Integer type message
+    pub integer: String,
+    /// Float type message
+    pub float: String,
     /// Array type message
     pub array: String,
+    /// Object type message
+    pub object: String,
+    /// Email type message
+    pub email: String,
+    /// URL type message
+    pub url: String,
 }
 
 /// Length validation messages
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateLengthMessages {
+    /// Exact length message
+    pub len: String,
     /// Min length message
     pub min: String,
     /// Max length message
@@ -220,7 +286,7 @@ pub struct ValidateLengthMessages {
 }
 
 /// Popup container configuration
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct PopupContainer {
     /// Function to get container element
     pub get_popup_container: Option<Arc<dyn Fn() -> Option<Element> + Send + Sync + 'static>>,
@@ -252,12 +318,12 @@ pub struct ComponentToken {
 impl Default for Config {
     fn default() -> Self {
         Self {
-            prefix_cls: "ant".to_string(),
+            prefix_cls: ANT_PREFIX.to_string(),
             direction: Direction::Ltr,
             size: ComponentSize::Middle,
             theme: Theme::default(),
             warning_config: WarningConfig::default(),
-            icon_prefix_cls: "anticon".to_string(),
+            icon_prefix_cls: ANT_ICON_PREFIX.to_string(),
             use_css_vars: true,
             form: FormConfig::default(),
             input: InputConfig::default(),
@@ -277,16 +343,57 @@ impl Default for Config {
 
 impl Default for Theme {
     fn default() -> Self {
+        Self::from(&ThemeToken::default())
+    }
+}
+
+impl From<&ThemeToken> for Theme {
+    fn from(token: &ThemeToken) -> Self {
+        let colors = &token.map.colors;
         Self {
-            primary_color: "#1890ff".to_string(),
-            info_color: "#1890ff".to_string(),
-            success_color: "#52c41a".to_string(),
-            warning_color: "#faad14".to_string(),
-            error_color: "#ff4d4f".to_string(),
+            primary_color: colors.color_primary.clone(),
+            info_color: colors.color_info.clone(),
+            success_color: colors.color_success.clone(),
+            warning_color: colors.color_warning.clone(),
+            error_color: colors.color_error.clone(),
         }
     }
 }
 
+/// Seed colors of a legacy theme
+///
+/// The legacy theme cannot leave a color unset, so colors equal to the
+/// default theme are treated as unset and inherit from the parent provider.
+impl From<&Theme> for ThemeConfig {
+    fn from(theme: &Theme) -> Self {
+        let defaults = Theme::default();
+        [
+            (
+                "colorPrimary",
+                &theme.primary_color,
+                &defaults.primary_color,
+            ),
+            ("colorInfo", &theme.info_color, &defaults.info_color),
+            (
+                "colorSuccess",
+                &theme.success_color,
+                &defaults.success_color,
+            ),
+            (
+                "colorWarning",
+                &theme.warning_color,
+                &defaults.warning_color,
+            ),
+            ("colorError", &theme.error_color, &defaults.error_color),
+        ]
+        .into_iter()
+        .filter(|(_, color, default)| color != default)
+        .fold(ThemeConfig::default(), |config, (name, color, _)| {
+            config.with_token(name, color.as_str())
+        })
+    }
+}
+
 impl Default for WarningConfig {
     fn default() -> Self {
         Self {
@@ -298,22 +405,13 @@ impl Default for WarningConfig {
 impl Default for FormConfig {
     fn default() -> Self {
         Self {
-            validate_messages: ValidateMessages::default(),
+            validate_messages: None,
             scroll_to_first_error: true,
             require_mark: true,
         }
     }
 }
 
-impl Default for InputConfig {
-    fn default() -> Self {
-        Self {
-            auto_focus: false,
-            border_color: None,
-        }
-    }
-}
-
 impl Default for SelectConfig {
     fn default() -> Self {
         Self {
@@ -358,19 +456,42 @@ impl Default for VirtualConfig {
 impl Default for ValidateMessages {
     fn default() -> Self {
         Self {
+            default: "Validation error on field ${label}".to_string(),
             required: "${label} is required".to_string(),
+            enumeration: "${label} must be one of [${enum}]".to_string(),
+            whitespace: "${label} cannot be empty".to_string(),
             types: ValidateTypeMessages::default(),
             length: ValidateLengthMessages::default(),
+            number: ValidateLengthMessages {
+                len: "${label} must equal ${len}".to_string(),
+                min: "${label} cannot be less than ${min}".to_string(),
+                max: "${label} cannot be greater than ${max}".to_string(),
+                range: "${label} must be between ${min} and ${max}".to_string(),
+            },
+            array: ValidateLengthMessages {
+                len: "Must be exactly ${len} ${label}".to_string(),
+                min: "At least ${min} ${label}".to_string(),
+                max: "At most ${max} ${label}".to_string(),
+                range: "The amount of ${label} must be between ${min}-${max}".to_string(),
+            },
+            pattern: "${label} does not match pattern ${pattern}".to_string(),
         }
     }
 }
 
 impl Default for ValidateTypeMessages {
     fn default() -> Self {
+        let message = |kind: &str| format!("${{label}} is not a valid {}", kind);
         Self {
-            string: "${label} is not a valid string".to_string(),
-            number: "${label} is not a valid number".to_string(),
-            array: "${label} is not a valid array".to_string(),
+            string: message("string"),
+            number: message("number"),
+            boolean: message("boolean"),
+            integer: message("integer"),
+            float: message("float"),
+            array: message("array"),
+            object: message("object"),
+            email: message("email"),
+            url: message("url"),
         }
     }
 }
@@ -378,6 +499,7 @@ impl Default for ValidateTypeMessages {
 impl Default for ValidateLengthMessages {
     fn default() -> Self {
         Self {
+            len: "${label} must be exactly ${len} characters".to_string(),
             min: "${label} must be at least ${min} characters".to_string(),
             max: "${label} cannot be longer than ${max} characters".to_string(),
             range: "${label} must be between ${min} and ${max} characters".to_string(),
@@ -385,14 +507,6 @@ impl Default for ValidateLengthMessages {
     }
 }
 
-impl Default for PopupContainer {
-    fn default() -> Self {
-        Self {
-            get_popup_container: None,
-        }
-    }
-}
-
 impl Default for ComponentToken {
     fn default() -> Self {
         Self {
@@ -410,10 +524,8 @@ impl Config {
     pub fn set_theme_mode(&mut self, mode: ThemeMode) {
         match mode {
             ThemeMode::Dark => {
-                self.theme.primary_color = "#177ddc".to_string();
-                self.theme.success_color = "#49aa19".to_string();
-                self.theme.warning_color = "#d89614".to_string();
-                self.theme.error_color = "#a61d24".to_string();
+                let dark: Arc<dyn ThemeAlgorithm> = Arc::new(DarkAlgorithm);
+                self.theme = Theme::from(&ThemeToken::derive(&SeedToken::default(), &[dark]));
             }
             ThemeMode::Light => {
                 self.theme = Theme::default();
@@ -422,6 +534,9 @@ impl Config {
                 // 保持颜色不变，只改变尺寸相关的配置
                 self.size = ComponentSize::Small;
             }
+            ThemeMode::System => {
+                self.set_theme_mode(mode.resolve(crate::utils::dom::prefers_dark_scheme()));
+            }
         }
     }
 
@@ -435,7 +550,7 @@ impl Config {
 }
 
 /// Theme mode options
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ThemeMode {
     /// Dark theme
     Dark,
@@ -443,6 +558,51 @@ pub enum ThemeMode {
     Light,
     /// Compact theme
     Compact,
+    /// Follow the operating system's `prefers-color-scheme`
+    System,
+}
+
+impl ThemeMode {
+    /// Name of the mode, as written to `data-theme` and storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Dark => "dark",
+            ThemeMode::Light => "light",
+            ThemeMode::Compact => "compact",
+            ThemeMode::System => "system",
+        }
+    }
+
+    /// Parse a mode from its name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(ThemeMode::Dark),
+            "light" => Some(ThemeMode::Light),
+            "compact" => Some(ThemeMode::Compact),
+            "system" => Some(ThemeMode::System),
+            _ => None,
+        }
+    }
+
+    /// Theme algorithms deriving the design token of the mode
+    ///
+    /// `System` should be resolved first; unresolved it uses the light algorithm.
+    pub fn algorithm(self) -> Vec<Arc<dyn ThemeAlgorithm>> {
+        match self {
+            ThemeMode::Dark => vec![Arc::new(DarkAlgorithm)],
+            ThemeMode::Compact => vec![Arc::new(CompactAlgorithm)],
+            ThemeMode::Light | ThemeMode::System => vec![Arc::new(DefaultAlgorithm)],
+        }
+    }
+
+    /// Resolve `System` to `Dark` or `Light`, other modes are returned as is
+    pub fn resolve(self, prefers_dark: bool) -> Self {
+        match self {
+            ThemeMode::System if prefers_dark => ThemeMode::Dark,
+            ThemeMode::System => ThemeMode::Light,
+            mode => mode,
+        }
+    }
 }
 
 /// Global configuration provider component
@@ -452,158 +612,189 @@ pub fn ConfigProvider(
     #[prop(optional)]
     config: Option<Config>,
     /// Theme configuration
-    #[prop(optional)]
-    theme: Option<ThemeConfig>,
+    #[prop(optional, into)]
+    theme: Option<Signal<ThemeConfig>>,
     /// Component size
-    #[prop(optional)]
-    size: Option<ComponentSize>,
+    #[prop(optional, into)]
+    size: Option<Signal<ComponentSize>>,
     /// Direction
-    #[prop(optional)]
-    direction: Option<Direction>,
+    #[prop(optional, into)]
+    direction: Option<Signal<Direction>>,
     /// Whether components are disabled
-    #[prop(optional)]
-    disabled: Option<bool>,
+    #[prop(optional, into)]
+    disabled: Option<Signal<bool>>,
     /// Child components
     children: Children,
 ) -> impl IntoView {
-    let mut base_config = config.unwrap_or_default();
-    let theme_clone = theme.clone(); // 克隆 theme 以便后续使用
-
-    // 应用主题配置
-    if let Some(theme_config) = theme {
-        let mut token = theme_config.token.clone();
-
-        // 应用主题算法
-        if let Some(algorithms) = &theme_config.algorithm {
-            for algorithm in algorithms {
-                token.apply_algorithm(algorithm);
-            }
+    // 嵌套时继承上层 ConfigProvider 的配置与主题
+    let parent_config = use_context::<ConfigContext>();
+    let parent_design = use_context::<DesignTokenContext>();
+    let parent_size = use_context::<SizeContext>();
+    let parent_disabled = use_context::<DisabledContext>();
+    let parent_direction = use_context::<DirectionContext>();
+    let is_root = parent_config.is_none();
+    let owns_vars = is_root || config.is_some() || theme.is_some();
+
+    // 旧版 Config.theme 作为种子颜色参与合并，主题只从设计令牌一处解析
+    let legacy_theme = config
+        .as_ref()
+        .map(|config| ThemeConfig::from(&config.theme));
+    let resolve_design = move || {
+        let mut own = legacy_theme.clone();
+        if let Some(theme) = theme {
+            let theme = theme.get();
+            own = Some(match own {
+                Some(legacy) => legacy.merge(theme),
+                None => theme,
+            });
         }
+        match (parent_design, own) {
+            (Some(parent), Some(own)) => DesignToken::new(parent.get().theme.merge(own)),
+            (Some(parent), None) => parent.get(),
+            (None, own) => DesignToken::new(own.unwrap_or_default()),
+        }
+    };
+    let resolve_size = move || {
+        size.map(|size| size.get())
+            .or_else(|| parent_size.and_then(|ctx| ctx.get()))
+    };
+    let resolve_config = {
+        let config = config.clone();
+        let has_config = config.is_some();
+        move |design: &DesignToken, size: Option<ComponentSize>| {
+            let mut config = config
+                .clone()
+                .or_else(|| parent_config.map(|ctx| ctx.get()))
+                .unwrap_or_default();
+
+            // 旧版颜色始终由设计令牌得出
+            config.theme = Theme::from(&design.token);
+
+            // 未传入配置时继承最近的 DisabledContextProvider 与方向
+            if let (false, Some(parent)) = (has_config, parent_disabled) {
+                config.component_disabled = parent.0.get();
+            }
+            if let (false, Some(parent)) = (has_config, parent_direction) {
+                config.direction = parent.get();
+            }
 
-        let css_generator = CSSVariablesGenerator::new(token.clone())
-            .with_components(theme_config.components.clone());
-
-        // 注入 CSS 变量
-        if base_config.use_css_vars {
-            let css = css_generator.generate();
-            if let Some(style_element) = crate::utils::dom::create_style_element(&css) {
-                if let Some(document) = crate::utils::dom::document() {
-                    if let Some(head) = document.head() {
-                        let _ = head.append_child(&style_element);
-                    }
-                }
+            // Apply props to config
+            if let Some(size) = size {
+                config.component_size = Some(size);
+            }
+            if let Some(direction) = direction {
+                config.direction = direction.get();
+            }
+            if let Some(disabled) = disabled {
+                config.component_disabled = disabled.get();
             }
+            config
         }
+    };
 
-        // 更新配置中的主题
-        base_config.theme = Theme {
-            primary_color: token.primary_color.clone(),
-            info_color: token.primary_color.clone(),
-            success_color: token.success_color,
-            warning_color: token.warning_color,
-            error_color: token.error_color,
-        };
-    }
-
-    // Apply props to config
-    if let Some(ref size) = size {
-        base_config.component_size = Some(size.clone());
-    }
-    if let Some(dir) = direction {
-        base_config.direction = dir;
-    }
-    if let Some(disabled) = disabled {
-        base_config.component_disabled = disabled;
-    }
+    let design = untrack(&resolve_design);
+    let size_value = untrack(resolve_size);
+    let base_config = untrack(|| resolve_config(&design, size_value.clone()));
 
+    let disabled_ctx = RwSignal::new(base_config.component_disabled);
+    let direction_ctx: DirectionContext = RwSignal::new(base_config.direction);
     let config = RwSignal::new(base_config);
+    let size_ctx = RwSignal::new(size_value);
+    let design_ctx = RwSignal::new(design);
+
+    // 上下文挂在子 owner 上，只对子树可见，不会泄漏给后面的兄弟组件
+    let owner = Owner::new();
+    owner.with(|| {
+        provide_context(config);
+        provide_context(size_ctx);
+        provide_context(DisabledContext(disabled_ctx));
+        provide_context(direction_ctx);
+        provide_context(design_ctx);
+    });
+
+    // 输入变化时同步更新提供给子组件的上下文，首次运行只建立依赖
+    Effect::new(move |initialized: Option<()>| {
+        let design = resolve_design();
+        let size = resolve_size();
+        let next = resolve_config(&design, size.clone());
+        if initialized.is_some() {
+            disabled_ctx.set(next.component_disabled);
+            direction_ctx.set(next.direction);
+            config.set(next);
+            size_ctx.set(size);
+            design_ctx.set(design);
+        }
+    });
 
-    // Generate theme from config
-    let theme_ctx = RwSignal::new(
-        theme_clone
-            .map(|t| Theme {
-                primary_color: t.token.primary_color.clone(),
-                info_color: t.token.primary_color.clone(),
-                success_color: t.token.success_color.clone(),
-                warning_color: t.token.warning_color.clone(),
-                error_color: t.token.error_color.clone(),
+    // 根节点的变量挂在 :root 上，嵌套节点只作用于自身包裹的子树
+    let scope_cls = move || {
+        owns_vars.then(|| {
+            config.with(|config| {
+                design_ctx.with(|design| css_var_cls(&config.prefix_cls, &design.hash))
             })
-            .unwrap_or_default(),
-    );
-
-    let size_ctx = RwSignal::new(size);
-
-    provide_context(config);
-    provide_context(theme_ctx);
-    provide_context(size_ctx);
-
-    // Apply CSS variables if enabled
-    Effect::new(move |_| {
-        let current_config = config.get_untracked();
-        if current_config.use_css_vars {
-            let theme = current_config.theme;
-            let token = current_config.component_token;
-            let style = format!(
-                r#"
-                :root {{
-                    --ant-primary-color: {};
-                    --ant-info-color: {};
-                    --ant-success-color: {};
-                    --ant-warning-color: {};
-                    --ant-error-color: {};
-                    --ant-motion-duration-mid: {}ms;
-                    --ant-motion-duration-fast: {}ms;
-                    --ant-motion-ease-in-out: {};
-                    --ant-motion-ease-out: {};
-                    --ant-motion-base: {}ms;
-                }}
-            "#,
-                theme.primary_color,
-                theme.info_color,
-                theme.success_color,
-                theme.warning_color,
-                theme.error_color,
-                token.motion_duration_mid,
-                token.motion_duration_fast,
-                token.motion_ease_in_out,
-                token.motion_ease_out,
-                token.motion_base,
-            );
-
-            if let Some(style_element) = crate::utils::dom::create_style_element(&style) {
-                if let Some(document) = crate::utils::dom::document() {
-                    if let Some(head) = document.head() {
-                        let _ = head.append_child(&style_element);
-                    }
-                }
-            }
+        })
+    };
+
+    // 注入 CSS 变量，主题变化时替换注册表中的样式表
+    use_dynamic_style(CSS_VAR_STYLE, move || {
+        let current_config = config.get();
+        if !current_config.use_css_vars || !owns_vars {
+            return None;
         }
+
+        let selector = match scope_cls() {
+            Some(cls) if !is_root => format!(".{}", cls),
+            _ => ":root".to_string(),
+        };
+        let prefix = current_config.prefix_cls;
+        let css = design_ctx.with(|design| {
+            CSSVariablesGenerator::new(design.token.clone())
+                .with_selector(selector.clone())
+                .with_prefix(prefix.clone())
+                .with_theme(&design.theme)
+                .generate()
+        });
+
+        Some((hash_str(&css), move || css))
     });
 
     let class = move || {
-        let current_config = config.get_untracked();
-        let mut classes = vec!["ant-config-provider"];
+        let current_config = config.get();
+        let cls = format!("{}-config-provider", current_config.prefix_cls);
+        let mut classes = vec![cls.clone()];
 
         if current_config.component_disabled {
-            classes.push("ant-config-provider-disabled");
+            classes.push(format!("{}-disabled", cls));
+        }
+
+        if current_config.direction.is_rtl() {
+            classes.push(format!("{}-rtl", cls));
         }
 
         if let Some(size) = current_config.component_size {
             match size {
-                ComponentSize::Small => classes.push("ant-config-provider-sm"),
-                ComponentSize::Large => classes.push("ant-config-provider-lg"),
+                ComponentSize::Small => classes.push(format!("{}-sm", cls)),
+                ComponentSize::Large => classes.push(format!("{}-lg", cls)),
                 _ => {}
             }
         }
 
+        if let Some(cls) = scope_cls() {
+            classes.push(cls);
+        }
+
         classes.join(" ")
     };
 
-    view! {
-        <div class={class()}>
-            {children()}
-        </div>
-    }
+    let children = owner.with(children);
+    OwnedView::new_with_owner(
+        view! {
+            <div class=class dir=move || direction_ctx.get().as_str()>
+                {children}
+            </div>
+        },
+        owner,
+    )
 }
 
 /// Hook to access the current configuration
@@ -612,31 +803,10 @@ pub fn use_config() -> Option<ReadSignal<Config>> {
 }
 
 /// Generate theme colors based on primary color and algorithm
-pub fn generate_colors(primary_color: &str, algorithm: Option<&[ThemeAlgorithm]>) -> Theme {
-    let mut theme = Theme {
-        primary_color: primary_color.to_string(),
-        info_color: primary_color.to_string(),
-        success_color: "#52c41a".to_string(),
-        warning_color: "#faad14".to_string(),
-        error_color: "#ff4d4f".to_string(),
+pub fn generate_colors(primary_color: &str, algorithm: &[Arc<dyn ThemeAlgorithm>]) -> Theme {
+    let seed = SeedToken {
+        color_primary: primary_color.to_string(),
+        ..SeedToken::default()
     };
-
-    if let Some(algorithms) = algorithm {
-        for algo in algorithms {
-            match algo {
-                ThemeAlgorithm::Dark => {
-                    theme.primary_color = "#177ddc".to_string();
-                    theme.success_color = "#49aa19".to_string();
-                    theme.warning_color = "#d89614".to_string();
-                    theme.error_color = "#a61d24".to_string();
-                }
-                ThemeAlgorithm::Compact => {
-                    // Compact 主题不改变颜色
-                }
-                ThemeAlgorithm::Default => {}
-            }
-        }
-    }
-
-    theme
+    Theme::from(&ThemeToken::derive(&seed, algorithm))
 }
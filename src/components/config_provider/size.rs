@@ -0,0 +1,51 @@
+use super::context::{ConfigContext, SizeContext};
+use super::ComponentSize;
+use leptos::prelude::*;
+use leptos::tachys::reactive_graph::OwnedView;
+
+/// Provides the component size to the components below it
+///
+/// Nested providers override their parent; leaving `size` unset keeps the
+/// inherited value.
+#[component]
+pub fn SizeContextProvider(
+    /// Component size
+    #[prop(optional, into)]
+    size: Option<Signal<ComponentSize>>,
+    /// Child components
+    children: Children,
+) -> impl IntoView {
+    let parent = use_context::<SizeContext>();
+    let resolve = move || {
+        size.map(|size| size.get())
+            .or_else(|| parent.and_then(|ctx| ctx.get()))
+    };
+
+    let ctx = RwSignal::new(untrack(resolve));
+    // 上下文只对子树可见
+    let owner = Owner::new();
+    owner.with(|| provide_context::<SizeContext>(ctx));
+    Effect::new(move |_| {
+        let next = resolve();
+        if ctx.get_untracked() != next {
+            ctx.set(next);
+        }
+    });
+
+    let children = owner.with(children);
+    OwnedView::new_with_owner(children, owner)
+}
+
+/// Merge a component's own `size` prop with the inherited size
+///
+/// Falls back to `Config.size` and then to `Middle` when nothing is set.
+pub fn use_merged_size(size: Option<Signal<ComponentSize>>) -> Signal<ComponentSize> {
+    let ctx = use_context::<SizeContext>();
+    let config = use_context::<ConfigContext>();
+    Signal::derive(move || {
+        size.map(|size| size.get())
+            .or_else(|| ctx.and_then(|ctx| ctx.get()))
+            .or_else(|| config.map(|config| config.with(|config| config.size.clone())))
+            .unwrap_or(ComponentSize::Middle)
+    })
+}
@@ -0,0 +1,356 @@
+use super::{MapToken, SeedToken};
+use crate::utils::color;
+use serde::{Deserialize, Serialize};
+
+/// Alias token, semantic tokens consumed directly by components
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AliasToken {
+    /// Content fill
+    pub color_fill_content: String,
+    /// Content fill on hover
+    pub color_fill_content_hover: String,
+    /// Alternate fill
+    pub color_fill_alter: String,
+    /// Disabled container background
+    pub color_bg_container_disabled: String,
+    /// Border matching the container background
+    pub color_border_bg: String,
+    /// Split line color
+    pub color_split: String,
+    /// Placeholder text
+    pub color_text_placeholder: String,
+    /// Disabled text
+    pub color_text_disabled: String,
+    /// Heading text
+    pub color_text_heading: String,
+    /// Label text
+    pub color_text_label: String,
+    /// Description text
+    pub color_text_description: String,
+    /// Text on solid backgrounds
+    pub color_text_light_solid: String,
+    /// Highlighted text
+    pub color_highlight: String,
+    /// Text background on hover
+    pub color_bg_text_hover: String,
+    /// Text background when active
+    pub color_bg_text_active: String,
+    /// Icon color
+    pub color_icon: String,
+    /// Icon color on hover
+    pub color_icon_hover: String,
+    /// Outline of error controls
+    pub color_error_outline: String,
+    /// Outline of warning controls
+    pub color_warning_outline: String,
+
+    /// Icon font size
+    pub font_size_icon: f64,
+    /// Focus outline width
+    pub line_width_focus: f64,
+    /// Control outline width
+    pub control_outline_width: f64,
+    /// Size of checkboxes and expand icons
+    pub control_interactive_size: f64,
+    /// Control item background on hover
+    pub control_item_bg_hover: String,
+    /// Control item background when active
+    pub control_item_bg_active: String,
+    /// Control item background when active and hovered
+    pub control_item_bg_active_hover: String,
+    /// Control item background when active and disabled
+    pub control_item_bg_active_disabled: String,
+    /// Temporary control outline
+    pub control_tmp_outline: String,
+    /// Control outline
+    pub control_outline: String,
+
+    /// Strong font weight
+    pub font_weight_strong: f64,
+    /// Opacity while loading
+    pub opacity_loading: f64,
+    /// Link decoration
+    pub link_decoration: String,
+    /// Link decoration on hover
+    pub link_hover_decoration: String,
+    /// Link decoration on focus
+    pub link_focus_decoration: String,
+
+    /// Horizontal control padding
+    pub control_padding_horizontal: f64,
+    /// Small horizontal control padding
+    #[serde(rename = "controlPaddingHorizontalSM")]
+    pub control_padding_horizontal_sm: f64,
+
+    /// XXS padding
+    #[serde(rename = "paddingXXS")]
+    pub padding_xxs: f64,
+    /// XS padding
+    #[serde(rename = "paddingXS")]
+    pub padding_xs: f64,
+    /// SM padding
+    #[serde(rename = "paddingSM")]
+    pub padding_sm: f64,
+    /// Default padding
+    pub padding: f64,
+    /// MD padding
+    #[serde(rename = "paddingMD")]
+    pub padding_md: f64,
+    /// LG padding
+    #[serde(rename = "paddingLG")]
+    pub padding_lg: f64,
+    /// XL padding
+    #[serde(rename = "paddingXL")]
+    pub padding_xl: f64,
+    /// Large horizontal content padding
+    #[serde(rename = "paddingContentHorizontalLG")]
+    pub padding_content_horizontal_lg: f64,
+    /// Large vertical content padding
+    #[serde(rename = "paddingContentVerticalLG")]
+    pub padding_content_vertical_lg: f64,
+    /// Horizontal content padding
+    pub padding_content_horizontal: f64,
+    /// Vertical content padding
+    pub padding_content_vertical: f64,
+    /// Small horizontal content padding
+    #[serde(rename = "paddingContentHorizontalSM")]
+    pub padding_content_horizontal_sm: f64,
+    /// Small vertical content padding
+    #[serde(rename = "paddingContentVerticalSM")]
+    pub padding_content_vertical_sm: f64,
+
+    /// XXS margin
+    #[serde(rename = "marginXXS")]
+    pub margin_xxs: f64,
+    /// XS margin
+    #[serde(rename = "marginXS")]
+    pub margin_xs: f64,
+    /// SM margin
+    #[serde(rename = "marginSM")]
+    pub margin_sm: f64,
+    /// Default margin
+    pub margin: f64,
+    /// MD margin
+    #[serde(rename = "marginMD")]
+    pub margin_md: f64,
+    /// LG margin
+    #[serde(rename = "marginLG")]
+    pub margin_lg: f64,
+    /// XL margin
+    #[serde(rename = "marginXL")]
+    pub margin_xl: f64,
+    /// XXL margin
+    #[serde(rename = "marginXXL")]
+    pub margin_xxl: f64,
+
+    /// Primary box shadow
+    pub box_shadow: String,
+    /// Secondary box shadow
+    pub box_shadow_secondary: String,
+    /// Tertiary box shadow
+    pub box_shadow_tertiary: String,
+    /// Popover arrow shadow
+    pub box_shadow_popover_arrow: String,
+    /// Card shadow
+    pub box_shadow_card: String,
+    /// Right drawer shadow
+    pub box_shadow_drawer_right: String,
+    /// Left drawer shadow
+    pub box_shadow_drawer_left: String,
+    /// Upward drawer shadow
+    pub box_shadow_drawer_up: String,
+    /// Downward drawer shadow
+    pub box_shadow_drawer_down: String,
+    /// Left tab overflow shadow
+    pub box_shadow_tabs_overflow_left: String,
+    /// Right tab overflow shadow
+    pub box_shadow_tabs_overflow_right: String,
+    /// Top tab overflow shadow
+    pub box_shadow_tabs_overflow_top: String,
+    /// Bottom tab overflow shadow
+    pub box_shadow_tabs_overflow_bottom: String,
+
+    /// XS breakpoint
+    #[serde(rename = "screenXS")]
+    pub screen_xs: u32,
+    /// XS breakpoint lower bound
+    #[serde(rename = "screenXSMin")]
+    pub screen_xs_min: u32,
+    /// XS breakpoint upper bound
+    #[serde(rename = "screenXSMax")]
+    pub screen_xs_max: u32,
+    /// SM breakpoint
+    #[serde(rename = "screenSM")]
+    pub screen_sm: u32,
+    /// SM breakpoint lower bound
+    #[serde(rename = "screenSMMin")]
+    pub screen_sm_min: u32,
+    /// SM breakpoint upper bound
+    #[serde(rename = "screenSMMax")]
+    pub screen_sm_max: u32,
+    /// MD breakpoint
+    #[serde(rename = "screenMD")]
+    pub screen_md: u32,
+    /// MD breakpoint lower bound
+    #[serde(rename = "screenMDMin")]
+    pub screen_md_min: u32,
+    /// MD breakpoint upper bound
+    #[serde(rename = "screenMDMax")]
+    pub screen_md_max: u32,
+    /// LG breakpoint
+    #[serde(rename = "screenLG")]
+    pub screen_lg: u32,
+    /// LG breakpoint lower bound
+    #[serde(rename = "screenLGMin")]
+    pub screen_lg_min: u32,
+    /// LG breakpoint upper bound
+    #[serde(rename = "screenLGMax")]
+    pub screen_lg_max: u32,
+    /// XL breakpoint
+    #[serde(rename = "screenXL")]
+    pub screen_xl: u32,
+    /// XL breakpoint lower bound
+    #[serde(rename = "screenXLMin")]
+    pub screen_xl_min: u32,
+    /// XL breakpoint upper bound
+    #[serde(rename = "screenXLMax")]
+    pub screen_xl_max: u32,
+    /// XXL breakpoint
+    #[serde(rename = "screenXXL")]
+    pub screen_xxl: u32,
+    /// XXL breakpoint lower bound
+    #[serde(rename = "screenXXLMin")]
+    pub screen_xxl_min: u32,
+}
+
+const SCREEN_XS: u32 = 480;
+const SCREEN_SM: u32 = 576;
+const SCREEN_MD: u32 = 768;
+const SCREEN_LG: u32 = 992;
+const SCREEN_XL: u32 = 1200;
+const SCREEN_XXL: u32 = 1600;
+
+impl AliasToken {
+    /// Derive alias tokens from the seed and map tokens
+    pub fn derive(seed: &SeedToken, map: &MapToken) -> Self {
+        let c = &map.colors;
+        let size = &map.size;
+        let shadow = "0 6px 16px 0 rgba(0, 0, 0, 0.08), 0 3px 6px -4px rgba(0, 0, 0, 0.12), \
+                      0 9px 28px 8px rgba(0, 0, 0, 0.05)";
+
+        Self {
+            color_fill_content: c.color_fill_secondary.clone(),
+            color_fill_content_hover: c.color_fill.clone(),
+            color_fill_alter: c.color_fill_quaternary.clone(),
+            color_bg_container_disabled: c.color_fill_tertiary.clone(),
+            color_border_bg: c.color_bg_container.clone(),
+            color_split: color::blend_alpha(&c.color_border_secondary, &c.color_bg_container),
+            color_text_placeholder: c.color_text_quaternary.clone(),
+            color_text_disabled: c.color_text_quaternary.clone(),
+            color_text_heading: c.color_text.clone(),
+            color_text_label: c.color_text_secondary.clone(),
+            color_text_description: c.color_text_tertiary.clone(),
+            color_text_light_solid: c.color_white.clone(),
+            color_highlight: c.color_error.clone(),
+            color_bg_text_hover: c.color_fill_secondary.clone(),
+            color_bg_text_active: c.color_fill.clone(),
+            color_icon: c.color_text_tertiary.clone(),
+            color_icon_hover: c.color_text.clone(),
+            color_error_outline: color::blend_alpha(&c.color_error_bg, &c.color_bg_container),
+            color_warning_outline: color::blend_alpha(&c.color_warning_bg, &c.color_bg_container),
+
+            font_size_icon: map.font.font_size_sm,
+            line_width_focus: seed.line_width * 3.0,
+            control_outline_width: seed.line_width * 2.0,
+            control_interactive_size: map.height.control_height / 2.0,
+            control_item_bg_hover: c.color_fill_tertiary.clone(),
+            control_item_bg_active: c.color_primary_bg.clone(),
+            control_item_bg_active_hover: c.color_primary_bg_hover.clone(),
+            control_item_bg_active_disabled: c.color_fill.clone(),
+            control_tmp_outline: c.color_fill_quaternary.clone(),
+            control_outline: color::blend_alpha(&c.color_primary_bg, &c.color_bg_container),
+
+            font_weight_strong: 600.0,
+            opacity_loading: 0.65,
+            link_decoration: "none".to_string(),
+            link_hover_decoration: "none".to_string(),
+            link_focus_decoration: "none".to_string(),
+
+            control_padding_horizontal: 12.0,
+            control_padding_horizontal_sm: 8.0,
+
+            padding_xxs: size.size_xxs,
+            padding_xs: size.size_xs,
+            padding_sm: size.size_sm,
+            padding: size.size,
+            padding_md: size.size_md,
+            padding_lg: size.size_lg,
+            padding_xl: size.size_xl,
+            padding_content_horizontal_lg: size.size_lg,
+            padding_content_vertical_lg: size.size_ms,
+            padding_content_horizontal: size.size_ms,
+            padding_content_vertical: size.size_sm,
+            padding_content_horizontal_sm: size.size,
+            padding_content_vertical_sm: size.size_xs,
+
+            margin_xxs: size.size_xxs,
+            margin_xs: size.size_xs,
+            margin_sm: size.size_sm,
+            margin: size.size,
+            margin_md: size.size_md,
+            margin_lg: size.size_lg,
+            margin_xl: size.size_xl,
+            margin_xxl: size.size_xxl,
+
+            box_shadow: shadow.to_string(),
+            box_shadow_secondary: shadow.to_string(),
+            box_shadow_tertiary:
+                "0 1px 2px 0 rgba(0, 0, 0, 0.03), 0 1px 6px -1px rgba(0, 0, 0, 0.02), \
+                                  0 2px 4px 0 rgba(0, 0, 0, 0.02)"
+                    .to_string(),
+            box_shadow_popover_arrow: "2px 2px 5px rgba(0, 0, 0, 0.05)".to_string(),
+            box_shadow_card:
+                "0 1px 2px -2px rgba(0, 0, 0, 0.16), 0 3px 6px 0 rgba(0, 0, 0, 0.12), \
+                              0 5px 12px 4px rgba(0, 0, 0, 0.09)"
+                    .to_string(),
+            box_shadow_drawer_right:
+                "-6px 0 16px 0 rgba(0, 0, 0, 0.08), -3px 0 6px -4px rgba(0, 0, 0, 0.12), \
+                                      -9px 0 28px 8px rgba(0, 0, 0, 0.05)"
+                    .to_string(),
+            box_shadow_drawer_left:
+                "6px 0 16px 0 rgba(0, 0, 0, 0.08), 3px 0 6px -4px rgba(0, 0, 0, 0.12), \
+                                     9px 0 28px 8px rgba(0, 0, 0, 0.05)"
+                    .to_string(),
+            box_shadow_drawer_up: shadow.to_string(),
+            box_shadow_drawer_down:
+                "0 -6px 16px 0 rgba(0, 0, 0, 0.08), 0 -3px 6px -4px rgba(0, 0, 0, 0.12), \
+                                     0 -9px 28px 8px rgba(0, 0, 0, 0.05)"
+                    .to_string(),
+            box_shadow_tabs_overflow_left: "inset 10px 0 8px -8px rgba(0, 0, 0, 0.08)".to_string(),
+            box_shadow_tabs_overflow_right: "inset -10px 0 8px -8px rgba(0, 0, 0, 0.08)"
+                .to_string(),
+            box_shadow_tabs_overflow_top: "inset 0 10px 8px -8px rgba(0, 0, 0, 0.08)".to_string(),
+            box_shadow_tabs_overflow_bottom: "inset 0 -10px 8px -8px rgba(0, 0, 0, 0.08)"
+                .to_string(),
+
+            screen_xs: SCREEN_XS,
+            screen_xs_min: SCREEN_XS,
+            screen_xs_max: SCREEN_SM - 1,
+            screen_sm: SCREEN_SM,
+            screen_sm_min: SCREEN_SM,
+            screen_sm_max: SCREEN_MD - 1,
+            screen_md: SCREEN_MD,
+            screen_md_min: SCREEN_MD,
+            screen_md_max: SCREEN_LG - 1,
+            screen_lg: SCREEN_LG,
+            screen_lg_min: SCREEN_LG,
+            screen_lg_max: SCREEN_XL - 1,
+            screen_xl: SCREEN_XL,
+            screen_xl_min: SCREEN_XL,
+            screen_xl_max: SCREEN_XXL - 1,
+            screen_xxl: SCREEN_XXL,
+            screen_xxl_min: SCREEN_XXL,
+        }
+    }
+}
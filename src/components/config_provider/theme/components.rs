@@ -0,0 +1,417 @@
+//! 组件令牌
+//!
+//! 每个组件有一个强类型的令牌结构体，默认值由全局令牌派生，与 antd 各组件的
+//! `prepareComponentToken` 一致；对应的 `*TokenConfig` 结构体用于覆盖其中的字段。
+
+use super::{object_fields, ThemeConfig, ThemeToken};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Typed token of a component
+pub trait ComponentTokenType: Clone + Serialize + DeserializeOwned + 'static {
+    /// Component name as used in antd's `components` theme option, e.g. `Button`
+    const NAME: &'static str;
+
+    /// Overrides for this token, with every field optional
+    type Config: Clone + Default + Serialize + DeserializeOwned;
+
+    /// Derive the default component token from the global token
+    fn derive(token: &ThemeToken) -> Self;
+
+    /// Replace the fields set in `config`
+    fn apply(self, config: &Self::Config) -> Self;
+
+    /// Names of every token field, in antd's camelCase
+    fn field_names() -> Vec<String> {
+        object_fields(&Self::derive(&ThemeToken::default()))
+            .keys()
+            .cloned()
+            .collect()
+    }
+}
+
+macro_rules! component_token {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $config:ident, $component:literal {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty = |$token:ident| $derive:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $ty,
+            )*
+        }
+
+        #[doc = concat!("Overrides for [`", stringify!($name), "`], unset fields keep their derived value")]
+        #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+        #[serde(default, rename_all = "camelCase")]
+        pub struct $config {
+            $(
+                $(#[$field_meta])*
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl ComponentTokenType for $name {
+            const NAME: &'static str = $component;
+            type Config = $config;
+
+            fn derive(token: &ThemeToken) -> Self {
+                Self {
+                    $($field: {
+                        let $token = token;
+                        $derive
+                    },)*
+                }
+            }
+
+            fn apply(mut self, config: &$config) -> Self {
+                $(
+                    if let Some(value) = &config.$field {
+                        self.$field = value.clone();
+                    }
+                )*
+                self
+            }
+        }
+    };
+}
+
+/// A component with a typed token, as listed in [`TYPED_COMPONENTS`]
+#[derive(Clone, Copy, Debug)]
+pub struct TypedComponent {
+    /// Component name as used in antd's `components` theme option
+    pub name: &'static str,
+    /// Names of every token field, in antd's camelCase
+    pub fields: fn() -> Vec<String>,
+    /// Resolved token values of the component in a theme, by antd name
+    pub values: fn(&ThemeConfig, &ThemeToken) -> Map<String, Value>,
+    /// Check that a single override deserializes into the token config
+    pub check: fn(&str, &Value) -> Result<(), serde_json::Error>,
+}
+
+impl TypedComponent {
+    const fn of<T: ComponentTokenType>() -> Self {
+        Self {
+            name: T::NAME,
+            fields: T::field_names,
+            values: component_values::<T>,
+            check: check_field::<T>,
+        }
+    }
+}
+
+fn component_values<T: ComponentTokenType>(
+    theme: &ThemeConfig,
+    token: &ThemeToken,
+) -> Map<String, Value> {
+    object_fields(&theme.component_token::<T>(token))
+}
+
+fn check_field<T: ComponentTokenType>(key: &str, value: &Value) -> Result<(), serde_json::Error> {
+    let mut single = Map::new();
+    single.insert(key.to_string(), value.clone());
+    serde_json::from_value::<T::Config>(Value::Object(single)).map(|_| ())
+}
+
+/// Look up a component with a typed token by its antd name
+pub fn typed_component(name: &str) -> Option<&'static TypedComponent> {
+    TYPED_COMPONENTS
+        .iter()
+        .find(|component| component.name == name)
+}
+
+// 所有组件令牌在同一处声明，同时生成 `TYPED_COMPONENTS` 注册表
+macro_rules! component_tokens {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident, $config:ident, $component:literal { $($fields:tt)* }
+    )*) => {
+        $(
+            component_token! {
+                $(#[$meta])*
+                $name, $config, $component { $($fields)* }
+            }
+        )*
+
+        /// Every component with a typed token
+        pub const TYPED_COMPONENTS: &[TypedComponent] = &[$(TypedComponent::of::<$name>()),*];
+    };
+}
+
+component_tokens! {
+    /// Button component token
+    ButtonToken, ButtonTokenConfig, "Button" {
+        /// Font weight of the button text
+        font_weight: f64 = |_t| 400.0,
+        /// Shadow of default buttons
+        default_shadow: String = |t| format!("0 {}px 0 {}", t.alias.control_outline_width, t.alias.control_tmp_outline),
+        /// Shadow of primary buttons
+        primary_shadow: String = |t| format!("0 {}px 0 {}", t.alias.control_outline_width, t.alias.control_outline),
+        /// Shadow of danger buttons
+        danger_shadow: String = |t| format!("0 {}px 0 {}", t.alias.control_outline_width, t.alias.color_error_outline),
+        /// Text color of primary buttons
+        primary_color: String = |t| t.alias.color_text_light_solid.clone(),
+        /// Text color of danger buttons
+        danger_color: String = |t| t.alias.color_text_light_solid.clone(),
+        /// Text color of default buttons
+        default_color: String = |t| t.map.colors.color_text.clone(),
+        /// Background of default buttons
+        default_bg: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Border color of default buttons
+        default_border_color: String = |t| t.map.colors.color_border.clone(),
+        /// Background of hovered default buttons
+        default_hover_bg: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Text color of hovered default buttons
+        default_hover_color: String = |t| t.map.colors.color_primary_hover.clone(),
+        /// Border color of hovered default buttons
+        default_hover_border_color: String = |t| t.map.colors.color_primary_hover.clone(),
+        /// Background of pressed default buttons
+        default_active_bg: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Text color of pressed default buttons
+        default_active_color: String = |t| t.map.colors.color_primary_active.clone(),
+        /// Border color of pressed default buttons
+        default_active_border_color: String = |t| t.map.colors.color_primary_active.clone(),
+        /// Border color of disabled buttons
+        border_color_disabled: String = |t| t.map.colors.color_border.clone(),
+        /// Text color of ghost default buttons
+        default_ghost_color: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Border color of ghost default buttons
+        default_ghost_border_color: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Background of ghost buttons
+        ghost_bg: String = |_t| "transparent".to_string(),
+        /// Border color of buttons inside a group
+        group_border_color: String = |t| t.map.colors.color_primary_hover.clone(),
+        /// Hover background of link buttons
+        link_hover_bg: String = |_t| "transparent".to_string(),
+        /// Hover background of text buttons
+        text_hover_bg: String = |t| t.alias.color_bg_text_hover.clone(),
+        /// Horizontal padding
+        padding_inline: f64 = |t| t.alias.padding_content_horizontal - t.seed.line_width,
+        /// Horizontal padding of large buttons
+        #[serde(rename = "paddingInlineLG")]
+        padding_inline_lg: f64 = |t| t.alias.padding_content_horizontal - t.seed.line_width,
+        /// Horizontal padding of small buttons
+        #[serde(rename = "paddingInlineSM")]
+        padding_inline_sm: f64 = |t| 8.0 - t.seed.line_width,
+        /// Icon size of icon-only buttons
+        only_icon_size: f64 = |t| t.map.font.font_size_lg,
+        /// Icon size of large icon-only buttons
+        #[serde(rename = "onlyIconSizeLG")]
+        only_icon_size_lg: f64 = |t| t.map.font.font_size_lg + 2.0,
+        /// Icon size of small icon-only buttons
+        #[serde(rename = "onlyIconSizeSM")]
+        only_icon_size_sm: f64 = |t| t.map.font.font_size_lg - 2.0,
+        /// Font size of the button content
+        content_font_size: f64 = |t| t.map.font.font_size,
+        /// Font size of large button content
+        #[serde(rename = "contentFontSizeLG")]
+        content_font_size_lg: f64 = |t| t.map.font.font_size_lg,
+        /// Font size of small button content
+        #[serde(rename = "contentFontSizeSM")]
+        content_font_size_sm: f64 = |t| t.map.font.font_size,
+    }
+
+    /// Input component token
+    InputToken, InputTokenConfig, "Input" {
+        /// Vertical padding
+        padding_block: f64 = |t| input_padding_block(t.map.height.control_height, t.map.font.font_size, t.map.font.line_height, t.seed.line_width),
+        /// Vertical padding of small inputs
+        #[serde(rename = "paddingBlockSM")]
+        padding_block_sm: f64 = |t| input_padding_block(t.map.height.control_height_sm, t.map.font.font_size, t.map.font.line_height, t.seed.line_width),
+        /// Vertical padding of large inputs
+        #[serde(rename = "paddingBlockLG")]
+        padding_block_lg: f64 = |t| {
+            let padding = (t.map.height.control_height_lg - t.map.font.font_size_lg * t.map.font.line_height_lg) / 2.0;
+            ((padding * 10.0).ceil() / 10.0 - t.seed.line_width).max(0.0)
+        },
+        /// Horizontal padding
+        padding_inline: f64 = |t| t.alias.padding_sm - t.seed.line_width,
+        /// Horizontal padding of small inputs
+        #[serde(rename = "paddingInlineSM")]
+        padding_inline_sm: f64 = |t| t.alias.control_padding_horizontal_sm - t.seed.line_width,
+        /// Horizontal padding of large inputs
+        #[serde(rename = "paddingInlineLG")]
+        padding_inline_lg: f64 = |t| t.alias.control_padding_horizontal - t.seed.line_width,
+        /// Background of addons
+        addon_bg: String = |t| t.alias.color_fill_alter.clone(),
+        /// Border color when focused
+        active_border_color: String = |t| t.map.colors.color_primary.clone(),
+        /// Border color when hovered
+        hover_border_color: String = |t| t.map.colors.color_primary_hover.clone(),
+        /// Shadow when focused
+        active_shadow: String = |t| format!("0 0 0 {}px {}", t.alias.control_outline_width, t.alias.control_outline),
+        /// Shadow when focused in the error state
+        error_active_shadow: String = |t| format!("0 0 0 {}px {}", t.alias.control_outline_width, t.alias.color_error_outline),
+        /// Shadow when focused in the warning state
+        warning_active_shadow: String = |t| format!("0 0 0 {}px {}", t.alias.control_outline_width, t.alias.color_warning_outline),
+        /// Background when hovered
+        hover_bg: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Background when focused
+        active_bg: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Font size
+        input_font_size: f64 = |t| t.map.font.font_size,
+        /// Font size of large inputs
+        #[serde(rename = "inputFontSizeLG")]
+        input_font_size_lg: f64 = |t| t.map.font.font_size_lg,
+        /// Font size of small inputs
+        #[serde(rename = "inputFontSizeSM")]
+        input_font_size_sm: f64 = |t| t.map.font.font_size_sm,
+    }
+
+    /// Table component token
+    TableToken, TableTokenConfig, "Table" {
+        /// Background of the header
+        header_bg: String = |t| t.alias.color_fill_alter.clone(),
+        /// Text color of the header
+        header_color: String = |t| t.alias.color_text_heading.clone(),
+        /// Background of the active sorted header
+        header_sort_active_bg: String = |t| t.map.colors.color_fill_secondary.clone(),
+        /// Background of the hovered sorted header
+        header_sort_hover_bg: String = |t| t.map.colors.color_fill_secondary.clone(),
+        /// Background of the sorted column
+        body_sort_bg: String = |t| t.alias.color_fill_alter.clone(),
+        /// Background of hovered rows
+        row_hover_bg: String = |t| t.alias.color_fill_alter.clone(),
+        /// Background of selected rows
+        row_selected_bg: String = |t| t.alias.control_item_bg_active.clone(),
+        /// Background of hovered selected rows
+        row_selected_hover_bg: String = |t| t.alias.control_item_bg_active_hover.clone(),
+        /// Background of expanded rows
+        row_expanded_bg: String = |t| t.alias.color_fill_alter.clone(),
+        /// Vertical padding of cells
+        cell_padding_block: f64 = |t| t.alias.padding,
+        /// Horizontal padding of cells
+        cell_padding_inline: f64 = |t| t.alias.padding,
+        /// Vertical padding of cells in middle size
+        #[serde(rename = "cellPaddingBlockMD")]
+        cell_padding_block_md: f64 = |t| t.alias.padding_sm,
+        /// Horizontal padding of cells in middle size
+        #[serde(rename = "cellPaddingInlineMD")]
+        cell_padding_inline_md: f64 = |t| t.alias.padding_xs,
+        /// Vertical padding of cells in small size
+        #[serde(rename = "cellPaddingBlockSM")]
+        cell_padding_block_sm: f64 = |t| t.alias.padding_xs,
+        /// Horizontal padding of cells in small size
+        #[serde(rename = "cellPaddingInlineSM")]
+        cell_padding_inline_sm: f64 = |t| t.alias.padding_xs,
+        /// Border color
+        border_color: String = |t| t.map.colors.color_border_secondary.clone(),
+        /// Border radius of the header
+        header_border_radius: f64 = |t| t.map.common.border_radius_lg,
+        /// Background of the footer
+        footer_bg: String = |t| t.alias.color_fill_alter.clone(),
+        /// Text color of the footer
+        footer_color: String = |t| t.alias.color_text_heading.clone(),
+        /// Font size of cells
+        cell_font_size: f64 = |t| t.map.font.font_size,
+        /// Font size of cells in middle size
+        #[serde(rename = "cellFontSizeMD")]
+        cell_font_size_md: f64 = |t| t.map.font.font_size,
+        /// Font size of cells in small size
+        #[serde(rename = "cellFontSizeSM")]
+        cell_font_size_sm: f64 = |t| t.map.font.font_size,
+        /// Color of the header column separators
+        header_split_color: String = |t| t.map.colors.color_border_secondary.clone(),
+        /// Background of the filter dropdown
+        filter_dropdown_bg: String = |t| t.map.colors.color_bg_container.clone(),
+        /// Background of the expand icon
+        expand_icon_bg: String = |t| t.map.colors.color_bg_container.clone(),
+    }
+
+    /// Form component token
+    FormToken, FormTokenConfig, "Form" {
+        /// Color of the required mark
+        label_required_mark_color: String = |t| t.map.colors.color_error.clone(),
+        /// Text color of labels
+        label_color: String = |t| t.alias.color_text_heading.clone(),
+        /// Font size of labels
+        label_font_size: f64 = |t| t.map.font.font_size,
+        /// Height of labels
+        label_height: f64 = |t| t.map.height.control_height,
+        /// Space before the label colon
+        label_colon_margin_inline_start: f64 = |t| t.alias.margin_xxs / 2.0,
+        /// Space after the label colon
+        label_colon_margin_inline_end: f64 = |t| t.alias.margin_xs,
+        /// Bottom margin of items
+        item_margin_bottom: f64 = |t| t.alias.margin_lg,
+        /// Padding of labels in vertical layout
+        vertical_label_padding: String = |t| format!("0 0 {}px", t.alias.padding_xs),
+        /// Margin of labels in vertical layout
+        vertical_label_margin: f64 = |_t| 0.0,
+        /// Bottom margin of items in inline layout
+        inline_item_margin_bottom: f64 = |_t| 0.0,
+    }
+
+    /// Modal component token
+    ModalToken, ModalTokenConfig, "Modal" {
+        /// Background of the header
+        header_bg: String = |t| t.map.colors.color_bg_elevated.clone(),
+        /// Background of the dialog
+        content_bg: String = |t| t.map.colors.color_bg_elevated.clone(),
+        /// Background of the footer
+        footer_bg: String = |_t| "transparent".to_string(),
+        /// Text color of the title
+        title_color: String = |t| t.alias.color_text_heading.clone(),
+        /// Font size of the title
+        title_font_size: f64 = |t| t.map.font.font_size_lg,
+        /// Line height of the title
+        title_line_height: f64 = |t| t.map.font.line_height_lg,
+        /// Padding of the dialog
+        content_padding: String = |t| format!("{}px {}px", t.alias.padding_md, t.alias.padding_content_horizontal_lg),
+        /// Space below the header
+        header_margin_bottom: f64 = |t| t.alias.margin_xs,
+        /// Space above the footer
+        footer_margin_top: f64 = |t| t.alias.margin_sm,
+        /// Width and height of the close button
+        close_btn_size: f64 = |t| t.map.height.control_height_lg * 0.55,
+        /// Size of the icon of confirm dialogs
+        confirm_icon_size: f64 = |t| t.map.font.font_size_heading3,
+    }
+}
+
+fn input_padding_block(
+    control_height: f64,
+    font_size: f64,
+    line_height: f64,
+    line_width: f64,
+) -> f64 {
+    let padding = (control_height - font_size * line_height) / 2.0;
+    ((padding * 10.0).round() / 10.0 - line_width).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_lists_every_typed_component() {
+        let names: Vec<&str> = TYPED_COMPONENTS.iter().map(|typed| typed.name).collect();
+        assert_eq!(names, ["Button", "Input", "Table", "Form", "Modal"]);
+        assert!(typed_component("Slider").is_none());
+    }
+
+    #[test]
+    fn registry_entries_match_their_token_type() {
+        let button = typed_component(ButtonToken::NAME).unwrap();
+        assert_eq!((button.fields)(), ButtonToken::field_names());
+        assert!((button.check)("fontWeight", &Value::from(600)).is_ok());
+        assert!((button.check)("fontWeight", &Value::from("bold")).is_err());
+
+        let theme = ThemeConfig::default().with_component::<ButtonToken>(ButtonTokenConfig {
+            primary_color: Some("#000".to_string()),
+            ..Default::default()
+        });
+        let values = (button.values)(&theme, &ThemeToken::default());
+        assert_eq!(values["primaryColor"], "#000");
+    }
+}
@@ -0,0 +1,375 @@
+//! 内置主题算法的派生逻辑
+
+use super::{
+    ColorMapToken, CommonMapToken, FontMapToken, HeightMapToken, MapToken, SeedToken, SizeMapToken,
+};
+use crate::utils::color::{self, Color, GenerateOptions, PaletteTheme};
+use std::collections::BTreeMap;
+
+fn parse(color: &str) -> Color {
+    Color::parse(color).unwrap_or_default()
+}
+
+fn alpha(color: &str, alpha: f64) -> String {
+    parse(color).with_alpha(alpha).to_rgb_string()
+}
+
+/// 色板生成方式
+#[derive(Clone, Copy, PartialEq)]
+enum Scheme {
+    Light,
+    Dark,
+}
+
+impl Scheme {
+    fn palette(self, color: &str) -> Vec<String> {
+        let theme = match self {
+            Scheme::Light => PaletteTheme::Default,
+            Scheme::Dark => PaletteTheme::Dark,
+        };
+        color::generate(
+            color,
+            &GenerateOptions {
+                theme,
+                background_color: None,
+            },
+        )
+    }
+
+    fn neutral(self, bg_base: &str, text_base: &str) -> NeutralColors {
+        match self {
+            Scheme::Light => {
+                let bg = if bg_base.is_empty() { "#fff" } else { bg_base };
+                let text = if text_base.is_empty() {
+                    "#000"
+                } else {
+                    text_base
+                };
+                let solid = |brightness| parse(bg).darken(brightness).to_hex_string();
+                NeutralColors {
+                    bg_base: bg.to_string(),
+                    text_base: text.to_string(),
+                    text: [0.88, 0.65, 0.45, 0.25].map(|a| alpha(text, a)),
+                    fill: [0.15, 0.06, 0.04, 0.02].map(|a| alpha(text, a)),
+                    bg_solid: [1.0, 0.75, 0.95].map(|a| alpha(text, a)),
+                    bg_layout: solid(4.0),
+                    bg_container: solid(0.0),
+                    bg_elevated: solid(0.0),
+                    bg_spotlight: alpha(text, 0.85),
+                    bg_blur: "transparent".to_string(),
+                    border: solid(15.0),
+                    border_secondary: solid(6.0),
+                }
+            }
+            Scheme::Dark => {
+                let bg = if bg_base.is_empty() { "#000" } else { bg_base };
+                let text = if text_base.is_empty() {
+                    "#fff"
+                } else {
+                    text_base
+                };
+                let solid = |brightness| parse(bg).lighten(brightness).to_hex_string();
+                NeutralColors {
+                    bg_base: bg.to_string(),
+                    text_base: text.to_string(),
+                    text: [0.85, 0.65, 0.45, 0.25].map(|a| alpha(text, a)),
+                    fill: [0.18, 0.12, 0.08, 0.04].map(|a| alpha(text, a)),
+                    bg_solid: [0.95, 1.0, 0.9].map(|a| alpha(text, a)),
+                    bg_layout: solid(0.0),
+                    bg_container: solid(8.0),
+                    bg_elevated: solid(12.0),
+                    bg_spotlight: solid(26.0),
+                    bg_blur: alpha(text, 0.04),
+                    border: solid(26.0),
+                    border_secondary: solid(19.0),
+                }
+            }
+        }
+    }
+}
+
+/// 中性色
+struct NeutralColors {
+    bg_base: String,
+    text_base: String,
+    text: [String; 4],
+    fill: [String; 4],
+    bg_solid: [String; 3],
+    bg_layout: String,
+    bg_container: String,
+    bg_elevated: String,
+    bg_spotlight: String,
+    bg_blur: String,
+    border: String,
+    border_secondary: String,
+}
+
+fn gen_color_map_token(seed: &SeedToken, scheme: Scheme) -> ColorMapToken {
+    let primary = scheme.palette(&seed.color_primary);
+    let success = scheme.palette(&seed.color_success);
+    let warning = scheme.palette(&seed.color_warning);
+    let error = scheme.palette(&seed.color_error);
+    let info = scheme.palette(&seed.color_info);
+    let link_base = if seed.color_link.is_empty() {
+        &seed.color_info
+    } else {
+        &seed.color_link
+    };
+    let link = scheme.palette(link_base);
+    let neutral = scheme.neutral(&seed.color_bg_base, &seed.color_text_base);
+    let [text, text_secondary, text_tertiary, text_quaternary] = neutral.text;
+    let [fill, fill_secondary, fill_tertiary, fill_quaternary] = neutral.fill;
+    let [bg_solid, bg_solid_hover, bg_solid_active] = neutral.bg_solid;
+
+    ColorMapToken {
+        color_primary_bg: primary[0].clone(),
+        color_primary_bg_hover: primary[1].clone(),
+        color_primary_border: primary[2].clone(),
+        color_primary_border_hover: primary[3].clone(),
+        color_primary_hover: primary[4].clone(),
+        color_primary: primary[5].clone(),
+        color_primary_active: primary[6].clone(),
+        color_primary_text_hover: primary[7].clone(),
+        color_primary_text: primary[8].clone(),
+        color_primary_text_active: primary[9].clone(),
+
+        color_success_bg: success[0].clone(),
+        color_success_bg_hover: success[1].clone(),
+        color_success_border: success[2].clone(),
+        color_success_border_hover: success[3].clone(),
+        color_success_hover: success[3].clone(),
+        color_success: success[5].clone(),
+        color_success_active: success[6].clone(),
+        color_success_text_hover: success[7].clone(),
+        color_success_text: success[8].clone(),
+        color_success_text_active: success[9].clone(),
+
+        color_warning_bg: warning[0].clone(),
+        color_warning_bg_hover: warning[1].clone(),
+        color_warning_border: warning[2].clone(),
+        color_warning_border_hover: warning[3].clone(),
+        color_warning_hover: warning[3].clone(),
+        color_warning: warning[5].clone(),
+        color_warning_active: warning[6].clone(),
+        color_warning_text_hover: warning[7].clone(),
+        color_warning_text: warning[8].clone(),
+        color_warning_text_active: warning[9].clone(),
+
+        color_error_bg: error[0].clone(),
+        color_error_bg_hover: error[1].clone(),
+        color_error_bg_filled_hover: parse(&error[0])
+            .mix(&parse(&error[2]), 50.0)
+            .to_hex_string(),
+        color_error_bg_active: error[2].clone(),
+        color_error_border: error[2].clone(),
+        color_error_border_hover: error[3].clone(),
+        color_error_hover: error[4].clone(),
+        color_error: error[5].clone(),
+        color_error_active: error[6].clone(),
+        color_error_text_hover: error[7].clone(),
+        color_error_text: error[8].clone(),
+        color_error_text_active: error[9].clone(),
+
+        color_info_bg: info[0].clone(),
+        color_info_bg_hover: info[1].clone(),
+        color_info_border: info[2].clone(),
+        color_info_border_hover: info[3].clone(),
+        color_info_hover: info[3].clone(),
+        color_info: info[5].clone(),
+        color_info_active: info[6].clone(),
+        color_info_text_hover: info[7].clone(),
+        color_info_text: info[8].clone(),
+        color_info_text_active: info[9].clone(),
+
+        color_link_hover: link[3].clone(),
+        color_link: link[5].clone(),
+        color_link_active: link[6].clone(),
+
+        color_bg_mask: alpha("#000", 0.45),
+        color_white: "#fff".to_string(),
+
+        color_bg_base: neutral.bg_base,
+        color_text_base: neutral.text_base,
+        color_text: text,
+        color_text_secondary: text_secondary,
+        color_text_tertiary: text_tertiary,
+        color_text_quaternary: text_quaternary,
+        color_fill: fill,
+        color_fill_secondary: fill_secondary,
+        color_fill_tertiary: fill_tertiary,
+        color_fill_quaternary: fill_quaternary,
+        color_bg_solid: bg_solid,
+        color_bg_solid_hover: bg_solid_hover,
+        color_bg_solid_active: bg_solid_active,
+        color_bg_layout: neutral.bg_layout,
+        color_bg_container: neutral.bg_container,
+        color_bg_elevated: neutral.bg_elevated,
+        color_bg_spotlight: neutral.bg_spotlight,
+        color_bg_blur: neutral.bg_blur,
+        color_border: neutral.border,
+        color_border_secondary: neutral.border_secondary,
+    }
+}
+
+fn gen_color_palettes(seed: &SeedToken, scheme: Scheme) -> BTreeMap<String, Vec<String>> {
+    seed.preset_colors()
+        .into_iter()
+        .map(|(name, color)| (name.to_string(), scheme.palette(color)))
+        .collect()
+}
+
+fn gen_font_sizes(base: f64) -> Vec<(f64, f64)> {
+    (0..10)
+        .map(|index| {
+            if index == 1 {
+                return base;
+            }
+            let size = base * ((index as f64 - 1.0) / 5.0).exp();
+            let int_size = if index > 1 { size.floor() } else { size.ceil() };
+            (int_size / 2.0).floor() * 2.0
+        })
+        .map(|size| (size, (size + 8.0) / size))
+        .collect()
+}
+
+fn gen_font_map_token(font_size: f64) -> FontMapToken {
+    let pairs = gen_font_sizes(font_size);
+    let size = |i: usize| pairs[i].0;
+    let line_height = |i: usize| pairs[i].1;
+
+    FontMapToken {
+        font_size_sm: size(0),
+        font_size: size(1),
+        font_size_lg: size(2),
+        font_size_xl: size(3),
+        font_size_heading1: size(6),
+        font_size_heading2: size(5),
+        font_size_heading3: size(4),
+        font_size_heading4: size(3),
+        font_size_heading5: size(2),
+        line_height: line_height(1),
+        line_height_lg: line_height(2),
+        line_height_sm: line_height(0),
+        font_height: (line_height(1) * size(1)).round(),
+        font_height_lg: (line_height(2) * size(2)).round(),
+        font_height_sm: (line_height(0) * size(0)).round(),
+        line_height_heading1: line_height(6),
+        line_height_heading2: line_height(5),
+        line_height_heading3: line_height(4),
+        line_height_heading4: line_height(3),
+        line_height_heading5: line_height(2),
+    }
+}
+
+fn gen_size_map_token(size_unit: f64, size_step: f64) -> SizeMapToken {
+    SizeMapToken {
+        size_xxl: size_unit * (size_step + 8.0),
+        size_xl: size_unit * (size_step + 4.0),
+        size_lg: size_unit * (size_step + 2.0),
+        size_md: size_unit * (size_step + 1.0),
+        size_ms: size_unit * size_step,
+        size: size_unit * size_step,
+        size_sm: size_unit * (size_step - 1.0),
+        size_xs: size_unit * (size_step - 2.0),
+        size_xxs: size_unit * (size_step - 3.0),
+    }
+}
+
+fn gen_compact_size_map_token(size_unit: f64, size_step: f64) -> SizeMapToken {
+    let step = size_step - 2.0;
+    SizeMapToken {
+        size_xxl: size_unit * (step + 10.0),
+        size_xl: size_unit * (step + 6.0),
+        size_lg: size_unit * (step + 2.0),
+        size_md: size_unit * (step + 2.0),
+        size_ms: size_unit * (step + 1.0),
+        size: size_unit * step,
+        size_sm: size_unit * step,
+        size_xs: size_unit * (step - 1.0),
+        size_xxs: size_unit * (step - 1.0),
+    }
+}
+
+fn gen_control_height(control_height: f64) -> HeightMapToken {
+    HeightMapToken {
+        control_height,
+        control_height_sm: control_height * 0.75,
+        control_height_xs: control_height * 0.5,
+        control_height_lg: control_height * 1.25,
+    }
+}
+
+fn gen_common_map_token(seed: &SeedToken) -> CommonMapToken {
+    let duration = |steps: f64| format!("{:.1}s", seed.motion_base + seed.motion_unit * steps);
+    let radius = seed.border_radius;
+
+    let radius_lg = match radius {
+        r if r >= 16.0 => 16.0,
+        r if r >= 6.0 => r + 2.0,
+        r if r >= 5.0 => r + 1.0,
+        r => r,
+    };
+    let radius_sm = match radius {
+        r if r >= 16.0 => 8.0,
+        r if r >= 14.0 => 7.0,
+        r if r >= 8.0 => 6.0,
+        r if r >= 7.0 => 5.0,
+        r if r >= 5.0 => 4.0,
+        r => r,
+    };
+    let radius_xs = match radius {
+        r if r >= 6.0 => 2.0,
+        r if r >= 2.0 => 1.0,
+        r => r,
+    };
+    let radius_outer = match radius {
+        r if r >= 8.0 => 6.0,
+        r if r > 4.0 => 4.0,
+        r => r,
+    };
+
+    CommonMapToken {
+        motion_duration_fast: duration(1.0),
+        motion_duration_mid: duration(2.0),
+        motion_duration_slow: duration(3.0),
+        line_width_bold: seed.line_width + 1.0,
+        border_radius: radius,
+        border_radius_xs: radius_xs,
+        border_radius_sm: radius_sm,
+        border_radius_lg: radius_lg,
+        border_radius_outer: radius_outer,
+    }
+}
+
+/// 默认算法
+pub(super) fn default_derivative(seed: &SeedToken) -> MapToken {
+    MapToken {
+        colors: gen_color_map_token(seed, Scheme::Light),
+        color_palettes: gen_color_palettes(seed, Scheme::Light),
+        font: gen_font_map_token(seed.font_size),
+        size: gen_size_map_token(seed.size_unit, seed.size_step),
+        height: gen_control_height(seed.control_height),
+        common: gen_common_map_token(seed),
+    }
+}
+
+/// 暗色算法
+pub(super) fn dark_derivative(seed: &SeedToken, map: Option<MapToken>) -> MapToken {
+    MapToken {
+        colors: gen_color_map_token(seed, Scheme::Dark),
+        color_palettes: gen_color_palettes(seed, Scheme::Dark),
+        ..map.unwrap_or_else(|| default_derivative(seed))
+    }
+}
+
+/// 紧凑算法
+pub(super) fn compact_derivative(seed: &SeedToken, map: Option<MapToken>) -> MapToken {
+    let map = map.unwrap_or_else(|| default_derivative(seed));
+
+    MapToken {
+        size: gen_compact_size_map_token(seed.size_unit, seed.size_step),
+        font: gen_font_map_token(map.font.font_size_sm),
+        height: gen_control_height(map.height.control_height - 4.0),
+        ..map
+    }
+}
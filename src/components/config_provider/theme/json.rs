@@ -0,0 +1,512 @@
+use super::{
+    object_fields, typed_component, CompactAlgorithm, ComponentToken, DarkAlgorithm,
+    DefaultAlgorithm, SeedToken, ThemeAlgorithm, ThemeConfig, ThemeToken,
+};
+use crate::utils::color::Color;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+
+/// Error raised when a theme JSON document is invalid
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeError {
+    /// Path of the offending key, e.g. `token.colorPrimary` or `algorithm[1]`
+    pub path: String,
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl ThemeError {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "`{}`: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+/// Look up a built-in algorithm by its antd name
+///
+/// Accepts both `dark` and `darkAlgorithm` style names.
+pub fn algorithm_from_name(name: &str) -> Option<Arc<dyn ThemeAlgorithm>> {
+    match name.strip_suffix("Algorithm").unwrap_or(name) {
+        "default" => Some(Arc::new(DefaultAlgorithm)),
+        "dark" => Some(Arc::new(DarkAlgorithm)),
+        "compact" => Some(Arc::new(CompactAlgorithm)),
+        _ => None,
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn check_value(path: &str, key: &str, value: &Value, default: &Value) -> Result<(), ThemeError> {
+    if std::mem::discriminant(value) != std::mem::discriminant(default) {
+        return Err(ThemeError::new(
+            path,
+            format!("expected {}, found {}", kind(default), kind(value)),
+        ));
+    }
+
+    if let Value::String(color) = value {
+        let is_color =
+            key.starts_with("color") || default.as_str().and_then(Color::parse).is_some();
+        if is_color && !color.is_empty() && Color::parse(color).is_none() {
+            return Err(ThemeError::new(path, format!("invalid color `{}`", color)));
+        }
+    }
+    Ok(())
+}
+
+fn parse_token(value: Value, theme: &mut ThemeConfig) -> Result<(), ThemeError> {
+    let Value::Object(tokens) = value else {
+        return Err(ThemeError::new(
+            "token",
+            format!("expected an object, found {}", kind(&value)),
+        ));
+    };
+
+    let (seed_fields, derived_fields) = global_fields();
+
+    for (key, value) in tokens {
+        let path = format!("token.{}", key);
+        if let Some(default) = seed_fields.get(&key) {
+            check_value(&path, &key, &value, default)?;
+            let mut single = Map::new();
+            single.insert(key.clone(), value.clone());
+            serde_json::from_value::<SeedToken>(Value::Object(single))
+                .map_err(|err| ThemeError::new(&path, err.to_string()))?;
+            theme.token.insert(key, value);
+        } else if let Some(default) = derived_fields.get(&key) {
+            check_value(&path, &key, &value, default)?;
+            theme.overrides.insert(key, value);
+        } else {
+            return Err(ThemeError::new(path, "unknown token"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Global token names with their default values, as accepted in `token`
+fn global_fields() -> (Map<String, Value>, Map<String, Value>) {
+    let seed_fields = object_fields(&SeedToken::default());
+    let default_token = ThemeToken::default();
+    let mut derived_fields = object_fields(&default_token.map);
+    derived_fields.remove("colorPalettes");
+    derived_fields.extend(object_fields(&default_token.alias));
+    (seed_fields, derived_fields)
+}
+
+fn parse_components(value: Value) -> Result<HashMap<String, ComponentToken>, ThemeError> {
+    let Value::Object(components) = value else {
+        return Err(ThemeError::new(
+            "components",
+            format!("expected an object, found {}", kind(&value)),
+        ));
+    };
+
+    let (seed_fields, derived_fields) = global_fields();
+    let mut parsed = HashMap::new();
+    for (name, tokens) in components {
+        let Value::Object(tokens) = tokens else {
+            return Err(ThemeError::new(
+                format!("components.{}", name),
+                format!("expected an object, found {}", kind(&tokens)),
+            ));
+        };
+
+        // 只有已知组件才校验令牌名，其余组件原样保留
+        let typed = typed_component(&name).map(|typed| (typed, (typed.fields)()));
+        let mut component = ComponentToken::default();
+        for (key, value) in tokens {
+            let path = format!("components.{}.{}", name, key);
+            if key == "algorithm" {
+                component.algorithm = value.as_bool().ok_or_else(|| {
+                    ThemeError::new(&path, format!("expected a boolean, found {}", kind(&value)))
+                })?;
+                continue;
+            }
+
+            if let Some(default) = seed_fields.get(&key).or_else(|| derived_fields.get(&key)) {
+                check_value(&path, &key, &value, default)?;
+            } else if let Some((typed, fields)) = &typed {
+                if !fields.contains(&key) {
+                    return Err(ThemeError::new(path, "unknown token"));
+                }
+                (typed.check)(&key, &value)
+                    .map_err(|err| ThemeError::new(&path, err.to_string()))?;
+            } else if value.is_array() || value.is_object() || value.is_null() {
+                return Err(ThemeError::new(
+                    path,
+                    format!(
+                        "expected a string, number or boolean, found {}",
+                        kind(&value)
+                    ),
+                ));
+            }
+            component.token.insert(key, value);
+        }
+        parsed.insert(name, component);
+    }
+    Ok(parsed)
+}
+
+fn parse_algorithm(value: Value) -> Result<Vec<Arc<dyn ThemeAlgorithm>>, ThemeError> {
+    let (names, indexed) = match value {
+        Value::String(name) => (vec![Value::String(name)], false),
+        Value::Array(names) => (names, true),
+        other => {
+            return Err(ThemeError::new(
+                "algorithm",
+                format!("expected a string or an array, found {}", kind(&other)),
+            ))
+        }
+    };
+
+    names
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let path = if indexed {
+                format!("algorithm[{}]", index)
+            } else {
+                "algorithm".to_string()
+            };
+            let Value::String(name) = name else {
+                return Err(ThemeError::new(
+                    path,
+                    format!("expected a string, found {}", kind(&name)),
+                ));
+            };
+            algorithm_from_name(&name)
+                .ok_or_else(|| ThemeError::new(path, format!("unknown algorithm `{}`", name)))
+        })
+        .collect()
+}
+
+/// Write whole numbers without a fractional part, like JavaScript does
+fn export_number(value: Value) -> Value {
+    match value.as_f64() {
+        Some(number) if value.is_f64() && number.fract() == 0.0 && number.abs() < 1e15 => {
+            Value::from(number as i64)
+        }
+        _ => value,
+    }
+}
+
+impl ThemeConfig {
+    /// Load a theme from antd's JSON format, as exported by the Theme Editor
+    ///
+    /// ```json
+    /// { "token": { "colorPrimary": "#00b96b" }, "components": { "Button": { "colorPrimary": "#fff" } }, "algorithm": ["dark"] }
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, ThemeError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|err| ThemeError::new("", err.to_string()))?;
+        Self::from_json_value(value)
+    }
+
+    /// Load a theme from an already parsed antd JSON value
+    pub fn from_json_value(value: Value) -> Result<Self, ThemeError> {
+        let Value::Object(fields) = value else {
+            return Err(ThemeError::new(
+                "",
+                format!("expected an object, found {}", kind(&value)),
+            ));
+        };
+
+        let mut theme = ThemeConfig::default();
+        for (key, value) in fields {
+            match key.as_str() {
+                "token" => parse_token(value, &mut theme)?,
+                "components" => theme.components = parse_components(value)?,
+                "algorithm" => theme.algorithm = parse_algorithm(value)?,
+                "inherit" => {
+                    theme.inherit = value.as_bool().ok_or_else(|| {
+                        ThemeError::new(
+                            "inherit",
+                            format!("expected a boolean, found {}", kind(&value)),
+                        )
+                    })?
+                }
+                _ => return Err(ThemeError::new(key, "unknown theme option")),
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// Export the theme in antd's JSON format
+    ///
+    /// Only the seed tokens the theme sets are written. Fails when the theme
+    /// uses a custom [`ThemeAlgorithm`], which antd's JSON cannot name.
+    pub fn to_json_value(&self) -> Result<Value, ThemeError> {
+        let mut token: Map<String, Value> = self
+            .token
+            .iter()
+            .map(|(key, value)| (key.clone(), export_number(value.clone())))
+            .collect();
+        token.extend(self.overrides.clone());
+
+        let mut json = Map::new();
+        json.insert("token".to_string(), Value::Object(token));
+
+        if !self.components.is_empty() {
+            let components: BTreeMap<&String, Map<String, Value>> = self
+                .components
+                .iter()
+                .map(|(name, component)| {
+                    let mut tokens: Map<String, Value> = component
+                        .token
+                        .iter()
+                        .map(|(key, value)| (key.clone(), export_number(value.clone())))
+                        .collect();
+                    if component.algorithm {
+                        tokens.insert("algorithm".to_string(), Value::Bool(true));
+                    }
+                    (name, tokens)
+                })
+                .collect();
+            json.insert(
+                "components".to_string(),
+                serde_json::to_value(components).unwrap_or_default(),
+            );
+        }
+
+        if !self.algorithm.is_empty() {
+            let names = self
+                .algorithm
+                .iter()
+                .enumerate()
+                .map(
+                    |(index, algorithm)| match algorithm_from_name(algorithm.name()) {
+                        Some(_) => Ok(Value::from(algorithm.name())),
+                        None => Err(ThemeError::new(
+                            format!("algorithm[{}]", index),
+                            format!("custom algorithm `{}` cannot be exported", algorithm.name()),
+                        )),
+                    },
+                )
+                .collect::<Result<Vec<_>, _>>()?;
+            json.insert("algorithm".to_string(), Value::Array(names));
+        }
+
+        if !self.inherit {
+            json.insert("inherit".to_string(), Value::Bool(false));
+        }
+
+        Ok(Value::Object(json))
+    }
+
+    /// Export the theme as pretty-printed antd JSON
+    pub fn to_json(&self) -> Result<String, ThemeError> {
+        let value = self.to_json_value()?;
+        serde_json::to_string_pretty(&value).map_err(|err| ThemeError::new("", err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::config_provider::theme::MapToken;
+    use serde_json::json;
+
+    fn error(value: Value) -> ThemeError {
+        ThemeConfig::from_json_value(value).expect_err("the theme should be rejected")
+    }
+
+    fn assert_error(value: Value, path: &str, message: &str) {
+        let error = error(value);
+        assert_eq!(error.path, path, "{}", error);
+        assert!(
+            error.message.contains(message),
+            "`{}` should contain `{}`",
+            error.message,
+            message
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        let error = ThemeConfig::from_json("{").expect_err("invalid JSON");
+        assert_eq!(error.path, "");
+        assert_error(json!([]), "", "expected an object, found an array");
+        assert_error(json!({ "colors": {} }), "colors", "unknown theme option");
+        assert_error(json!({ "inherit": "no" }), "inherit", "expected a boolean");
+    }
+
+    #[test]
+    fn rejects_invalid_global_tokens() {
+        assert_error(json!({ "token": [] }), "token", "expected an object");
+        assert_error(
+            json!({ "token": { "colorPrimery": "#fff" } }),
+            "token.colorPrimery",
+            "unknown token",
+        );
+        assert_error(
+            json!({ "token": { "borderRadius": "4px" } }),
+            "token.borderRadius",
+            "expected a number, found a string",
+        );
+        assert_error(
+            json!({ "token": { "colorPrimary": "blurple" } }),
+            "token.colorPrimary",
+            "invalid color `blurple`",
+        );
+        assert_error(
+            json!({ "token": { "colorBgLayout": "nope" } }),
+            "token.colorBgLayout",
+            "invalid color",
+        );
+        assert_error(
+            json!({ "token": { "zIndexBase": 1.5 } }),
+            "token.zIndexBase",
+            "",
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_component_tokens() {
+        assert_error(
+            json!({ "components": 1 }),
+            "components",
+            "expected an object",
+        );
+        assert_error(
+            json!({ "components": { "Button": true } }),
+            "components.Button",
+            "expected an object, found a boolean",
+        );
+        assert_error(
+            json!({ "components": { "Button": { "algorithm": "yes" } } }),
+            "components.Button.algorithm",
+            "expected a boolean",
+        );
+        assert_error(
+            json!({ "components": { "Button": { "primaryColour": "#fff" } } }),
+            "components.Button.primaryColour",
+            "unknown token",
+        );
+        assert_error(
+            json!({ "components": { "Button": { "fontWeight": "bold" } } }),
+            "components.Button.fontWeight",
+            "",
+        );
+        assert_error(
+            json!({ "components": { "Button": { "colorPrimary": 1 } } }),
+            "components.Button.colorPrimary",
+            "expected a string, found a number",
+        );
+        assert_error(
+            json!({ "components": { "Slider": { "railSize": [4] } } }),
+            "components.Slider.railSize",
+            "expected a string, number or boolean, found an array",
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_components_as_is() {
+        let theme =
+            ThemeConfig::from_json_value(json!({ "components": { "Slider": { "railSize": 4 } } }))
+                .unwrap();
+        assert_eq!(theme.components["Slider"].token["railSize"], json!(4));
+    }
+
+    #[test]
+    fn rejects_invalid_algorithms() {
+        assert_error(
+            json!({ "algorithm": 1 }),
+            "algorithm",
+            "expected a string or an array",
+        );
+        assert_error(
+            json!({ "algorithm": "neon" }),
+            "algorithm",
+            "unknown algorithm `neon`",
+        );
+        assert_error(
+            json!({ "algorithm": ["dark", false] }),
+            "algorithm[1]",
+            "expected a string, found a boolean",
+        );
+    }
+
+    #[test]
+    fn accepts_both_algorithm_name_styles() {
+        let theme =
+            ThemeConfig::from_json_value(json!({ "algorithm": ["darkAlgorithm", "compact"] }))
+                .unwrap();
+        let names: Vec<&str> = theme
+            .algorithm
+            .iter()
+            .map(|algorithm| algorithm.name())
+            .collect();
+        assert_eq!(names, ["dark", "compact"]);
+    }
+
+    #[test]
+    fn export_round_trips() {
+        let original = json!({
+            "token": { "colorPrimary": "#00b96b", "borderRadius": 2, "colorBgLayout": "#f0f0f0" },
+            "components": {
+                "Button": { "primaryColor": "#fff", "fontWeight": 600, "algorithm": true },
+                "Slider": { "railSize": 4 }
+            },
+            "algorithm": ["dark", "compact"],
+            "inherit": false
+        });
+        let theme = ThemeConfig::from_json_value(original.clone()).unwrap();
+        let exported = theme.to_json_value().unwrap();
+        assert_eq!(exported, original);
+
+        let reloaded = ThemeConfig::from_json(&theme.to_json().unwrap()).unwrap();
+        assert_eq!(reloaded.to_json_value().unwrap(), original);
+        assert_eq!(reloaded.seed(), theme.seed());
+        assert_eq!(reloaded.components, theme.components);
+    }
+
+    #[test]
+    fn export_rejects_custom_algorithms() {
+        struct Neon;
+
+        impl ThemeAlgorithm for Neon {
+            fn name(&self) -> &str {
+                "neon"
+            }
+
+            fn derive(&self, seed: &SeedToken, map: Option<MapToken>) -> MapToken {
+                DarkAlgorithm.derive(seed, map)
+            }
+        }
+
+        let theme = ThemeConfig {
+            algorithm: vec![Arc::new(DarkAlgorithm), Arc::new(Neon)],
+            ..ThemeConfig::default()
+        };
+        let error = theme.to_json_value().expect_err("custom algorithm");
+        assert_eq!(error.path, "algorithm[1]");
+        assert!(theme.to_json().is_err());
+    }
+}
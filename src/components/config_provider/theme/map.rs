@@ -0,0 +1,321 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Map token, produced from the seed token by a theme algorithm
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MapToken {
+    /// Derived colors
+    #[serde(flatten)]
+    pub colors: ColorMapToken,
+    /// Ten-step palettes of every preset color, keyed by preset name
+    pub color_palettes: BTreeMap<String, Vec<String>>,
+    /// Font sizes and line heights
+    #[serde(flatten)]
+    pub font: FontMapToken,
+    /// Spacing sizes
+    #[serde(flatten)]
+    pub size: SizeMapToken,
+    /// Control heights
+    #[serde(flatten)]
+    pub height: HeightMapToken,
+    /// Motion durations, line widths and radii
+    #[serde(flatten)]
+    pub common: CommonMapToken,
+}
+
+/// Colors derived from the brand and neutral seed colors
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ColorMapToken {
+    /// Primary background
+    pub color_primary_bg: String,
+    /// Primary background on hover
+    pub color_primary_bg_hover: String,
+    /// Primary border
+    pub color_primary_border: String,
+    /// Primary border on hover
+    pub color_primary_border_hover: String,
+    /// Primary on hover
+    pub color_primary_hover: String,
+    /// Primary
+    pub color_primary: String,
+    /// Primary when active
+    pub color_primary_active: String,
+    /// Primary text on hover
+    pub color_primary_text_hover: String,
+    /// Primary text
+    pub color_primary_text: String,
+    /// Primary text when active
+    pub color_primary_text_active: String,
+
+    /// Success background
+    pub color_success_bg: String,
+    /// Success background on hover
+    pub color_success_bg_hover: String,
+    /// Success border
+    pub color_success_border: String,
+    /// Success border on hover
+    pub color_success_border_hover: String,
+    /// Success on hover
+    pub color_success_hover: String,
+    /// Success
+    pub color_success: String,
+    /// Success when active
+    pub color_success_active: String,
+    /// Success text on hover
+    pub color_success_text_hover: String,
+    /// Success text
+    pub color_success_text: String,
+    /// Success text when active
+    pub color_success_text_active: String,
+
+    /// Warning background
+    pub color_warning_bg: String,
+    /// Warning background on hover
+    pub color_warning_bg_hover: String,
+    /// Warning border
+    pub color_warning_border: String,
+    /// Warning border on hover
+    pub color_warning_border_hover: String,
+    /// Warning on hover
+    pub color_warning_hover: String,
+    /// Warning
+    pub color_warning: String,
+    /// Warning when active
+    pub color_warning_active: String,
+    /// Warning text on hover
+    pub color_warning_text_hover: String,
+    /// Warning text
+    pub color_warning_text: String,
+    /// Warning text when active
+    pub color_warning_text_active: String,
+
+    /// Error background
+    pub color_error_bg: String,
+    /// Error background on hover
+    pub color_error_bg_hover: String,
+    /// Filled error background on hover
+    pub color_error_bg_filled_hover: String,
+    /// Error background when active
+    pub color_error_bg_active: String,
+    /// Error border
+    pub color_error_border: String,
+    /// Error border on hover
+    pub color_error_border_hover: String,
+    /// Error on hover
+    pub color_error_hover: String,
+    /// Error
+    pub color_error: String,
+    /// Error when active
+    pub color_error_active: String,
+    /// Error text on hover
+    pub color_error_text_hover: String,
+    /// Error text
+    pub color_error_text: String,
+    /// Error text when active
+    pub color_error_text_active: String,
+
+    /// Info background
+    pub color_info_bg: String,
+    /// Info background on hover
+    pub color_info_bg_hover: String,
+    /// Info border
+    pub color_info_border: String,
+    /// Info border on hover
+    pub color_info_border_hover: String,
+    /// Info on hover
+    pub color_info_hover: String,
+    /// Info
+    pub color_info: String,
+    /// Info when active
+    pub color_info_active: String,
+    /// Info text on hover
+    pub color_info_text_hover: String,
+    /// Info text
+    pub color_info_text: String,
+    /// Info text when active
+    pub color_info_text_active: String,
+
+    /// Link on hover
+    pub color_link_hover: String,
+    /// Link
+    pub color_link: String,
+    /// Link when active
+    pub color_link_active: String,
+
+    /// Mask background
+    pub color_bg_mask: String,
+    /// Pure white
+    pub color_white: String,
+
+    /// Resolved base background color
+    pub color_bg_base: String,
+    /// Resolved base text color
+    pub color_text_base: String,
+    /// Primary text
+    pub color_text: String,
+    /// Secondary text
+    pub color_text_secondary: String,
+    /// Tertiary text
+    pub color_text_tertiary: String,
+    /// Quaternary text
+    pub color_text_quaternary: String,
+    /// Strongest fill
+    pub color_fill: String,
+    /// Secondary fill
+    pub color_fill_secondary: String,
+    /// Tertiary fill
+    pub color_fill_tertiary: String,
+    /// Weakest fill
+    pub color_fill_quaternary: String,
+    /// Solid background
+    pub color_bg_solid: String,
+    /// Solid background on hover
+    pub color_bg_solid_hover: String,
+    /// Solid background when active
+    pub color_bg_solid_active: String,
+    /// Layout background
+    pub color_bg_layout: String,
+    /// Container background
+    pub color_bg_container: String,
+    /// Elevated container background (popups)
+    pub color_bg_elevated: String,
+    /// Spotlight background (tooltips)
+    pub color_bg_spotlight: String,
+    /// Frosted glass background
+    pub color_bg_blur: String,
+    /// Default border
+    pub color_border: String,
+    /// Secondary border
+    pub color_border_secondary: String,
+}
+
+/// Font sizes and line heights
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FontMapToken {
+    /// Small font size
+    #[serde(rename = "fontSizeSM")]
+    pub font_size_sm: f64,
+    /// Standard font size
+    pub font_size: f64,
+    /// Large font size
+    #[serde(rename = "fontSizeLG")]
+    pub font_size_lg: f64,
+    /// Extra large font size
+    #[serde(rename = "fontSizeXL")]
+    pub font_size_xl: f64,
+    /// Heading 1 font size
+    pub font_size_heading1: f64,
+    /// Heading 2 font size
+    pub font_size_heading2: f64,
+    /// Heading 3 font size
+    pub font_size_heading3: f64,
+    /// Heading 4 font size
+    pub font_size_heading4: f64,
+    /// Heading 5 font size
+    pub font_size_heading5: f64,
+    /// Standard line height
+    pub line_height: f64,
+    /// Large line height
+    #[serde(rename = "lineHeightLG")]
+    pub line_height_lg: f64,
+    /// Small line height
+    #[serde(rename = "lineHeightSM")]
+    pub line_height_sm: f64,
+    /// Rendered height of standard text
+    pub font_height: f64,
+    /// Rendered height of large text
+    #[serde(rename = "fontHeightLG")]
+    pub font_height_lg: f64,
+    /// Rendered height of small text
+    #[serde(rename = "fontHeightSM")]
+    pub font_height_sm: f64,
+    /// Heading 1 line height
+    pub line_height_heading1: f64,
+    /// Heading 2 line height
+    pub line_height_heading2: f64,
+    /// Heading 3 line height
+    pub line_height_heading3: f64,
+    /// Heading 4 line height
+    pub line_height_heading4: f64,
+    /// Heading 5 line height
+    pub line_height_heading5: f64,
+}
+
+/// Spacing sizes
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SizeMapToken {
+    /// XXL size
+    #[serde(rename = "sizeXXL")]
+    pub size_xxl: f64,
+    /// XL size
+    #[serde(rename = "sizeXL")]
+    pub size_xl: f64,
+    /// LG size
+    #[serde(rename = "sizeLG")]
+    pub size_lg: f64,
+    /// MD size
+    #[serde(rename = "sizeMD")]
+    pub size_md: f64,
+    /// MS size
+    #[serde(rename = "sizeMS")]
+    pub size_ms: f64,
+    /// Default size
+    pub size: f64,
+    /// SM size
+    #[serde(rename = "sizeSM")]
+    pub size_sm: f64,
+    /// XS size
+    #[serde(rename = "sizeXS")]
+    pub size_xs: f64,
+    /// XXS size
+    #[serde(rename = "sizeXXS")]
+    pub size_xxs: f64,
+}
+
+/// Control heights
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HeightMapToken {
+    /// Standard control height
+    pub control_height: f64,
+    /// Small control height
+    #[serde(rename = "controlHeightSM")]
+    pub control_height_sm: f64,
+    /// Extra small control height
+    #[serde(rename = "controlHeightXS")]
+    pub control_height_xs: f64,
+    /// Large control height
+    #[serde(rename = "controlHeightLG")]
+    pub control_height_lg: f64,
+}
+
+/// Motion durations, line widths and radii
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CommonMapToken {
+    /// Fast motion duration
+    pub motion_duration_fast: String,
+    /// Medium motion duration
+    pub motion_duration_mid: String,
+    /// Slow motion duration
+    pub motion_duration_slow: String,
+    /// Bold border width
+    pub line_width_bold: f64,
+    /// Standard border radius
+    pub border_radius: f64,
+    /// Extra small border radius
+    #[serde(rename = "borderRadiusXS")]
+    pub border_radius_xs: f64,
+    /// Small border radius
+    #[serde(rename = "borderRadiusSM")]
+    pub border_radius_sm: f64,
+    /// Large border radius
+    #[serde(rename = "borderRadiusLG")]
+    pub border_radius_lg: f64,
+    /// Outer border radius
+    pub border_radius_outer: f64,
+}
@@ -0,0 +1,498 @@
+//! 主题令牌
+//!
+//! 与 antd v5 一致，令牌分三层派生：种子令牌（SeedToken）经过主题算法得到
+//! 映射令牌（MapToken），再由映射令牌得到别名令牌（AliasToken）。
+
+mod alias;
+mod components;
+mod derivative;
+mod json;
+mod map;
+mod seed;
+
+pub use alias::*;
+pub use components::*;
+pub use json::*;
+pub use map::*;
+pub use seed::*;
+
+use crate::utils::hash::hash_str;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Fully derived theme token
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ThemeToken {
+    /// Seed token the rest was derived from
+    pub seed: SeedToken,
+    /// Map token produced by the theme algorithms
+    pub map: MapToken,
+    /// Alias token derived from the map token
+    pub alias: AliasToken,
+}
+
+/// Theme algorithm configuration
+#[derive(Clone, Debug)]
+pub struct ThemeAlgorithmConfig {
+    /// Theme algorithm
+    pub algorithm: Vec<Arc<dyn ThemeAlgorithm>>,
+    /// Theme token
+    pub token: ThemeToken,
+    /// Component tokens
+    pub components: HashMap<String, ComponentToken>,
+}
+
+/// Theme algorithm, deriving the map token from the seed token
+///
+/// Algorithms compose like antd's `algorithm: [darkAlgorithm, compactAlgorithm]`:
+/// each one receives the map token produced by the previous one, or `None`
+/// when it runs first.
+pub trait ThemeAlgorithm: Send + Sync {
+    /// Algorithm name, e.g. `"dark"`
+    fn name(&self) -> &str;
+
+    /// Derive the map token from the seed, on top of the previous algorithm's output
+    fn derive(&self, seed: &SeedToken, map: Option<MapToken>) -> MapToken;
+}
+
+impl fmt::Debug for dyn ThemeAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ThemeAlgorithm").field(&self.name()).finish()
+    }
+}
+
+/// Default light algorithm
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultAlgorithm;
+
+/// Dark algorithm
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DarkAlgorithm;
+
+/// Compact algorithm
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CompactAlgorithm;
+
+impl ThemeAlgorithm for DefaultAlgorithm {
+    fn name(&self) -> &str {
+        "default"
+    }
+
+    fn derive(&self, seed: &SeedToken, _map: Option<MapToken>) -> MapToken {
+        derivative::default_derivative(seed)
+    }
+}
+
+impl ThemeAlgorithm for DarkAlgorithm {
+    fn name(&self) -> &str {
+        "dark"
+    }
+
+    fn derive(&self, seed: &SeedToken, map: Option<MapToken>) -> MapToken {
+        derivative::dark_derivative(seed, map)
+    }
+}
+
+impl ThemeAlgorithm for CompactAlgorithm {
+    fn name(&self) -> &str {
+        "compact"
+    }
+
+    fn derive(&self, seed: &SeedToken, map: Option<MapToken>) -> MapToken {
+        derivative::compact_derivative(seed, map)
+    }
+}
+
+/// Component theme overrides, like one entry of antd's `components` option
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComponentToken {
+    /// Overridden values by antd name, either component tokens or global tokens
+    pub token: Map<String, Value>,
+    /// Whether to re-run the theme algorithms on the overridden global tokens
+    pub algorithm: bool,
+}
+
+impl ComponentToken {
+    /// Overrides from a typed component config
+    pub fn from_config<T: ComponentTokenType>(config: &T::Config) -> Self {
+        Self {
+            token: object_fields(config),
+            algorithm: false,
+        }
+    }
+
+    /// Also override the seed tokens that differ from the defaults
+    pub fn with_seed(mut self, seed: &SeedToken) -> Self {
+        let defaults = object_fields(&SeedToken::default());
+        self.token.extend(
+            object_fields(seed)
+                .into_iter()
+                .filter(|(key, value)| defaults.get(key) != Some(value)),
+        );
+        self
+    }
+
+    /// Set whether to re-run the theme algorithms on overridden global tokens
+    pub fn with_algorithm(mut self, algorithm: bool) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+}
+
+/// Serialize a token into its antd-named fields
+fn object_fields<T: Serialize>(token: &T) -> Map<String, Value> {
+    match serde_json::to_value(token) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    }
+}
+
+impl Default for ThemeToken {
+    fn default() -> Self {
+        Self::derive(&SeedToken::default(), &[])
+    }
+}
+
+impl ThemeToken {
+    /// Derive all tokens from a seed, running the algorithms in order
+    ///
+    /// An empty algorithm list behaves like `[DefaultAlgorithm]`.
+    pub fn derive(seed: &SeedToken, algorithms: &[Arc<dyn ThemeAlgorithm>]) -> Self {
+        Self::derive_with_overrides(seed, algorithms, &Map::new())
+    }
+
+    /// Derive all tokens, then override map and alias tokens by their antd name
+    ///
+    /// Map overrides are applied before the alias token is derived, so aliases
+    /// follow them unless overridden themselves.
+    pub fn derive_with_overrides(
+        seed: &SeedToken,
+        algorithms: &[Arc<dyn ThemeAlgorithm>],
+        overrides: &Map<String, Value>,
+    ) -> Self {
+        let mut map = algorithms
+            .iter()
+            .fold(None, |map, algorithm| Some(algorithm.derive(seed, map)))
+            .unwrap_or_else(|| derivative::default_derivative(seed));
+        map = apply_overrides(map, overrides);
+
+        if !seed.motion {
+            map.common.motion_duration_fast = "0s".to_string();
+            map.common.motion_duration_mid = "0s".to_string();
+            map.common.motion_duration_slow = "0s".to_string();
+        }
+
+        let alias = apply_overrides(AliasToken::derive(seed, &map), overrides);
+        Self {
+            seed: seed.clone(),
+            map,
+            alias,
+        }
+    }
+
+    /// Flatten into antd-style `{ tokenName: value }` pairs
+    ///
+    /// Later stages win over earlier ones, and preset palettes are expanded to
+    /// `blue1` ... `blue10` keys like antd does.
+    pub fn flatten(&self) -> Map<String, Value> {
+        let mut tokens = Map::new();
+        for stage in [
+            serde_json::to_value(&self.seed),
+            serde_json::to_value(&self.map),
+            serde_json::to_value(&self.alias),
+        ] {
+            if let Ok(Value::Object(stage)) = stage {
+                tokens.extend(stage);
+            }
+        }
+
+        tokens.remove("colorPalettes");
+        for (name, palette) in &self.map.color_palettes {
+            for (index, color) in palette.iter().enumerate() {
+                tokens.insert(
+                    format!("{}{}", name, index + 1),
+                    Value::from(color.as_str()),
+                );
+            }
+        }
+
+        tokens
+    }
+
+    /// Stable hash of the token values
+    pub fn hash(&self) -> String {
+        hash_str(&serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+/// Replace the fields of a token that appear in `overrides`
+fn apply_overrides<T>(token: T, overrides: &Map<String, Value>) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    if overrides.is_empty() {
+        return token;
+    }
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(&token) else {
+        return token;
+    };
+    for (key, value) in overrides {
+        if let Some(field) = fields.get_mut(key) {
+            *field = value.clone();
+        }
+    }
+    serde_json::from_value(Value::Object(fields)).unwrap_or(token)
+}
+
+/// Theme of a ConfigProvider, like antd's `theme` prop
+///
+/// The seed tokens set here run through the algorithm list to produce the
+/// design token; `overrides` then replace single map or alias tokens and
+/// `components` adjust individual components. Nested providers merge their
+/// theme into the parent's unless `inherit` is `false`.
+#[derive(Clone, Debug)]
+pub struct ThemeConfig {
+    /// Seed tokens set by this theme, by antd name, e.g. `colorPrimary`
+    ///
+    /// Tokens left out inherit from the parent provider, or keep their
+    /// default at the root.
+    pub token: Map<String, Value>,
+    /// Theme algorithms, applied in order; empty means the default algorithm
+    pub algorithm: Vec<Arc<dyn ThemeAlgorithm>>,
+    /// Component tokens
+    pub components: HashMap<String, ComponentToken>,
+    /// Map and alias tokens overridden by their antd name, e.g. `colorBgContainer`
+    pub overrides: Map<String, Value>,
+    /// Whether to inherit the theme of the parent ConfigProvider
+    pub inherit: bool,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            token: Map::new(),
+            algorithm: Vec::new(),
+            components: HashMap::new(),
+            overrides: Map::new(),
+            inherit: true,
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Primary color seed
+    pub fn primary_color(&self) -> String {
+        self.seed().color_primary
+    }
+
+    /// Info color seed
+    pub fn info_color(&self) -> String {
+        self.seed().color_info
+    }
+
+    /// Success color seed
+    pub fn success_color(&self) -> String {
+        self.seed().color_success
+    }
+
+    /// Warning color seed
+    pub fn warning_color(&self) -> String {
+        self.seed().color_warning
+    }
+
+    /// Error color seed
+    pub fn error_color(&self) -> String {
+        self.seed().color_error
+    }
+
+    /// Seed token with the tokens this theme sets applied over the defaults
+    pub fn seed(&self) -> SeedToken {
+        apply_overrides(SeedToken::default(), &self.token)
+    }
+
+    /// Set every seed token, so none of them is inherited
+    pub fn with_seed(mut self, seed: &SeedToken) -> Self {
+        self.token = object_fields(seed);
+        self
+    }
+
+    /// Set one seed token by its antd name
+    ///
+    /// Names that are not seed tokens are ignored when deriving.
+    pub fn with_token(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.token.insert(name.into(), value.into());
+        self
+    }
+
+    /// Derive the full theme token for this configuration
+    pub fn derive_token(&self) -> ThemeToken {
+        ThemeToken::derive_with_overrides(&self.seed(), &self.algorithm, &self.overrides)
+    }
+
+    /// Add typed overrides for a component
+    pub fn with_component<T: ComponentTokenType>(mut self, config: T::Config) -> Self {
+        let component = ComponentToken::from_config::<T>(&config);
+        self.components
+            .entry(T::NAME.to_string())
+            .and_modify(|merged| merged.token.extend(component.token.clone()))
+            .or_insert(component);
+        self
+    }
+
+    /// Global token as seen by a component, with its global overrides applied
+    ///
+    /// When the component sets `algorithm`, the theme algorithms run again on
+    /// the overridden seed, like antd does; otherwise only the overridden
+    /// tokens change.
+    pub fn component_theme_token(&self, name: &str, token: &ThemeToken) -> ThemeToken {
+        let Some(component) = self.components.get(name) else {
+            return token.clone();
+        };
+        let component_fields = typed_component(name)
+            .map(|typed| (typed.fields)())
+            .unwrap_or_default();
+        let globals: Map<String, Value> = component
+            .token
+            .iter()
+            .filter(|(key, _)| !component_fields.contains(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        if globals.is_empty() {
+            return token.clone();
+        }
+
+        let seed = apply_overrides(token.seed.clone(), &globals);
+        if component.algorithm {
+            let mut overrides = self.overrides.clone();
+            overrides.extend(globals);
+            ThemeToken::derive_with_overrides(&seed, &self.algorithm, &overrides)
+        } else {
+            ThemeToken {
+                seed,
+                map: apply_overrides(token.map.clone(), &globals),
+                alias: apply_overrides(token.alias.clone(), &globals),
+            }
+        }
+    }
+
+    /// Resolve a typed component token from the derived global token
+    pub fn component_token<T: ComponentTokenType>(&self, token: &ThemeToken) -> T {
+        let config: T::Config = self
+            .components
+            .get(T::NAME)
+            .and_then(|component| {
+                serde_json::from_value(Value::Object(component.token.clone())).ok()
+            })
+            .unwrap_or_default();
+        T::derive(&self.component_theme_token(T::NAME, token)).apply(&config)
+    }
+
+    /// Merge a nested provider's theme over this one
+    ///
+    /// Seed tokens and component tokens are merged key by key, so a child can
+    /// also set a token back to its default value. The child's algorithms
+    /// replace the parent's only when it sets any. A child with
+    /// `inherit: false` is returned unchanged.
+    pub fn merge(&self, child: ThemeConfig) -> ThemeConfig {
+        if !child.inherit {
+            return child;
+        }
+
+        let mut components = self.components.clone();
+        for (name, component) in child.components.iter() {
+            components
+                .entry(name.clone())
+                .and_modify(|merged| {
+                    merged.token.extend(component.token.clone());
+                    merged.algorithm |= component.algorithm;
+                })
+                .or_insert_with(|| component.clone());
+        }
+
+        let mut token = self.token.clone();
+        token.extend(child.token.clone());
+        let mut overrides = self.overrides.clone();
+        overrides.extend(child.overrides.clone());
+
+        ThemeConfig {
+            token,
+            overrides,
+            algorithm: if child.algorithm.is_empty() {
+                self.algorithm.clone()
+            } else {
+                child.algorithm.clone()
+            },
+            components,
+            ..child
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn red() -> ThemeConfig {
+        ThemeConfig::default().with_token("colorPrimary", "#f5222d")
+    }
+
+    #[test]
+    fn nested_theme_inherits_unset_seed_tokens() {
+        let child = ThemeConfig::default().with_token("borderRadius", 2);
+        let seed = red().merge(child).seed();
+        assert_eq!(seed.color_primary, "#f5222d");
+        assert_eq!(seed.border_radius, 2.0);
+    }
+
+    #[test]
+    fn nested_theme_can_reset_a_seed_token_to_its_default() {
+        let default_primary = SeedToken::default().color_primary;
+        let child = ThemeConfig::default().with_token("colorPrimary", default_primary.as_str());
+        assert_eq!(red().merge(child).seed().color_primary, default_primary);
+    }
+
+    #[test]
+    fn theme_without_inherit_ignores_the_parent() {
+        let child = ThemeConfig {
+            inherit: false,
+            ..ThemeConfig::default()
+        };
+        assert_eq!(
+            red().merge(child).seed().color_primary,
+            SeedToken::default().color_primary
+        );
+    }
+
+    #[test]
+    fn with_seed_sets_every_token() {
+        let child = ThemeConfig::default().with_seed(&SeedToken::default());
+        assert_eq!(red().merge(child).seed(), SeedToken::default());
+    }
+
+    #[test]
+    fn theme_mode_replaces_the_parent_algorithm_and_keeps_its_tokens() {
+        use crate::components::config_provider::ThemeMode;
+
+        let mode = |mode: ThemeMode| ThemeConfig {
+            algorithm: mode.algorithm(),
+            ..ThemeConfig::default()
+        };
+        let dark = red().merge(mode(ThemeMode::Dark)).derive_token();
+        assert_eq!(dark.seed.color_primary, "#f5222d");
+        assert_eq!(dark.map.colors.color_bg_container, "#141414");
+
+        let light = red()
+            .merge(mode(ThemeMode::Dark))
+            .merge(mode(ThemeMode::Light));
+        assert_eq!(
+            light.derive_token().map.colors.color_bg_container,
+            "#ffffff"
+        );
+
+        let compact = red().merge(mode(ThemeMode::Compact)).derive_token();
+        assert_eq!(compact.map.height.control_height, 28.0);
+    }
+}
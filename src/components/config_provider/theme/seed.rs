@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+
+/// Seed token, the design inputs every other token is derived from
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SeedToken {
+    /// Brand color
+    pub color_primary: String,
+    /// Color for successful operations
+    pub color_success: String,
+    /// Color for warning states
+    pub color_warning: String,
+    /// Color for error states
+    pub color_error: String,
+    /// Color for informational states
+    pub color_info: String,
+    /// Hyperlink color, falls back to `color_info` when empty
+    pub color_link: String,
+    /// Base text color, empty means the algorithm default
+    pub color_text_base: String,
+    /// Base background color, empty means the algorithm default
+    pub color_bg_base: String,
+
+    /// Preset blue
+    pub blue: String,
+    /// Preset purple
+    pub purple: String,
+    /// Preset cyan
+    pub cyan: String,
+    /// Preset green
+    pub green: String,
+    /// Preset magenta
+    pub magenta: String,
+    /// Preset pink
+    pub pink: String,
+    /// Preset red
+    pub red: String,
+    /// Preset orange
+    pub orange: String,
+    /// Preset yellow
+    pub yellow: String,
+    /// Preset volcano
+    pub volcano: String,
+    /// Preset geekblue
+    pub geekblue: String,
+    /// Preset gold
+    pub gold: String,
+    /// Preset lime
+    pub lime: String,
+
+    /// Font family
+    pub font_family: String,
+    /// Font family for code
+    pub font_family_code: String,
+    /// Base font size
+    pub font_size: f64,
+    /// Base border width
+    pub line_width: f64,
+    /// Border style
+    pub line_type: String,
+
+    /// Base unit of animation duration, in seconds
+    pub motion_unit: f64,
+    /// Base animation duration, in seconds
+    pub motion_base: f64,
+    /// Ease out circ curve
+    pub motion_ease_out_circ: String,
+    /// Ease in out circ curve
+    pub motion_ease_in_out_circ: String,
+    /// Ease out curve
+    pub motion_ease_out: String,
+    /// Ease in out curve
+    pub motion_ease_in_out: String,
+    /// Ease out back curve
+    pub motion_ease_out_back: String,
+    /// Ease in back curve
+    pub motion_ease_in_back: String,
+    /// Ease in quint curve
+    pub motion_ease_in_quint: String,
+    /// Ease out quint curve
+    pub motion_ease_out_quint: String,
+
+    /// Base border radius
+    pub border_radius: f64,
+    /// Size change unit
+    pub size_unit: f64,
+    /// Base size step
+    pub size_step: f64,
+    /// Size of popup arrows
+    pub size_popup_arrow: f64,
+    /// Base control height
+    pub control_height: f64,
+    /// Base z-index of regular components
+    pub z_index_base: i32,
+    /// Base z-index of popup components
+    pub z_index_popup_base: i32,
+    /// Opacity of images
+    pub opacity_image: f64,
+
+    /// Whether to use the wireframe style
+    pub wireframe: bool,
+    /// Whether motion is enabled
+    pub motion: bool,
+}
+
+impl Default for SeedToken {
+    fn default() -> Self {
+        Self {
+            color_primary: "#1677ff".to_string(),
+            color_success: "#52c41a".to_string(),
+            color_warning: "#faad14".to_string(),
+            color_error: "#ff4d4f".to_string(),
+            color_info: "#1677ff".to_string(),
+            color_link: String::new(),
+            color_text_base: String::new(),
+            color_bg_base: String::new(),
+
+            blue: "#1677FF".to_string(),
+            purple: "#722ED1".to_string(),
+            cyan: "#13C2C2".to_string(),
+            green: "#52C41A".to_string(),
+            magenta: "#EB2F96".to_string(),
+            pink: "#EB2F96".to_string(),
+            red: "#F5222D".to_string(),
+            orange: "#FA8C16".to_string(),
+            yellow: "#FADB14".to_string(),
+            volcano: "#FA541C".to_string(),
+            geekblue: "#2F54EB".to_string(),
+            gold: "#FAAD14".to_string(),
+            lime: "#A0D911".to_string(),
+
+            font_family:
+                "-apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, 'Helvetica Neue', \
+                          Arial, 'Noto Sans', sans-serif, 'Apple Color Emoji', 'Segoe UI Emoji', \
+                          'Segoe UI Symbol', 'Noto Color Emoji'"
+                    .to_string(),
+            font_family_code:
+                "'SFMono-Regular', Consolas, 'Liberation Mono', Menlo, Courier, monospace"
+                    .to_string(),
+            font_size: 14.0,
+            line_width: 1.0,
+            line_type: "solid".to_string(),
+
+            motion_unit: 0.1,
+            motion_base: 0.0,
+            motion_ease_out_circ: "cubic-bezier(0.08, 0.82, 0.17, 1)".to_string(),
+            motion_ease_in_out_circ: "cubic-bezier(0.78, 0.14, 0.15, 0.86)".to_string(),
+            motion_ease_out: "cubic-bezier(0.215, 0.61, 0.355, 1)".to_string(),
+            motion_ease_in_out: "cubic-bezier(0.645, 0.045, 0.355, 1)".to_string(),
+            motion_ease_out_back: "cubic-bezier(0.12, 0.4, 0.29, 1.46)".to_string(),
+            motion_ease_in_back: "cubic-bezier(0.71, -0.46, 0.88, 0.6)".to_string(),
+            motion_ease_in_quint: "cubic-bezier(0.755, 0.05, 0.855, 0.06)".to_string(),
+            motion_ease_out_quint: "cubic-bezier(0.23, 1, 0.32, 1)".to_string(),
+
+            border_radius: 6.0,
+            size_unit: 4.0,
+            size_step: 4.0,
+            size_popup_arrow: 16.0,
+            control_height: 32.0,
+            z_index_base: 0,
+            z_index_popup_base: 1000,
+            opacity_image: 1.0,
+
+            wireframe: false,
+            motion: true,
+        }
+    }
+}
+
+impl SeedToken {
+    /// Preset colors as `(name, color)` pairs
+    pub fn preset_colors(&self) -> [(&'static str, &str); 13] {
+        [
+            ("blue", &self.blue),
+            ("purple", &self.purple),
+            ("cyan", &self.cyan),
+            ("green", &self.green),
+            ("magenta", &self.magenta),
+            ("pink", &self.pink),
+            ("red", &self.red),
+            ("orange", &self.orange),
+            ("yellow", &self.yellow),
+            ("volcano", &self.volcano),
+            ("geekblue", &self.geekblue),
+            ("gold", &self.gold),
+            ("lime", &self.lime),
+        ]
+    }
+}
@@ -0,0 +1,278 @@
+use super::store::{FieldEntry, FieldMeta, FormInstance};
+use super::{FormContext, FormLayout, RequiredMark};
+use crate::components::config_provider::get_component_cls;
+use crate::components::locale::{resolve_locale, use_locale_text, Language};
+use crate::styles::apply_form_style;
+use crate::utils::validate::{NamePath, Rule, ValidateTrigger};
+use leptos::prelude::*;
+use leptos::tachys::reactive_graph::OwnedView;
+use serde_json::Value;
+
+/// The field of the surrounding `FormItem`, used by form controls
+#[derive(Clone, Copy)]
+pub struct FormFieldContext {
+    form: FormInstance,
+    key: usize,
+    name: StoredValue<NamePath>,
+    id: StoredValue<String>,
+}
+
+impl FormFieldContext {
+    /// Path of the field
+    pub fn name(&self) -> NamePath {
+        self.name.get_value()
+    }
+
+    /// Element id the control should use, so the label points at it
+    pub fn id(&self) -> String {
+        self.id.get_value()
+    }
+
+    /// Current value, tracked
+    pub fn value(&self) -> Value {
+        self.form.get_field_value(self.name.get_value())
+    }
+
+    /// Change the value as the user did, validating the field
+    pub fn set_value(&self, value: Value) {
+        self.form.change_field(self.key, value);
+    }
+
+    /// Report that the control lost focus, validating the blur rules
+    pub fn blur(&self) {
+        self.form.trigger_field(self.key, ValidateTrigger::Blur);
+    }
+
+    /// Whether the last validation failed, tracked
+    pub fn has_error(&self) -> bool {
+        !self
+            .form
+            .get_field_meta(&self.name.get_value())
+            .errors
+            .is_empty()
+    }
+
+    /// Whether the last validation produced warnings, tracked
+    pub fn has_warning(&self) -> bool {
+        !self
+            .form
+            .get_field_meta(&self.name.get_value())
+            .warnings
+            .is_empty()
+    }
+}
+
+/// Get the field of the surrounding `FormItem`
+///
+/// Returns `None` outside of an item or inside an item without `name`.
+pub fn use_form_field() -> Option<FormFieldContext> {
+    use_context::<FormFieldContext>()
+}
+
+/// 表单项
+///
+/// 设置 `name` 时注册为字段，其中的表单控件通过 [`use_form_field`] 读写值；
+/// 不设置时只负责标签与布局。
+#[component]
+pub fn FormItem(
+    /// 字段路径，例如 `"user.name"`
+    #[prop(optional, into)]
+    name: Option<NamePath>,
+    /// 标签
+    #[prop(optional, into)]
+    label: Option<String>,
+    /// 校验规则
+    #[prop(optional)]
+    rules: Vec<Rule>,
+    /// 是否显示必填标记，未设置时由规则中是否有 `required` 决定
+    #[prop(optional)]
+    required: Option<bool>,
+    /// 依赖的字段，它们变化时重新校验本字段
+    #[prop(optional)]
+    dependencies: Vec<NamePath>,
+    /// 触发校验的事件，未设置时使用 `Form` 的 `validate_trigger`
+    #[prop(optional)]
+    validate_trigger: Option<Vec<ValidateTrigger>>,
+    /// 事件触发校验前等待的毫秒数，未设置时使用 `Form` 的 `validate_debounce`
+    #[prop(optional)]
+    validate_debounce: Option<u32>,
+    /// 初始值，优先级低于 `Form` 的 `initial_values`
+    #[prop(optional)]
+    initial_value: Option<Value>,
+    /// 提示信息，设置后替代校验错误显示
+    #[prop(optional, into)]
+    help: Option<Signal<String>>,
+    /// 额外的提示信息
+    #[prop(optional, into)]
+    extra: Option<String>,
+    /// 额外的类名
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// 表单控件
+    children: Children,
+) -> impl IntoView {
+    let hash_cls = apply_form_style();
+    let cls = get_component_cls("form-item");
+    let ctx = use_context::<FormContext>().unwrap_or_else(FormContext::standalone);
+    let form = ctx.form;
+    let locale_text = use_locale_text();
+    let required = required.unwrap_or_else(|| rules.iter().any(|rule| rule.required));
+    // 标签末尾的冒号由样式统一添加
+    let label = label.map(|label| label.trim_end_matches([':', '：']).to_string());
+
+    let field = name.map(|name| {
+        if let Some(value) = initial_value {
+            form.set_initial_value(&name, value);
+        }
+        let key = form.register(FieldEntry {
+            key: 0,
+            name: name.clone(),
+            label: label.clone(),
+            rules,
+            dependencies,
+            validate_trigger: validate_trigger.unwrap_or_else(|| ctx.validate_trigger.get_value()),
+            validate_debounce: validate_debounce.unwrap_or(ctx.validate_debounce),
+        });
+        on_cleanup(move || form.unregister(key));
+        FormFieldContext {
+            form,
+            key,
+            id: StoredValue::new(form.field_id(&name)),
+            name: StoredValue::new(name),
+        }
+    });
+    let meta = Memo::new(move |_| {
+        field
+            .map(|field| form.get_field_meta(&field.name.get_value()))
+            .unwrap_or_default()
+    });
+
+    let class = {
+        let cls = cls.clone();
+        move || {
+            let meta = meta.get();
+            let mut classes = vec![cls.clone(), hash_cls.get()];
+            if !meta.errors.is_empty() {
+                classes.push(format!("{}-has-error", cls));
+            } else if !meta.warnings.is_empty() {
+                classes.push(format!("{}-has-warning", cls));
+            }
+            if meta.validating {
+                classes.push(format!("{}-is-validating", cls));
+            }
+            classes.extend(class.clone());
+            classes.join(" ")
+        }
+    };
+
+    let label_view = label.map(|label| {
+        let label_class = {
+            let cls = cls.clone();
+            move || {
+                let mark = ctx.required_mark.get();
+                let mut classes = Vec::new();
+                if required {
+                    classes.push(format!("{}-required", cls));
+                }
+                if mark == RequiredMark::Optional {
+                    classes.push(format!("{}-required-mark-optional", cls));
+                }
+                if !ctx.colon.get() || ctx.layout.get() == FormLayout::Vertical {
+                    classes.push(format!("{}-no-colon", cls));
+                }
+                classes.join(" ")
+            }
+        };
+        let optional = {
+            let cls = cls.clone();
+            move || {
+                (ctx.required_mark.get() == RequiredMark::Optional && !required).then(|| {
+                    let text = locale_text
+                        .map(|text| text.with(|text| text.form.optional.clone()))
+                        .unwrap_or_else(|| {
+                            resolve_locale(Language::EnUS.tag()).texts.form.optional
+                        });
+                    view! { <span class=format!("{}-optional", cls)>"(" {text} ")"</span> }
+                })
+            }
+        };
+        let title = label.clone();
+        view! {
+            <div class=format!("{}-label", cls)>
+                <label
+                    class=label_class
+                    for=field.map(|field| field.id())
+                    title=title
+                >
+                    {label}
+                    {optional}
+                </label>
+            </div>
+        }
+    });
+
+    let explain = {
+        let cls = cls.clone();
+        move || {
+            let messages: Vec<(String, &str)> = match help {
+                Some(help) => vec![(help.get(), "help")],
+                None => {
+                    let FieldMeta {
+                        errors, warnings, ..
+                    } = meta.get();
+                    errors
+                        .into_iter()
+                        .map(|error| (error, "error"))
+                        .chain(warnings.into_iter().map(|warning| (warning, "warning")))
+                        .collect()
+                }
+            };
+            (!messages.is_empty()).then(|| {
+                view! {
+                    <div
+                        class=format!("{}-explain", cls)
+                        id=field.map(|field| format!("{}_help", field.id()))
+                    >
+                        {messages
+                            .into_iter()
+                            .map(|(message, kind)| {
+                                view! {
+                                    <div role="alert" class=format!("{}-explain-{}", cls, kind)>
+                                        {message}
+                                    </div>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                }
+            })
+        }
+    };
+    let extra = extra.map(|extra| view! { <div class=format!("{}-extra", cls)>{extra}</div> });
+
+    // 字段上下文只对子树可见
+    let owner = Owner::new();
+    if let Some(field) = field {
+        owner.with(|| provide_context(field));
+    }
+    let children = owner.with(children);
+
+    OwnedView::new_with_owner(
+        view! {
+            <div class=class id=field.map(|field| format!("{}_item", field.id()))>
+                <div class=format!("{}-row", cls)>
+                    {label_view}
+                    <div class=format!("{}-control", cls)>
+                        <div class=format!("{}-control-input", cls)>
+                            <div class=format!("{}-control-input-content", cls)>{children}</div>
+                        </div>
+                        {explain}
+                        {extra}
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any(),
+        owner,
+    )
+}
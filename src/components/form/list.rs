@@ -0,0 +1,199 @@
+use super::store::FormInstance;
+use super::FormContext;
+use crate::utils::validate::NamePath;
+use leptos::prelude::*;
+use serde_json::Value;
+
+/// A row of a `FormList`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListField {
+    /// Stable key of the row, unchanged when other rows move
+    pub key: usize,
+    /// Index of the row
+    pub index: usize,
+    /// Path of the row, the list path followed by the index
+    pub name: NamePath,
+}
+
+impl ListField {
+    /// Path of a field inside the row
+    pub fn path(&self, sub: impl Into<NamePath>) -> NamePath {
+        self.name.concat(&sub.into())
+    }
+}
+
+/// Row of the rearranged list, taken from an old row or newly added
+enum Row {
+    Old(usize),
+    New(Value),
+}
+
+#[derive(Default)]
+struct RowKeys {
+    next: usize,
+    keys: Vec<usize>,
+}
+
+impl RowKeys {
+    fn next_key(&mut self) -> usize {
+        self.next += 1;
+        self.next - 1
+    }
+
+    /// Follow rows added or removed without the list operations
+    fn sync(&mut self, len: usize) {
+        self.keys.truncate(len);
+        while self.keys.len() < len {
+            let key = self.next_key();
+            self.keys.push(key);
+        }
+    }
+}
+
+/// Operations changing the rows of a `FormList`
+#[derive(Clone, Copy)]
+pub struct FormListOperations {
+    form: FormInstance,
+    name: StoredValue<NamePath>,
+    keys: StoredValue<RowKeys>,
+}
+
+impl FormListOperations {
+    pub(crate) fn new(form: FormInstance, name: NamePath) -> Self {
+        Self {
+            form,
+            name: StoredValue::new(name),
+            keys: StoredValue::new(RowKeys::default()),
+        }
+    }
+
+    /// Append a row
+    pub fn add(&self, value: Value) {
+        self.rearrange(|rows| rows.push(Row::New(value)));
+    }
+
+    /// Insert a row at `index`, or append it when past the end
+    pub fn insert(&self, index: usize, value: Value) {
+        self.rearrange(|rows| rows.insert(index.min(rows.len()), Row::New(value)));
+    }
+
+    /// Remove the row at `index`
+    pub fn remove(&self, index: usize) {
+        self.rearrange(|rows| {
+            if index < rows.len() {
+                rows.remove(index);
+            }
+        });
+    }
+
+    /// Move the row at `from` to `to`
+    pub fn move_row(&self, from: usize, to: usize) {
+        self.rearrange(|rows| {
+            if from < rows.len() && to < rows.len() {
+                let row = rows.remove(from);
+                rows.insert(to, row);
+            }
+        });
+    }
+
+    fn rearrange(&self, update: impl FnOnce(&mut Vec<Row>)) {
+        let name = self.name.get_value();
+        let old = match untrack(|| self.form.get_field_value(name.clone())) {
+            Value::Array(items) => items,
+            _ => Vec::new(),
+        };
+        let mut rows: Vec<Row> = (0..old.len()).map(Row::Old).collect();
+        update(&mut rows);
+
+        let old_keys = self.keys.with_value(|keys| keys.keys.clone());
+        let mut keys = Vec::with_capacity(rows.len());
+        let mut values = Vec::with_capacity(rows.len());
+        let mut new_index = vec![None; old.len()];
+        for (index, row) in rows.into_iter().enumerate() {
+            match row {
+                Row::Old(old_index) => {
+                    new_index[old_index] = Some(index);
+                    keys.push(old_keys.get(old_index).copied());
+                    values.push(old[old_index].clone());
+                }
+                Row::New(value) => {
+                    keys.push(None);
+                    values.push(value);
+                }
+            }
+        }
+        self.keys.update_value(|row_keys| {
+            row_keys.keys = keys
+                .into_iter()
+                .map(|key| key.unwrap_or_else(|| row_keys.next_key()))
+                .collect();
+        });
+        // 错误等字段状态跟随行移动
+        self.form
+            .remap_list(&name, |index| new_index.get(index).copied().flatten());
+        self.form.set_field_value(name, Value::Array(values));
+    }
+}
+
+/// Rows and operations passed to the children of a `FormList`
+#[derive(Clone)]
+pub struct FormListFields {
+    /// Current rows
+    pub fields: Vec<ListField>,
+    /// Operations changing the rows
+    pub operations: FormListOperations,
+}
+
+/// 动态列表
+///
+/// 字段值为数组，每个元素一行。`children` 接收当前的行与操作，行内的
+/// `FormItem` 使用 [`ListField::path`] 作为 `name`。
+#[component]
+pub fn FormList<F, IV>(
+    /// 列表字段的路径
+    #[prop(into)]
+    name: NamePath,
+    /// 初始行，优先级低于 `Form` 的 `initial_values`
+    #[prop(optional)]
+    initial_value: Option<Vec<Value>>,
+    /// 根据行渲染列表
+    children: F,
+) -> impl IntoView
+where
+    F: Fn(FormListFields) -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let form = use_context::<FormContext>()
+        .unwrap_or_else(FormContext::standalone)
+        .form;
+    if let Some(initial_value) = initial_value {
+        form.set_initial_value(&name, Value::Array(initial_value));
+    }
+    let operations = FormListOperations::new(form, name.clone());
+
+    let fields = Memo::new(move |_| {
+        let len = form
+            .get_field_value(name.clone())
+            .as_array()
+            .map_or(0, Vec::len);
+        operations.keys.update_value(|keys| keys.sync(len));
+        operations.keys.with_value(|keys| {
+            keys.keys
+                .iter()
+                .enumerate()
+                .map(|(index, key)| ListField {
+                    key: *key,
+                    index,
+                    name: name.join(index),
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    move || {
+        children(FormListFields {
+            fields: fields.get(),
+            operations,
+        })
+    }
+}
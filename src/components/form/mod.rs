@@ -0,0 +1,258 @@
+//! 表单组件
+//!
+//! `Form` 持有一个 [`FormInstance`]，其中的 `FormItem` 按 `name` 注册字段、
+//! 校验规则与依赖。表单控件通过 [`use_form_field`] 读写所在字段的值；值变化时
+//! 校验该字段，以及依赖它且已修改过的字段。提交时校验全部字段，失败时滚动到
+//! 第一个出错的字段。`FormList` 用于数组形式的动态行。
+//!
+//! 规则由 `validate_trigger` 指定的事件触发，可按字段或按规则覆盖，并可通过
+//! `validate_debounce` 延迟；`warning_only` 的规则只产生警告，不影响提交。
+//! 规则本身定义在 [`crate::utils::validate`] 中，也可脱离表单使用。
+//!
+//! 校验信息依次取 `validate_messages`、`Config.form.validate_messages` 与当前
+//! 语言包；必填标记默认按 `Config.form.require_mark` 显示。
+
+mod item;
+mod list;
+mod store;
+
+pub use crate::utils::validate::{
+    FieldError, NamePath, NameSegment, Rule, RuleType, Schema, ValidateTrigger, Validator,
+};
+pub use item::{use_form_field, FormFieldContext, FormItem};
+pub use list::{FormList, FormListFields, FormListOperations, ListField};
+pub use store::{use_form, FieldMeta, FormInstance};
+
+use crate::components::config_provider::{
+    get_component_cls, use_config, use_direction, ComponentSize, Direction,
+    DisabledContextProvider, DisabledContextProviderProps, SizeContextProvider,
+    SizeContextProviderProps, ValidateMessages,
+};
+use crate::components::locale::use_locale_text;
+use crate::styles::apply_form_style;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::tachys::reactive_graph::OwnedView;
+use serde_json::Value;
+
+/// Layout of the form items
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FormLayout {
+    /// Label beside the control
+    #[default]
+    Horizontal,
+    /// Label above the control
+    Vertical,
+    /// Items in one line
+    Inline,
+}
+
+impl FormLayout {
+    /// Class suffix of the layout
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FormLayout::Horizontal => "horizontal",
+            FormLayout::Vertical => "vertical",
+            FormLayout::Inline => "inline",
+        }
+    }
+}
+
+/// How required fields are marked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RequiredMark {
+    /// `*` before the labels of required fields
+    #[default]
+    Shown,
+    /// No mark
+    Hidden,
+    /// No `*`, but an "optional" text after the labels of other fields
+    Optional,
+}
+
+/// Settings shared by the items of a form
+#[derive(Clone, Copy)]
+pub(crate) struct FormContext {
+    pub form: FormInstance,
+    pub layout: Signal<FormLayout>,
+    pub required_mark: Signal<RequiredMark>,
+    pub colon: Signal<bool>,
+    pub validate_trigger: StoredValue<Vec<ValidateTrigger>>,
+    pub validate_debounce: u32,
+}
+
+impl FormContext {
+    /// Context of an item used outside of a `Form`
+    fn standalone() -> Self {
+        Self {
+            form: FormInstance::new(),
+            layout: Signal::default(),
+            required_mark: Signal::default(),
+            colon: Signal::stored(true),
+            validate_trigger: StoredValue::new(vec![ValidateTrigger::Change]),
+            validate_debounce: 0,
+        }
+    }
+}
+
+/// Get the instance of the surrounding form
+pub fn use_form_instance() -> Option<FormInstance> {
+    use_context::<FormContext>().map(|ctx| ctx.form)
+}
+
+/// 表单组件
+#[component]
+pub fn Form(
+    /// 表单实例，由 [`use_form`] 创建；未设置时表单自行创建
+    #[prop(optional)]
+    form: Option<FormInstance>,
+    /// 表单名，作为字段 id 的前缀
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// 布局
+    #[prop(optional, into)]
+    layout: Signal<FormLayout>,
+    /// 初始值
+    #[prop(optional)]
+    initial_values: Option<Value>,
+    /// 必填标记，未设置时按 `Config.form.require_mark` 显示或隐藏
+    #[prop(optional, into)]
+    required_mark: Option<Signal<RequiredMark>>,
+    /// 是否在标签后显示冒号，垂直布局下不显示
+    #[prop(default = Signal::stored(true), into)]
+    colon: Signal<bool>,
+    /// 校验信息，未设置时使用 `Config.form.validate_messages`，其次为当前语言包
+    #[prop(optional)]
+    validate_messages: Option<ValidateMessages>,
+    /// 触发校验的事件，默认为值变化
+    #[prop(optional)]
+    validate_trigger: Option<Vec<ValidateTrigger>>,
+    /// 事件触发校验前等待的毫秒数，提交时不等待
+    #[prop(optional)]
+    validate_debounce: u32,
+    /// 提交失败时是否滚动到第一个出错的字段，未设置时使用 `Config.form.scroll_to_first_error`
+    #[prop(optional)]
+    scroll_to_first_error: Option<bool>,
+    /// 表单内组件的尺寸
+    #[prop(optional, into)]
+    size: Option<Signal<ComponentSize>>,
+    /// 禁用表单内的组件
+    #[prop(optional, into)]
+    disabled: Option<Signal<bool>>,
+    /// 额外的类名
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// 校验通过后提交，参数为表单的值
+    #[prop(optional, into)]
+    on_finish: Option<Callback<Value>>,
+    /// 校验失败后调用，参数为出错的字段
+    #[prop(optional, into)]
+    on_finish_failed: Option<Callback<Vec<FieldError>>>,
+    /// 表单项
+    children: Children,
+) -> impl IntoView {
+    let hash_cls = apply_form_style();
+    let cls = get_component_cls("form");
+    let config = use_config();
+    let direction = use_direction();
+    let locale_text = use_locale_text();
+    let form = form.unwrap_or_default();
+
+    form.set_name(name);
+    if let Some(initial_values) = initial_values {
+        form.set_initial_values(initial_values);
+    }
+    let default_messages = move || {
+        config
+            .and_then(|config| config.with(|config| config.form.validate_messages.clone()))
+            .or_else(|| {
+                locale_text.map(|text| text.with(|text| text.form.validate_messages.clone()))
+            })
+            .unwrap_or_default()
+    };
+    match validate_messages {
+        Some(messages) => form.set_messages(messages),
+        None => {
+            form.set_messages(untrack(default_messages));
+            Effect::new(move |_| form.set_messages(default_messages()));
+        }
+    }
+    let scroll_to_first_error = scroll_to_first_error.unwrap_or_else(|| {
+        config
+            .is_none_or(|config| config.with_untracked(|config| config.form.scroll_to_first_error))
+    });
+    let required_mark = required_mark.unwrap_or_else(|| {
+        Signal::derive(move || {
+            let shown = config.is_none_or(|config| config.with(|config| config.form.require_mark));
+            if shown {
+                RequiredMark::Shown
+            } else {
+                RequiredMark::Hidden
+            }
+        })
+    });
+
+    let class = move || {
+        let mut classes = vec![
+            cls.clone(),
+            hash_cls.get(),
+            format!("{}-{}", cls, layout.get().as_str()),
+        ];
+        if required_mark.get() == RequiredMark::Hidden {
+            classes.push(format!("{}-hide-required-mark", cls));
+        }
+        match size.map(|size| size.get()) {
+            Some(ComponentSize::Small) => classes.push(format!("{}-small", cls)),
+            Some(ComponentSize::Large) => classes.push(format!("{}-large", cls)),
+            _ => {}
+        }
+        if direction.is_some_and(|direction| direction.get() == Direction::Rtl) {
+            classes.push(format!("{}-rtl", cls));
+        }
+        classes.extend(class.clone());
+        classes.join(" ")
+    };
+
+    let on_submit = move |event: SubmitEvent| {
+        event.prevent_default();
+        leptos::task::spawn_local(form.submit(on_finish, on_finish_failed, scroll_to_first_error));
+    };
+    let on_reset = move |event: leptos::ev::Event| {
+        event.prevent_default();
+        form.reset_fields();
+    };
+
+    // 上下文只对子树可见
+    let owner = Owner::new();
+    owner.with(|| {
+        provide_context(FormContext {
+            form,
+            layout,
+            required_mark,
+            colon,
+            validate_trigger: StoredValue::new(
+                validate_trigger.unwrap_or_else(|| vec![ValidateTrigger::Change]),
+            ),
+            validate_debounce,
+        })
+    });
+    // 尺寸与禁用状态未设置时沿用上层的值
+    let children = owner.with(|| {
+        SizeContextProvider(SizeContextProviderProps {
+            size,
+            children: Box::new(move || {
+                DisabledContextProvider(DisabledContextProviderProps { disabled, children })
+                    .into_any()
+            }),
+        })
+    });
+
+    OwnedView::new_with_owner(
+        view! {
+            <form class=class on:submit=on_submit on:reset=on_reset>
+                {children}
+            </form>
+        },
+        owner,
+    )
+}
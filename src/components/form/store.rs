@@ -0,0 +1,673 @@
+//! 表单数据仓库
+//!
+//! [`FormInstance`] 保存表单的值、每个字段的状态（错误、警告、是否修改过、
+//! 是否正在校验）以及已注册字段的规则。它是可复制的句柄，可以在 `Form` 外部通过
+//! [`use_form`] 创建后传入，以便在回调中读写字段或触发校验。
+
+use crate::components::config_provider::ValidateMessages;
+use crate::utils::dom::document;
+use crate::utils::validate::{
+    get_value, merge_values, set_value, validate_rules, FieldError, NamePath, NameSegment, Rule,
+    ValidateResult, ValidateTrigger,
+};
+use leptos::prelude::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// State of one field
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldMeta {
+    /// Messages of the last validation
+    pub errors: Vec<String>,
+    /// Messages of the warning-only rules in the last validation
+    pub warnings: Vec<String>,
+    /// Whether the user has changed the field
+    pub touched: bool,
+    /// Whether a validation is running
+    pub validating: bool,
+}
+
+/// A field registered by a `FormItem`
+#[derive(Clone, Debug)]
+pub(crate) struct FieldEntry {
+    pub key: usize,
+    pub name: NamePath,
+    pub label: Option<String>,
+    pub rules: Vec<Rule>,
+    pub dependencies: Vec<NamePath>,
+    /// Events validating the rules without triggers of their own
+    pub validate_trigger: Vec<ValidateTrigger>,
+    /// Delay in milliseconds before validating on an event
+    pub validate_debounce: u32,
+}
+
+impl FieldEntry {
+    fn label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.name.to_string())
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    next_key: usize,
+    fields: Vec<FieldEntry>,
+    /// 每个字段最近一次校验的序号，用于丢弃过期的异步结果
+    generations: HashMap<NamePath, u64>,
+    /// 等待防抖结束的校验
+    debounces: HashMap<usize, TimeoutHandle>,
+}
+
+/// Handle of a form's values and field states
+///
+/// Create one with [`use_form`] to control a form from outside, or let `Form`
+/// create its own.
+#[derive(Clone, Copy)]
+pub struct FormInstance {
+    values: RwSignal<Value>,
+    initial_values: StoredValue<Value>,
+    meta: RwSignal<HashMap<NamePath, FieldMeta>>,
+    registry: StoredValue<Registry>,
+    messages: StoredValue<ValidateMessages>,
+    name: StoredValue<Option<String>>,
+}
+
+/// Create a form instance
+pub fn use_form() -> FormInstance {
+    FormInstance::new()
+}
+
+impl Default for FormInstance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormInstance {
+    /// Create an empty form instance
+    pub fn new() -> Self {
+        Self {
+            values: RwSignal::new(Value::Object(Default::default())),
+            initial_values: StoredValue::new(Value::Object(Default::default())),
+            meta: RwSignal::new(HashMap::new()),
+            registry: StoredValue::new(Registry::default()),
+            messages: StoredValue::new(ValidateMessages::default()),
+            name: StoredValue::new(None),
+        }
+    }
+
+    /// All values, tracked
+    pub fn get_fields_value(&self) -> Value {
+        self.values.get()
+    }
+
+    /// Value of one field, tracked; `null` when unset
+    pub fn get_field_value(&self, name: impl Into<NamePath>) -> Value {
+        let name = name.into();
+        self.values
+            .with(|values| get_value(values, &name).cloned())
+            .unwrap_or(Value::Null)
+    }
+
+    /// Set the value of one field without validating it
+    pub fn set_field_value(&self, name: impl Into<NamePath>, value: Value) {
+        let name = name.into();
+        self.values.update(|values| set_value(values, &name, value));
+    }
+
+    /// Deep merge `values` into the form values without validating them
+    pub fn set_fields_value(&self, values: Value) {
+        self.values.update(|current| merge_values(current, values));
+    }
+
+    /// Restore the initial values and clear all errors and touched states
+    pub fn reset_fields(&self) {
+        self.values.set(self.initial_values.get_value());
+        self.meta.set(HashMap::new());
+    }
+
+    /// Errors of one field, tracked
+    pub fn get_field_error(&self, name: impl Into<NamePath>) -> Vec<String> {
+        let name = name.into();
+        self.meta
+            .with(|meta| meta.get(&name).map(|meta| meta.errors.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Warnings of one field, tracked
+    pub fn get_field_warning(&self, name: impl Into<NamePath>) -> Vec<String> {
+        let name = name.into();
+        self.meta
+            .with(|meta| meta.get(&name).map(|meta| meta.warnings.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Errors and warnings of all fields that have any, in registration
+    /// order, tracked
+    pub fn get_fields_error(&self) -> Vec<FieldError> {
+        let names: Vec<NamePath> = self.registry.with_value(|registry| {
+            registry
+                .fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect()
+        });
+        self.meta.with(|meta| {
+            names
+                .into_iter()
+                .filter_map(|name| {
+                    let meta = meta.get(&name)?;
+                    (!meta.errors.is_empty() || !meta.warnings.is_empty()).then(|| FieldError {
+                        name,
+                        errors: meta.errors.clone(),
+                        warnings: meta.warnings.clone(),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// State of one field, tracked
+    pub fn get_field_meta(&self, name: &NamePath) -> FieldMeta {
+        self.meta
+            .with(|meta| meta.get(name).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Whether the user has changed the field, tracked
+    pub fn is_field_touched(&self, name: impl Into<NamePath>) -> bool {
+        self.get_field_meta(&name.into()).touched
+    }
+
+    /// Element id of a field's control, `{form name}_{path}`
+    pub fn field_id(&self, name: &NamePath) -> String {
+        match self.name.get_value() {
+            Some(form) => format!("{}_{}", form, name.to_id()),
+            None => name.to_id(),
+        }
+    }
+
+    /// Scroll the item of a field into view
+    ///
+    /// Does nothing on the server or when the field is not rendered.
+    pub fn scroll_to_field(&self, name: impl Into<NamePath>) {
+        let id = format!("{}_item", self.field_id(&name.into()));
+        let Some(element) = document().and_then(|document| document.get_element_by_id(&id)) else {
+            return;
+        };
+        let options = web_sys::ScrollIntoViewOptions::new();
+        options.set_behavior(web_sys::ScrollBehavior::Smooth);
+        options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+
+    /// Validate one field with all of its rules, returning its errors
+    pub async fn validate_field(self, name: impl Into<NamePath>) -> Vec<String> {
+        let name = name.into();
+        let Some(field) = self.registry.with_value(|registry| {
+            registry
+                .fields
+                .iter()
+                .find(|field| field.name == name)
+                .cloned()
+        }) else {
+            return Vec::new();
+        };
+        self.run_validation(field, None).await.errors
+    }
+
+    /// Validate all registered fields with all of their rules
+    ///
+    /// Returns the values when no field has errors, otherwise the failing
+    /// fields in registration order. Warnings do not fail the validation.
+    pub async fn validate_fields(self) -> Result<Value, Vec<FieldError>> {
+        let fields = self.registry.with_value(|registry| registry.fields.clone());
+        let mut failed = Vec::new();
+        for field in fields {
+            let name = field.name.clone();
+            let result = self.run_validation(field, None).await;
+            if !result.errors.is_empty() {
+                failed.push(FieldError {
+                    name,
+                    errors: result.errors,
+                    warnings: result.warnings,
+                });
+            }
+        }
+        if failed.is_empty() {
+            Ok(self.values.try_get_untracked().unwrap_or(Value::Null))
+        } else {
+            Err(failed)
+        }
+    }
+
+    /// Validate all fields, then call `on_finish` with the values or
+    /// `on_finish_failed` with the failing fields
+    pub(crate) async fn submit(
+        self,
+        on_finish: Option<Callback<Value>>,
+        on_finish_failed: Option<Callback<Vec<FieldError>>>,
+        scroll_to_first_error: bool,
+    ) {
+        match self.validate_fields().await {
+            Ok(values) => {
+                if let Some(on_finish) = on_finish {
+                    on_finish.run(values);
+                }
+            }
+            Err(errors) => {
+                if scroll_to_first_error {
+                    if let Some(first) = errors.first() {
+                        self.scroll_to_field(first.name.clone());
+                    }
+                }
+                if let Some(on_finish_failed) = on_finish_failed {
+                    on_finish_failed.run(errors);
+                }
+            }
+        }
+    }
+
+    /// Validate a field with the rules run by `trigger`, or all rules when
+    /// `None`
+    async fn run_validation(
+        self,
+        field: FieldEntry,
+        trigger: Option<ValidateTrigger>,
+    ) -> ValidateResult {
+        let name = field.name.clone();
+        let Some(generation) = self.registry.try_update_value(|registry| {
+            let generation = registry.generations.entry(name.clone()).or_default();
+            *generation += 1;
+            *generation
+        }) else {
+            return ValidateResult::default();
+        };
+        let rules: Vec<Rule> = field
+            .rules
+            .iter()
+            .filter(|rule| {
+                trigger.is_none_or(|trigger| rule.is_triggered_by(trigger, &field.validate_trigger))
+            })
+            .cloned()
+            .collect();
+        if rules.is_empty() {
+            self.update_meta(&name, |meta| {
+                meta.errors.clear();
+                meta.warnings.clear();
+            });
+            return ValidateResult::default();
+        }
+
+        self.update_meta(&name, |meta| meta.validating = true);
+        let values = self.values.get_untracked();
+        let value = get_value(&values, &name).cloned().unwrap_or(Value::Null);
+        let messages = self.messages.get_value();
+        let result = validate_rules(&field.label(), &value, &values, &rules, &messages).await;
+
+        // 校验期间字段可能已被重新校验或卸载
+        let current = self
+            .registry
+            .try_with_value(|registry| registry.generations.get(&name).copied())
+            .flatten();
+        if current == Some(generation) {
+            let ValidateResult { errors, warnings } = result.clone();
+            self.update_meta(&name, move |meta| {
+                meta.errors = errors;
+                meta.warnings = warnings;
+                meta.validating = false;
+            });
+        }
+        result
+    }
+
+    fn update_meta(&self, name: &NamePath, update: impl FnOnce(&mut FieldMeta)) {
+        self.meta
+            .try_update(|meta| update(meta.entry(name.clone()).or_default()));
+    }
+
+    pub(crate) fn set_name(&self, name: Option<String>) {
+        self.name.set_value(name);
+    }
+
+    pub(crate) fn set_messages(&self, messages: ValidateMessages) {
+        self.messages.set_value(messages);
+    }
+
+    /// Set the initial values, filling the fields that have no value yet
+    pub(crate) fn set_initial_values(&self, initial: Value) {
+        self.initial_values
+            .update_value(|values| merge_values(values, initial.clone()));
+        self.values.update_untracked(|values| {
+            let mut merged = initial;
+            merge_values(&mut merged, values.clone());
+            *values = merged;
+        });
+    }
+
+    /// Set the initial value of one field, unless it already has a value
+    pub(crate) fn set_initial_value(&self, name: &NamePath, value: Value) {
+        self.initial_values
+            .update_value(|values| set_value(values, name, value.clone()));
+        self.values.update_untracked(|values| {
+            if get_value(values, name).is_none_or(Value::is_null) {
+                set_value(values, name, value);
+            }
+        });
+    }
+
+    pub(crate) fn register(&self, mut field: FieldEntry) -> usize {
+        self.registry
+            .try_update_value(|registry| {
+                field.key = registry.next_key;
+                registry.next_key += 1;
+                registry.fields.push(field);
+                registry.next_key - 1
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn unregister(&self, key: usize) {
+        self.registry.try_update_value(|registry| {
+            registry.fields.retain(|field| field.key != key);
+            if let Some(handle) = registry.debounces.remove(&key) {
+                handle.clear();
+            }
+        });
+    }
+
+    fn field_by_key(&self, key: usize) -> Option<FieldEntry> {
+        self.registry
+            .try_with_value(|registry| {
+                registry
+                    .fields
+                    .iter()
+                    .find(|field| field.key == key)
+                    .cloned()
+            })
+            .flatten()
+    }
+
+    /// Handle a change made by the user
+    ///
+    /// Sets the value, marks the field touched and validates it, then
+    /// validates the touched fields depending on it.
+    pub(crate) fn change_field(&self, key: usize, value: Value) {
+        let Some(field) = self.field_by_key(key) else {
+            return;
+        };
+        let name = field.name.clone();
+        self.values.update(|values| set_value(values, &name, value));
+        self.update_meta(&name, |meta| meta.touched = true);
+        self.trigger_field(key, ValidateTrigger::Change);
+    }
+
+    /// Validate a field with the rules run by `trigger`, after its debounce
+    /// delay, then validate the touched fields depending on it
+    pub(crate) fn trigger_field(&self, key: usize, trigger: ValidateTrigger) {
+        let Some(field) = self.field_by_key(key) else {
+            return;
+        };
+        let name = field.name.clone();
+        let triggered = field
+            .rules
+            .iter()
+            .any(|rule| rule.is_triggered_by(trigger, &field.validate_trigger));
+        // 只有值变化时才需要重新校验依赖它的字段
+        let changed = trigger == ValidateTrigger::Change;
+        if !triggered && !changed {
+            return;
+        }
+
+        let dependents: Vec<FieldEntry> = self.registry.with_value(|registry| {
+            registry
+                .fields
+                .iter()
+                .filter(|dependent| {
+                    changed
+                        && dependent.key != key
+                        && dependent.dependencies.iter().any(|dependency| {
+                            name.starts_with(dependency) || dependency.starts_with(&name)
+                        })
+                })
+                .cloned()
+                .collect()
+        });
+        let delay = field.validate_debounce;
+        let form = *self;
+        let validate = move || {
+            leptos::task::spawn_local(async move {
+                if triggered {
+                    form.run_validation(field, Some(trigger)).await;
+                }
+                for dependent in dependents {
+                    if form.get_field_meta_untracked(&dependent.name).touched {
+                        form.run_validation(dependent, None).await;
+                    }
+                }
+            })
+        };
+        if delay == 0 {
+            validate();
+            return;
+        }
+
+        // 防抖期间的新事件取消前一次等待
+        let pending = self
+            .registry
+            .try_update_value(|registry| registry.debounces.remove(&key))
+            .flatten();
+        if let Some(handle) = pending {
+            handle.clear();
+        }
+        let handle = set_timeout_with_handle(
+            move || {
+                form.registry.try_update_value(|registry| {
+                    registry.debounces.remove(&key);
+                });
+                validate();
+            },
+            Duration::from_millis(delay.into()),
+        );
+        if let Ok(handle) = handle {
+            self.registry.try_update_value(|registry| {
+                registry.debounces.insert(key, handle);
+            });
+        }
+    }
+
+    fn get_field_meta_untracked(&self, name: &NamePath) -> FieldMeta {
+        self.meta
+            .try_with_untracked(|meta| meta.get(name).cloned())
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Move the states of the rows of a list after its rows were rearranged
+    ///
+    /// `map` gives the new index of an old row, `None` when it was removed.
+    pub(crate) fn remap_list(&self, list: &NamePath, map: impl Fn(usize) -> Option<usize>) {
+        self.meta.update(|meta| {
+            *meta = std::mem::take(meta)
+                .into_iter()
+                .filter_map(|(name, state)| {
+                    if !name.starts_with(list) || name.0.len() == list.0.len() {
+                        return Some((name, state));
+                    }
+                    let NameSegment::Index(index) = name.0[list.0.len()] else {
+                        return Some((name, state));
+                    };
+                    let mut name = name;
+                    name.0[list.0.len()] = NameSegment::Index(map(index)?);
+                    Some((name, state))
+                })
+                .collect();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::form::FormListOperations;
+    use futures::executor::block_on;
+    use leptos::task::{CustomExecutor, Executor};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex, Once};
+
+    /// 在调用处直接运行任务，校验器都是立即完成的
+    struct InlineExecutor;
+
+    impl CustomExecutor for InlineExecutor {
+        fn spawn(&self, fut: leptos::task::any_spawner::PinnedFuture<()>) {
+            block_on(fut);
+        }
+
+        fn spawn_local(&self, fut: leptos::task::any_spawner::PinnedLocalFuture<()>) {
+            block_on(fut);
+        }
+
+        fn poll_local(&self) {}
+    }
+
+    fn init_executor() {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            let _ = Executor::init_custom_executor(InlineExecutor);
+        });
+    }
+
+    fn field(name: &str, rules: Vec<Rule>) -> FieldEntry {
+        FieldEntry {
+            key: 0,
+            name: NamePath::from(name),
+            label: None,
+            rules,
+            dependencies: Vec::new(),
+            validate_trigger: vec![ValidateTrigger::Change],
+            validate_debounce: 0,
+        }
+    }
+
+    fn errors(form: &FormInstance, name: &str) -> Vec<String> {
+        untrack(|| form.get_field_error(name))
+    }
+
+    #[test]
+    fn list_rows_keep_their_state_when_rows_are_removed_or_added() {
+        let form = FormInstance::new();
+        form.set_initial_values(json!({ "users": [{}, {}, {}], "other": null }));
+        for name in ["users.0.name", "users.1.name", "users.2.name", "other"] {
+            form.register(field(name, vec![Rule::required()]));
+        }
+        assert!(block_on(form.validate_fields()).is_err());
+
+        let users = FormListOperations::new(form, NamePath::from("users"));
+        users.remove(1);
+        assert_eq!(untrack(|| form.get_field_value("users")), json!([{}, {}]));
+        assert_eq!(errors(&form, "users.0.name"), ["users.0.name is required"]);
+        assert_eq!(errors(&form, "users.1.name"), ["users.2.name is required"]);
+        assert!(errors(&form, "users.2.name").is_empty());
+        assert_eq!(errors(&form, "other"), ["other is required"]);
+
+        users.insert(0, json!({ "name": "new" }));
+        assert_eq!(
+            untrack(|| form.get_field_value("users")),
+            json!([{ "name": "new" }, {}, {}])
+        );
+        assert!(errors(&form, "users.0.name").is_empty());
+        assert_eq!(errors(&form, "users.1.name"), ["users.0.name is required"]);
+        assert_eq!(errors(&form, "users.2.name"), ["users.2.name is required"]);
+
+        users.add(json!({ "name": "last" }));
+        users.move_row(3, 0);
+        assert_eq!(
+            untrack(|| form.get_field_value("users.0.name")),
+            json!("last")
+        );
+        assert!(errors(&form, "users.0.name").is_empty());
+        assert_eq!(errors(&form, "users.2.name"), ["users.0.name is required"]);
+    }
+
+    #[test]
+    fn changes_revalidate_touched_dependent_fields() {
+        init_executor();
+        let form = FormInstance::new();
+        let password = form.register(field("password", vec![Rule::required()]));
+        let same_as_password = || {
+            Rule::validator(|value: Value, values: Value| async move {
+                if value == values["password"] {
+                    Ok(())
+                } else {
+                    Err("Passwords differ".to_string())
+                }
+            })
+        };
+        let confirm = form.register(FieldEntry {
+            dependencies: vec![NamePath::from("password")],
+            ..field("confirm", vec![same_as_password()])
+        });
+        form.register(FieldEntry {
+            dependencies: vec![NamePath::from("password")],
+            ..field("untouched", vec![same_as_password()])
+        });
+
+        form.change_field(password, json!("a"));
+        form.change_field(confirm, json!("a"));
+        assert!(errors(&form, "confirm").is_empty());
+
+        form.change_field(password, json!("b"));
+        assert_eq!(errors(&form, "confirm"), ["Passwords differ"]);
+        assert!(errors(&form, "untouched").is_empty());
+
+        form.change_field(confirm, json!("b"));
+        assert!(errors(&form, "confirm").is_empty());
+
+        form.change_field(password, Value::Null);
+        assert_eq!(errors(&form, "password"), ["password is required"]);
+        assert_eq!(errors(&form, "confirm"), ["Passwords differ"]);
+    }
+
+    #[test]
+    fn submit_calls_on_finish_or_on_finish_failed() {
+        let finished = Arc::new(Mutex::new(Vec::new()));
+        let failed = Arc::new(Mutex::new(Vec::new()));
+        let on_finish = {
+            let finished = finished.clone();
+            Callback::new(move |values: Value| finished.lock().unwrap().push(values))
+        };
+        let on_finish_failed = {
+            let failed = failed.clone();
+            Callback::new(move |errors: Vec<FieldError>| failed.lock().unwrap().push(errors))
+        };
+
+        let form = FormInstance::new();
+        form.register(field("name", vec![Rule::required()]));
+        form.register(field("nick", vec![Rule::min(3.0).with_warning_only()]));
+        form.set_field_value("nick", json!("ab"));
+
+        block_on(form.submit(Some(on_finish), Some(on_finish_failed), false));
+        assert!(finished.lock().unwrap().is_empty());
+        assert_eq!(
+            *failed.lock().unwrap(),
+            [vec![FieldError {
+                name: NamePath::from("name"),
+                errors: vec!["name is required".to_string()],
+                warnings: Vec::new(),
+            }]]
+        );
+
+        // 只有警告时仍然提交
+        form.set_field_value("name", json!("Ada"));
+        block_on(form.submit(Some(on_finish), Some(on_finish_failed), false));
+        assert_eq!(
+            *finished.lock().unwrap(),
+            [json!({ "name": "Ada", "nick": "ab" })]
+        );
+        assert_eq!(failed.lock().unwrap().len(), 1);
+        assert_eq!(
+            untrack(|| form.get_field_warning("nick")),
+            ["nick must be at least 3 characters"]
+        );
+    }
+}
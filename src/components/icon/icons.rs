@@ -0,0 +1,13 @@
+//! 内置图标
+//!
+//! 由构建脚本根据 `icons/{outlined,filled,twotone}/*.svg` 生成，常量名为图标名
+//! 加主题后缀，例如 `CHECK_CIRCLE_OUTLINED`、`CHECK_CIRCLE_TWO_TONE`。
+//!
+//! 目前只内置了组件自身用到的图标和少量常用图标，尚未包含
+//! `@ant-design/icons-svg` 的完整图标集，也没有按主题划分的特性开关。需要其他
+//! 图标时，把该包 `inline-svg/{outlined,filled,twotone}/` 下的 SVG 文件放入对应
+//! 目录即可，或者通过 `symbol` 引用自定义的 SVG sprite。
+
+use super::{IconDefinition, IconPath, IconTheme, PathFill};
+
+include!(concat!(env!("OUT_DIR"), "/icons.rs"));
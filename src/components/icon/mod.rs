@@ -0,0 +1,261 @@
+//! 图标组件
+//!
+//! 内置图标在构建时由 `icons/` 目录下的 SVG 文件生成为 [`icons`] 中的常量，
+//! 每个图标一个常量，未使用的图标不会进入最终产物。也可以注册自定义的 SVG
+//! sprite 或 iconfont 脚本，通过 `symbol` 引用其中的符号。
+
+pub mod icons;
+
+use crate::components::config_provider::{use_config, Config, DesignTokenContext};
+use crate::styles::apply_icon_style;
+use crate::utils::color::{generate, GenerateOptions};
+use crate::utils::dom::document;
+use crate::utils::hash::hash_str;
+use leptos::prelude::*;
+
+/// Theme of a built-in icon
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IconTheme {
+    /// Outlined
+    Outlined,
+    /// Filled
+    Filled,
+    /// Two-tone, filled with a primary and a secondary color
+    TwoTone,
+}
+
+impl IconTheme {
+    /// Name of the theme
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IconTheme::Outlined => "outlined",
+            IconTheme::Filled => "filled",
+            IconTheme::TwoTone => "twotone",
+        }
+    }
+}
+
+/// Fill of an icon path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathFill {
+    /// Inherits the text color
+    Current,
+    /// Primary color of a two-tone icon
+    Primary,
+    /// Secondary color of a two-tone icon
+    Secondary,
+}
+
+/// One path of an icon
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconPath {
+    /// Path data
+    pub d: &'static str,
+    /// Fill of the path
+    pub fill: PathFill,
+}
+
+/// A built-in SVG icon
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconDefinition {
+    /// Icon name, e.g. `check-circle`
+    pub name: &'static str,
+    /// Icon theme
+    pub theme: IconTheme,
+    /// SVG `viewBox`
+    pub view_box: &'static str,
+    /// Paths, drawn in order
+    pub paths: &'static [IconPath],
+}
+
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+/// Colors of a two-tone icon, `(primary, secondary)`
+///
+/// The secondary color is the lightest shade of the primary color's palette.
+pub fn two_tone_colors(primary: &str) -> (String, String) {
+    let secondary = generate(primary, &GenerateOptions::default())
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| primary.to_string());
+    (primary.to_string(), secondary)
+}
+
+/// Render the SVG markup of an icon
+pub fn render_icon_svg(icon: &IconDefinition, two_tone: (&str, &str), style: &str) -> String {
+    let paths: String = icon
+        .paths
+        .iter()
+        .map(|path| match path.fill {
+            PathFill::Current => format!("<path d=\"{}\"></path>", path.d),
+            PathFill::Primary => format!(
+                "<path d=\"{}\" fill=\"{}\"></path>",
+                path.d,
+                escape_attr(two_tone.0)
+            ),
+            PathFill::Secondary => format!(
+                "<path d=\"{}\" fill=\"{}\"></path>",
+                path.d,
+                escape_attr(two_tone.1)
+            ),
+        })
+        .collect();
+    format!(
+        "<svg viewBox=\"{}\" focusable=\"false\" data-icon=\"{}\" width=\"1em\" height=\"1em\" \
+         fill=\"currentColor\" aria-hidden=\"true\"{}>{}</svg>",
+        icon.view_box,
+        icon.name,
+        style_attr(style),
+        paths
+    )
+}
+
+/// Render the SVG markup referencing a registered symbol
+pub fn render_symbol_svg(symbol: &str, style: &str) -> String {
+    format!(
+        "<svg width=\"1em\" height=\"1em\" fill=\"currentColor\" aria-hidden=\"true\" \
+         focusable=\"false\"{}><use href=\"#{}\"></use></svg>",
+        style_attr(style),
+        escape_attr(symbol)
+    )
+}
+
+fn style_attr(style: &str) -> String {
+    if style.is_empty() {
+        String::new()
+    } else {
+        format!(" style=\"{}\"", escape_attr(style))
+    }
+}
+
+/// Load an iconfont.cn script, whose symbols can then be used as `symbol`
+///
+/// The script is added to the page once per URL. Does nothing on the server.
+pub fn register_iconfont(script_url: &str) {
+    let Some(document) = document() else {
+        return;
+    };
+    let id = format!("iconfont-{}", hash_str(script_url));
+    if document.get_element_by_id(&id).is_some() {
+        return;
+    }
+    let Ok(script) = document.create_element("script") else {
+        return;
+    };
+    script.set_id(&id);
+    let _ = script.set_attribute("src", script_url);
+    if let Some(body) = document.body() {
+        let _ = body.append_child(&script);
+    }
+}
+
+/// Add an SVG sprite, i.e. markup containing `<symbol>` elements, to the page
+///
+/// Registering the same `id` again replaces the sprite. Does nothing on the
+/// server.
+pub fn register_svg_sprite(id: &str, sprite: &str) {
+    let Some(document) = document() else {
+        return;
+    };
+    let container = match document.get_element_by_id(id) {
+        Some(container) => container,
+        None => {
+            let Ok(container) = document.create_element("div") else {
+                return;
+            };
+            container.set_id(id);
+            let _ = container.set_attribute("aria-hidden", "true");
+            let _ = container.set_attribute(
+                "style",
+                "position:absolute;width:0;height:0;overflow:hidden",
+            );
+            let Some(body) = document.body() else {
+                return;
+            };
+            if body.append_child(&container).is_err() {
+                return;
+            }
+            container
+        }
+    };
+    container.set_inner_html(sprite);
+}
+
+/// 图标组件
+///
+/// 传入内置图标 `icon`，或已注册的 sprite 符号 `symbol`。`loading` 图标默认旋转。
+#[component]
+pub fn Icon(
+    /// 内置图标，例如 [`icons::CHECK_OUTLINED`]
+    #[prop(optional)]
+    icon: Option<IconDefinition>,
+    /// 已注册的 sprite 或 iconfont 中的符号 id
+    #[prop(optional, into)]
+    symbol: Option<String>,
+    /// 是否旋转
+    #[prop(optional, into)]
+    spin: Signal<bool>,
+    /// 旋转角度
+    #[prop(optional, into)]
+    rotate: Option<Signal<f64>>,
+    /// 双色图标的主色，默认使用主题主色
+    #[prop(optional, into)]
+    two_tone_color: Option<Signal<String>>,
+    /// 额外的类名
+    #[prop(optional, into)]
+    class: Option<String>,
+) -> impl IntoView {
+    let hash_cls = apply_icon_style();
+    let icon_prefix_cls = use_config()
+        .map(|config| config.with_untracked(|config| config.icon_prefix_cls.clone()))
+        .unwrap_or_else(|| Config::default().icon_prefix_cls);
+    let design = use_context::<DesignTokenContext>();
+    let name = icon.map(|icon| icon.name);
+
+    let class = move || {
+        let mut classes = vec![icon_prefix_cls.clone(), hash_cls.get()];
+        if let Some(name) = name {
+            classes.push(format!("{}-{}", icon_prefix_cls, name));
+        }
+        if spin.get() || name == Some("loading") {
+            classes.push(format!("{}-spin", icon_prefix_cls));
+        }
+        classes.extend(class.clone());
+        classes.join(" ")
+    };
+
+    let svg = move || {
+        let style = rotate
+            .map(|rotate| format!("transform:rotate({}deg)", rotate.get()))
+            .unwrap_or_default();
+        match (icon, &symbol) {
+            (Some(icon), _) => {
+                let (primary, secondary) = if icon.theme == IconTheme::TwoTone {
+                    let primary = two_tone_color
+                        .map(|color| color.get())
+                        .or_else(|| {
+                            design.map(|design| {
+                                design.with(|design| design.token.map.colors.color_primary.clone())
+                            })
+                        })
+                        .unwrap_or_else(|| Config::default().theme.primary_color);
+                    two_tone_colors(&primary)
+                } else {
+                    Default::default()
+                };
+                render_icon_svg(&icon, (&primary, &secondary), &style)
+            }
+            (None, Some(symbol)) => render_symbol_svg(symbol, &style),
+            (None, None) => String::new(),
+        }
+    };
+
+    view! {
+        <span role="img" aria-label=name class=class inner_html=svg></span>
+    }
+}
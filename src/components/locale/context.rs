@@ -1,31 +1,95 @@
 //! 本地化上下文组件
 
-use crate::components::locale::types::{Language, LocaleConfig, LocaleContext};
+use crate::components::config_provider::{get_component_cls, Direction, DirectionContext};
+use crate::components::locale::registry::resolve_locale;
+use crate::components::locale::types::{Language, LocaleConfig};
+use crate::styles::apply_locale;
 use leptos::prelude::*;
+use leptos::tachys::reactive_graph::OwnedView;
+
+/// Fill the formats and direction of a config from the registry
+fn localize(mut config: LocaleConfig) -> LocaleConfig {
+    let resolved = resolve_locale(config.language.tag());
+    config.number_format = resolved.number_format;
+    config.date_format = resolved.date_format;
+    config.rtl.enabled = resolved.rtl;
+    config
+}
 
 /// 本地化提供器组件
+///
+/// 可以只传入语言，数字、日期格式与文字方向从语言包注册表中解析；也可以传入完整
+/// 配置，此时配置中的格式保持不变，只有语言切换到与配置不同的语言时才重新解析。
+/// RTL 语言只作用于当前子树。
 #[component]
 pub fn LocaleProvider(
     /// 当前语言
-    #[prop(into)]
-    current_language: Signal<Language>,
+    #[prop(optional, into)]
+    current_language: Option<Signal<Language>>,
+    /// 完整的本地化配置
+    #[prop(optional, into)]
+    config: Option<Signal<LocaleConfig>>,
     /// 子元素
     children: Children,
 ) -> impl IntoView {
-    // 创建本地化配置信号
-    let locale_config = create_rw_signal(LocaleConfig::default());
-
-    // 监听语言变化并更新配置
-    create_effect(move |_| {
-        locale_config.update(|config| {
-            config.language = current_language.get();
-        });
+    let resolve_config = move || {
+        let base = config.map(|config| config.get());
+        let language = current_language.map(|language| language.get());
+        match (base, language) {
+            (Some(base), Some(language)) if base.language != language => {
+                localize(LocaleConfig { language, ..base })
+            }
+            (Some(base), _) => base,
+            (None, language) => localize(LocaleConfig {
+                language: language.unwrap_or(LocaleConfig::default().language),
+                ..LocaleConfig::default()
+            }),
+        }
+    };
+
+    // 创建本地化配置信号，输入变化时同步更新
+    let locale_config = RwSignal::new(untrack(resolve_config));
+    Effect::new(move |initialized: Option<()>| {
+        let next = resolve_config();
+        if initialized.is_some() {
+            locale_config.set(next);
+        }
+    });
+
+    // 上下文只对子树可见
+    let owner = Owner::new();
+    owner.with(|| provide_context(locale_config));
+
+    // RTL 只作用于当前子树，未启用时沿用上层方向
+    let parent_direction = use_context::<DirectionContext>();
+    let resolve_direction = move || {
+        if locale_config.with(|config| config.rtl.enabled) {
+            Direction::Rtl
+        } else {
+            parent_direction
+                .map(|direction| direction.get())
+                .unwrap_or(Direction::Ltr)
+        }
+    };
+    let direction: DirectionContext = RwSignal::new(untrack(resolve_direction));
+    owner.with(|| provide_context(direction));
+    Effect::new(move |_| {
+        let next = resolve_direction();
+        if direction.get_untracked() != next {
+            direction.set(next);
+        }
     });
 
-    // 提供上下文
-    provide_context(locale_config);
+    let hash_cls = apply_locale();
+    let class = move || format!("{} {}", get_component_cls("locale"), hash_cls.get());
 
-    view! {
-        {children()}
-    }
+    let children = owner.with(children);
+    OwnedView::new_with_owner(
+        view! {
+            <div class=class dir=move || direction.get().as_str()>
+                {children}
+            </div>
+        },
+        owner,
+    )
 }
@@ -0,0 +1,109 @@
+//! 阿拉伯文的默认语言包
+
+use crate::components::locale::registry::LocalePack;
+use crate::components::locale::types::*;
+use chrono::Weekday;
+use serde_json::json;
+
+/// 阿拉伯文语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        texts: json!({
+            "placeholder": "يرجى الاختيار",
+            "selectPlaceholder": "يرجى الاختيار",
+            "modal": { "okText": "موافق", "cancelText": "إلغاء", "justOkText": "موافق" },
+            "popconfirm": { "ok": "موافق", "cancel": "إلغاء", "yes": "نعم", "no": "لا" },
+            "form": {
+                "optional": "اختياري",
+                "required": "مطلوب",
+                "validateMessages": {
+                    "default": "خطأ في حقل الإدخال ${label}",
+                    "required": "يرجى إدخال ${label}",
+                    "enum": "يجب أن يكون ${label} واحدًا من [${enum}]",
+                    "whitespace": "لا يمكن أن يكون ${label} فارغًا",
+                    "types": {
+                        "string": "${label} ليس نصًا صالحًا",
+                        "number": "${label} ليس رقمًا صالحًا",
+                        "boolean": "${label} ليس قيمة منطقية صالحة",
+                        "integer": "${label} ليس عددًا صحيحًا صالحًا",
+                        "float": "${label} ليس عددًا عشريًا صالحًا",
+                        "array": "${label} ليس مصفوفة صالحة",
+                        "object": "${label} ليس كائنًا صالحًا",
+                        "email": "${label} ليس بريدًا إلكترونيًا صالحًا",
+                        "url": "${label} ليس رابطًا صالحًا",
+                    },
+                    "string": {
+                        "len": "يجب أن يتكون ${label} من ${len} أحرف",
+                        "min": "يجب أن يتكون ${label} من ${min} أحرف على الأقل",
+                        "max": "يجب ألا يزيد ${label} عن ${max} أحرف",
+                        "range": "يجب أن يتكون ${label} من ${min} إلى ${max} أحرف",
+                    },
+                    "number": {
+                        "len": "يجب أن يساوي ${label} ${len}",
+                        "min": "الحد الأدنى لـ ${label} هو ${min}",
+                        "max": "الحد الأقصى لـ ${label} هو ${max}",
+                        "range": "يجب أن يكون ${label} بين ${min} و ${max}",
+                    },
+                    "array": {
+                        "len": "يجب أن يكون عدد ${label} ${len}",
+                        "min": "يجب ألا يقل عدد ${label} عن ${min}",
+                        "max": "يجب ألا يزيد عدد ${label} عن ${max}",
+                        "range": "يجب أن يكون عدد ${label} بين ${min} و ${max}",
+                    },
+                    "pattern": "${label} لا يطابق النمط ${pattern}",
+                },
+            },
+            "table": { "filterTitle": "تصفية", "emptyText": "لا توجد بيانات" },
+            "upload": { "uploadText": "رفع", "removeText": "إزالة" },
+            "empty": { "description": "لا توجد بيانات" },
+        }),
+        number_format: Some(NumberFormatConfig {
+            currency_symbol: "ج.م.\u{200f}".to_string(),
+            currency_code: "EGP".to_string(),
+            currency_position: CurrencyPosition::After,
+            currency_spacing: true,
+            currency_fraction_digits: 2,
+            decimal_separator: ".".to_string(),
+            group_separator: ",".to_string(),
+            percent_suffix: "%".to_string(),
+            compact_units: vec![
+                (3, " ألف".to_string()),
+                (6, " مليون".to_string()),
+                (9, " مليار".to_string()),
+                (12, " ترليون".to_string()),
+            ],
+            ..NumberFormatConfig::en_us()
+        }),
+        date_format: Some(DateFormatConfig {
+            months: names(
+                "يناير_فبراير_مارس_أبريل_مايو_يونيو_يوليو_أغسطس_سبتمبر_أكتوبر_نوفمبر_ديسمبر",
+            ),
+            months_short: names(
+                "يناير_فبراير_مارس_أبريل_مايو_يونيو_يوليو_أغسطس_سبتمبر_أكتوبر_نوفمبر_ديسمبر",
+            ),
+            weekdays: names("الأحد_الإثنين_الثلاثاء_الأربعاء_الخميس_الجمعة_السبت"),
+            weekdays_short: names("أحد_إثنين_ثلاثاء_أربعاء_خميس_جمعة_سبت"),
+            weekdays_min: names("ح_ن_ث_ر_خ_ج_س"),
+            meridiem: vec![(0, "ص".to_string()), (1200, "م".to_string())],
+            week_start: Weekday::Sat,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "بعد {}".to_string(),
+                past: "منذ {}".to_string(),
+                seconds: "ثانية واحدة".to_string(),
+                minute: "دقيقة واحدة".to_string(),
+                minutes: "{} دقائق".to_string(),
+                hour: "ساعة واحدة".to_string(),
+                hours: "{} ساعات".to_string(),
+                day: "يوم واحد".to_string(),
+                days: "{} أيام".to_string(),
+                month: "شهر واحد".to_string(),
+                months: "{} أشهر".to_string(),
+                year: "عام واحد".to_string(),
+                years: "{} أعوام".to_string(),
+            },
+        }),
+        rtl: Some(true),
+        ..LocalePack::new("ar-EG")
+    }
+}
@@ -0,0 +1,107 @@
+//! 德文的默认语言包
+
+use crate::components::locale::registry::LocalePack;
+use crate::components::locale::types::*;
+use chrono::Weekday;
+use serde_json::json;
+
+/// 德文语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        texts: json!({
+            "placeholder": "Bitte auswählen",
+            "selectPlaceholder": "Bitte auswählen",
+            "modal": { "okText": "OK", "cancelText": "Abbrechen", "justOkText": "OK" },
+            "popconfirm": { "ok": "OK", "cancel": "Abbrechen", "yes": "Ja", "no": "Nein" },
+            "form": {
+                "optional": "optional",
+                "required": "erforderlich",
+                "validateMessages": {
+                    "default": "Feld-Validierungsfehler: ${label}",
+                    "required": "Bitte geben Sie ${label} an",
+                    "enum": "${label} muss eines der folgenden sein: [${enum}]",
+                    "whitespace": "${label} darf nicht leer sein",
+                    "types": {
+                        "string": "${label} ist keine gültige Zeichenkette",
+                        "number": "${label} ist keine gültige Zahl",
+                        "boolean": "${label} ist kein gültiger Wahrheitswert",
+                        "integer": "${label} ist keine gültige Ganzzahl",
+                        "float": "${label} ist keine gültige Dezimalzahl",
+                        "array": "${label} ist keine gültige Liste",
+                        "object": "${label} ist kein gültiges Objekt",
+                        "email": "${label} ist keine gültige E-Mail-Adresse",
+                        "url": "${label} ist keine gültige URL",
+                    },
+                    "string": {
+                        "len": "${label} muss genau ${len} Zeichen lang sein",
+                        "min": "${label} muss mindestens ${min} Zeichen lang sein",
+                        "max": "${label} darf höchstens ${max} Zeichen lang sein",
+                        "range": "${label} muss zwischen ${min} und ${max} Zeichen lang sein",
+                    },
+                    "number": {
+                        "len": "${label} muss gleich ${len} sein",
+                        "min": "${label} muss mindestens ${min} sein",
+                        "max": "${label} darf höchstens ${max} sein",
+                        "range": "${label} muss zwischen ${min} und ${max} liegen",
+                    },
+                    "array": {
+                        "len": "Es müssen genau ${len} ${label} sein",
+                        "min": "Es müssen mindestens ${min} ${label} sein",
+                        "max": "Es dürfen höchstens ${max} ${label} sein",
+                        "range": "Die Anzahl an ${label} muss zwischen ${min} und ${max} liegen",
+                    },
+                    "pattern": "${label} entspricht nicht dem Muster ${pattern}",
+                },
+            },
+            "table": { "filterTitle": "Filter", "emptyText": "Keine Daten" },
+            "upload": { "uploadText": "Hochladen", "removeText": "Entfernen" },
+            "empty": { "description": "Keine Daten" },
+        }),
+        number_format: Some(NumberFormatConfig {
+            currency_symbol: "€".to_string(),
+            currency_code: "EUR".to_string(),
+            currency_position: CurrencyPosition::After,
+            currency_spacing: true,
+            currency_fraction_digits: 2,
+            decimal_separator: ",".to_string(),
+            group_separator: ".".to_string(),
+            percent_suffix: "\u{a0}%".to_string(),
+            compact_units: vec![
+                (6, "\u{a0}Mio.".to_string()),
+                (9, "\u{a0}Mrd.".to_string()),
+                (12, "\u{a0}Bio.".to_string()),
+            ],
+            ..NumberFormatConfig::en_us()
+        }),
+        date_format: Some(DateFormatConfig {
+            months: names("Januar_Februar_März_April_Mai_Juni_Juli_August_September_Oktober_November_Dezember"),
+            months_short: names("Jan._Feb._März_Apr._Mai_Juni_Juli_Aug._Sept._Okt._Nov._Dez."),
+            weekdays: names("Sonntag_Montag_Dienstag_Mittwoch_Donnerstag_Freitag_Samstag"),
+            weekdays_short: names("So._Mo._Di._Mi._Do._Fr._Sa."),
+            weekdays_min: names("So_Mo_Di_Mi_Do_Fr_Sa"),
+            meridiem: vec![
+                (0, "AM".to_string()),
+                (1200, "PM".to_string()),
+            ],
+            week_start: Weekday::Mon,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "in {}".to_string(),
+                past: "vor {}".to_string(),
+                seconds: "ein paar Sekunden".to_string(),
+                minute: "einer Minute".to_string(),
+                minutes: "{} Minuten".to_string(),
+                hour: "einer Stunde".to_string(),
+                hours: "{} Stunden".to_string(),
+                day: "einem Tag".to_string(),
+                days: "{} Tagen".to_string(),
+                month: "einem Monat".to_string(),
+                months: "{} Monaten".to_string(),
+                year: "einem Jahr".to_string(),
+                years: "{} Jahren".to_string(),
+            },
+        }),
+        rtl: Some(false),
+        ..LocalePack::new("de-DE")
+    }
+}
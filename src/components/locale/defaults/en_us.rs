@@ -1,5 +1,7 @@
 //! 英语（美国）的默认文本定义
 
+use crate::components::config_provider::ValidateMessages;
+use crate::components::locale::registry::LocalePack;
 use crate::components::locale::types::*;
 
 /// 获取英语（美国）的默认文本
@@ -22,6 +24,7 @@ pub fn get_default_texts() -> LocaleText {
         form: FormLocaleText {
             optional: "Optional".to_string(),
             required: "Required".to_string(),
+            validate_messages: ValidateMessages::default(),
         },
         table: TableLocaleText {
             filter_title: "Filter".to_string(),
@@ -36,3 +39,13 @@ pub fn get_default_texts() -> LocaleText {
         },
     }
 }
+
+/// 英语（美国）语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        number_format: Some(NumberFormatConfig::en_us()),
+        date_format: Some(DateFormatConfig::en_us()),
+        rtl: Some(false),
+        ..LocalePack::new("en-US").with_texts(&get_default_texts())
+    }
+}
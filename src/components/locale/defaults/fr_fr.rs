@@ -0,0 +1,108 @@
+//! 法文的默认语言包
+
+use crate::components::locale::registry::LocalePack;
+use crate::components::locale::types::*;
+use chrono::Weekday;
+use serde_json::json;
+
+/// 法文语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        texts: json!({
+            "placeholder": "Veuillez sélectionner",
+            "selectPlaceholder": "Veuillez sélectionner",
+            "modal": { "okText": "OK", "cancelText": "Annuler", "justOkText": "OK" },
+            "popconfirm": { "ok": "OK", "cancel": "Annuler", "yes": "Oui", "no": "Non" },
+            "form": {
+                "optional": "optionnel",
+                "required": "obligatoire",
+                "validateMessages": {
+                    "default": "Erreur de validation du champ ${label}",
+                    "required": "Le champ ${label} est obligatoire",
+                    "enum": "${label} doit être parmi [${enum}]",
+                    "whitespace": "${label} ne doit pas être vide",
+                    "types": {
+                        "string": "${label} n'est pas une chaîne valide",
+                        "number": "${label} n'est pas un nombre valide",
+                        "boolean": "${label} n'est pas un booléen valide",
+                        "integer": "${label} n'est pas un entier valide",
+                        "float": "${label} n'est pas un nombre décimal valide",
+                        "array": "${label} n'est pas un tableau valide",
+                        "object": "${label} n'est pas un objet valide",
+                        "email": "${label} n'est pas une adresse e-mail valide",
+                        "url": "${label} n'est pas une URL valide",
+                    },
+                    "string": {
+                        "len": "La taille du champ ${label} doit être de ${len} caractères",
+                        "min": "La taille du champ ${label} doit être au minimum de ${min} caractères",
+                        "max": "La taille du champ ${label} doit être au maximum de ${max} caractères",
+                        "range": "La taille du champ ${label} doit être entre ${min} et ${max} caractères",
+                    },
+                    "number": {
+                        "len": "${label} doit être égal à ${len}",
+                        "min": "${label} doit être supérieur ou égal à ${min}",
+                        "max": "${label} doit être inférieur ou égal à ${max}",
+                        "range": "${label} doit être entre ${min} et ${max}",
+                    },
+                    "array": {
+                        "len": "La taille du tableau ${label} doit être de ${len}",
+                        "min": "La taille du tableau ${label} doit être au minimum de ${min}",
+                        "max": "La taille du tableau ${label} doit être au maximum de ${max}",
+                        "range": "La taille du tableau ${label} doit être entre ${min} et ${max}",
+                    },
+                    "pattern": "Le champ ${label} ne correspond pas au modèle ${pattern}",
+                },
+            },
+            "table": { "filterTitle": "Filtrer", "emptyText": "Aucune donnée" },
+            "upload": { "uploadText": "Télécharger", "removeText": "Supprimer" },
+            "empty": { "description": "Aucune donnée" },
+        }),
+        number_format: Some(NumberFormatConfig {
+            currency_symbol: "€".to_string(),
+            currency_code: "EUR".to_string(),
+            currency_position: CurrencyPosition::After,
+            currency_spacing: true,
+            currency_fraction_digits: 2,
+            decimal_separator: ",".to_string(),
+            group_separator: "\u{202f}".to_string(),
+            percent_suffix: "\u{202f}%".to_string(),
+            compact_units: vec![
+                (3, "\u{a0}k".to_string()),
+                (6, "\u{a0}M".to_string()),
+                (9, "\u{a0}Md".to_string()),
+                (12, "\u{a0}Bn".to_string()),
+            ],
+            ..NumberFormatConfig::en_us()
+        }),
+        date_format: Some(DateFormatConfig {
+            months: names("janvier_février_mars_avril_mai_juin_juillet_août_septembre_octobre_novembre_décembre"),
+            months_short: names("janv._févr._mars_avr._mai_juin_juil._août_sept._oct._nov._déc."),
+            weekdays: names("dimanche_lundi_mardi_mercredi_jeudi_vendredi_samedi"),
+            weekdays_short: names("dim._lun._mar._mer._jeu._ven._sam."),
+            weekdays_min: names("di_lu_ma_me_je_ve_sa"),
+            meridiem: vec![
+                (0, "AM".to_string()),
+                (1200, "PM".to_string()),
+            ],
+            week_start: Weekday::Mon,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "dans {}".to_string(),
+                past: "il y a {}".to_string(),
+                seconds: "quelques secondes".to_string(),
+                minute: "une minute".to_string(),
+                minutes: "{} minutes".to_string(),
+                hour: "une heure".to_string(),
+                hours: "{} heures".to_string(),
+                day: "un jour".to_string(),
+                days: "{} jours".to_string(),
+                month: "un mois".to_string(),
+                months: "{} mois".to_string(),
+                year: "un an".to_string(),
+                years: "{} ans".to_string(),
+            },
+        }),
+        rtl: Some(false),
+        ..LocalePack::new("fr-FR")
+    }
+}
@@ -0,0 +1,104 @@
+//! 日文的默认语言包
+
+use crate::components::locale::registry::LocalePack;
+use crate::components::locale::types::*;
+use chrono::Weekday;
+use serde_json::json;
+
+/// 日文语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        texts: json!({
+            "placeholder": "選択してください",
+            "selectPlaceholder": "選択してください",
+            "modal": { "okText": "OK", "cancelText": "キャンセル", "justOkText": "OK" },
+            "popconfirm": { "ok": "OK", "cancel": "キャンセル", "yes": "はい", "no": "いいえ" },
+            "form": {
+                "optional": "任意",
+                "required": "必須",
+                "validateMessages": {
+                    "default": "${label}のフィールドが無効です",
+                    "required": "${label}を入力してください",
+                    "enum": "${label}は[${enum}]のいずれかである必要があります",
+                    "whitespace": "${label}は空白文字にすることはできません",
+                    "types": {
+                        "string": "${label}は有効な文字列ではありません",
+                        "number": "${label}は有効な数値ではありません",
+                        "boolean": "${label}は有効な真偽値ではありません",
+                        "integer": "${label}は有効な整数ではありません",
+                        "float": "${label}は有効な小数ではありません",
+                        "array": "${label}は有効な配列ではありません",
+                        "object": "${label}は有効なオブジェクトではありません",
+                        "email": "${label}は有効なメールアドレスではありません",
+                        "url": "${label}は有効なURLではありません",
+                    },
+                    "string": {
+                        "len": "${label}は${len}文字である必要があります",
+                        "min": "${label}は${min}文字以上である必要があります",
+                        "max": "${label}は${max}文字以下である必要があります",
+                        "range": "${label}は${min}-${max}文字の範囲である必要があります",
+                    },
+                    "number": {
+                        "len": "${label}は${len}と等しい必要があります",
+                        "min": "${label}の最小値は${min}です",
+                        "max": "${label}の最大値は${max}です",
+                        "range": "${label}は${min}-${max}の範囲である必要があります",
+                    },
+                    "array": {
+                        "len": "${label}は${len}個である必要があります",
+                        "min": "${label}は${min}個以上である必要があります",
+                        "max": "${label}は${max}個以下である必要があります",
+                        "range": "${label}の数は${min}-${max}の範囲である必要があります",
+                    },
+                    "pattern": "${label}はパターン${pattern}と一致しません",
+                },
+            },
+            "table": { "filterTitle": "フィルター", "emptyText": "データがありません" },
+            "upload": { "uploadText": "アップロード", "removeText": "削除" },
+            "empty": { "description": "データがありません" },
+        }),
+        number_format: Some(NumberFormatConfig {
+            currency_symbol: "¥".to_string(),
+            currency_code: "JPY".to_string(),
+            currency_position: CurrencyPosition::Before,
+            currency_spacing: false,
+            currency_fraction_digits: 0,
+            decimal_separator: ".".to_string(),
+            group_separator: ",".to_string(),
+            percent_suffix: "%".to_string(),
+            compact_units: vec![
+                (4, "万".to_string()),
+                (8, "億".to_string()),
+                (12, "兆".to_string()),
+            ],
+            ..NumberFormatConfig::en_us()
+        }),
+        date_format: Some(DateFormatConfig {
+            months: names("1月_2月_3月_4月_5月_6月_7月_8月_9月_10月_11月_12月"),
+            months_short: names("1月_2月_3月_4月_5月_6月_7月_8月_9月_10月_11月_12月"),
+            weekdays: names("日曜日_月曜日_火曜日_水曜日_木曜日_金曜日_土曜日"),
+            weekdays_short: names("日_月_火_水_木_金_土"),
+            weekdays_min: names("日_月_火_水_木_金_土"),
+            meridiem: vec![(0, "午前".to_string()), (1200, "午後".to_string())],
+            week_start: Weekday::Sun,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "{}後".to_string(),
+                past: "{}前".to_string(),
+                seconds: "数秒".to_string(),
+                minute: "1分".to_string(),
+                minutes: "{}分".to_string(),
+                hour: "1時間".to_string(),
+                hours: "{}時間".to_string(),
+                day: "1日".to_string(),
+                days: "{}日".to_string(),
+                month: "1ヶ月".to_string(),
+                months: "{}ヶ月".to_string(),
+                year: "1年".to_string(),
+                years: "{}年".to_string(),
+            },
+        }),
+        rtl: Some(false),
+        ..LocalePack::new("ja-JP")
+    }
+}
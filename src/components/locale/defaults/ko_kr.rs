@@ -0,0 +1,105 @@
+//! 韩文的默认语言包
+
+use crate::components::locale::registry::LocalePack;
+use crate::components::locale::types::*;
+use chrono::Weekday;
+use serde_json::json;
+
+/// 韩文语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        texts: json!({
+            "placeholder": "선택하세요",
+            "selectPlaceholder": "선택하세요",
+            "modal": { "okText": "확인", "cancelText": "취소", "justOkText": "확인" },
+            "popconfirm": { "ok": "확인", "cancel": "취소", "yes": "예", "no": "아니오" },
+            "form": {
+                "optional": "선택 사항",
+                "required": "필수",
+                "validateMessages": {
+                    "default": "필드 유효성 검사 오류 ${label}",
+                    "required": "${label} 필드를 입력하세요",
+                    "enum": "${label} 필드의 값은 [${enum}] 중 하나여야 합니다",
+                    "whitespace": "${label} 필드는 공백 문자일 수 없습니다",
+                    "types": {
+                        "string": "${label} 필드의 값이 유효한 문자열이 아닙니다",
+                        "number": "${label} 필드의 값이 유효한 숫자가 아닙니다",
+                        "boolean": "${label} 필드의 값이 유효한 불리언이 아닙니다",
+                        "integer": "${label} 필드의 값이 유효한 정수가 아닙니다",
+                        "float": "${label} 필드의 값이 유효한 실수가 아닙니다",
+                        "array": "${label} 필드의 값이 유효한 배열이 아닙니다",
+                        "object": "${label} 필드의 값이 유효한 객체가 아닙니다",
+                        "email": "${label} 필드의 값이 유효한 이메일이 아닙니다",
+                        "url": "${label} 필드의 값이 유효한 URL이 아닙니다",
+                    },
+                    "string": {
+                        "len": "${label} 필드는 ${len}자여야 합니다",
+                        "min": "${label} 필드는 ${min}자 이상이어야 합니다",
+                        "max": "${label} 필드는 ${max}자 이하여야 합니다",
+                        "range": "${label} 필드는 ${min}~${max}자 사이여야 합니다",
+                    },
+                    "number": {
+                        "len": "${label} 필드의 값은 ${len}이어야 합니다",
+                        "min": "${label} 필드의 값은 ${min} 이상이어야 합니다",
+                        "max": "${label} 필드의 값은 ${max} 이하여야 합니다",
+                        "range": "${label} 필드의 값은 ${min}~${max} 사이여야 합니다",
+                    },
+                    "array": {
+                        "len": "${label}은(는) ${len}개여야 합니다",
+                        "min": "${label}은(는) 최소 ${min}개여야 합니다",
+                        "max": "${label}은(는) 최대 ${max}개여야 합니다",
+                        "range": "${label}의 개수는 ${min}~${max}개 사이여야 합니다",
+                    },
+                    "pattern": "${label} 필드의 값이 ${pattern} 패턴과 일치하지 않습니다",
+                },
+            },
+            "table": { "filterTitle": "필터", "emptyText": "데이터 없음" },
+            "upload": { "uploadText": "업로드", "removeText": "삭제" },
+            "empty": { "description": "데이터 없음" },
+        }),
+        number_format: Some(NumberFormatConfig {
+            currency_symbol: "₩".to_string(),
+            currency_code: "KRW".to_string(),
+            currency_position: CurrencyPosition::Before,
+            currency_spacing: false,
+            currency_fraction_digits: 0,
+            decimal_separator: ".".to_string(),
+            group_separator: ",".to_string(),
+            percent_suffix: "%".to_string(),
+            compact_units: vec![
+                (3, "천".to_string()),
+                (4, "만".to_string()),
+                (8, "억".to_string()),
+                (12, "조".to_string()),
+            ],
+            ..NumberFormatConfig::en_us()
+        }),
+        date_format: Some(DateFormatConfig {
+            months: names("1월_2월_3월_4월_5월_6월_7월_8월_9월_10월_11월_12월"),
+            months_short: names("1월_2월_3월_4월_5월_6월_7월_8월_9월_10월_11월_12월"),
+            weekdays: names("일요일_월요일_화요일_수요일_목요일_금요일_토요일"),
+            weekdays_short: names("일_월_화_수_목_금_토"),
+            weekdays_min: names("일_월_화_수_목_금_토"),
+            meridiem: vec![(0, "오전".to_string()), (1200, "오후".to_string())],
+            week_start: Weekday::Sun,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "{} 후".to_string(),
+                past: "{} 전".to_string(),
+                seconds: "몇 초".to_string(),
+                minute: "1분".to_string(),
+                minutes: "{}분".to_string(),
+                hour: "한 시간".to_string(),
+                hours: "{}시간".to_string(),
+                day: "하루".to_string(),
+                days: "{}일".to_string(),
+                month: "한 달".to_string(),
+                months: "{}달".to_string(),
+                year: "일 년".to_string(),
+                years: "{}년".to_string(),
+            },
+        }),
+        rtl: Some(false),
+        ..LocalePack::new("ko-KR")
+    }
+}
@@ -1,11 +1,53 @@
 //! 默认的本地化文本定义
 //!
-//! 这个模块包含了不同语言的默认文本定义，目前支持：
-//! - 英语（美国）
-//! - 中文（简体）
+//! 这个模块包含了不同语言的默认语言包。英语（美国）与中文（简体）始终内置，
+//! 其余语言需要开启对应的特性：
+//! - `locale-zh-tw`：中文（繁体）
+//! - `locale-ja-jp`：日文
+//! - `locale-ko-kr`：韩文
+//! - `locale-de-de`：德文
+//! - `locale-fr-fr`：法文
+//! - `locale-ar-eg`：阿拉伯文
+//!
+//! `all-locales` 特性开启全部语言。
+
+use super::registry::LocalePack;
 
 pub mod en_us;
 pub mod zh_cn;
 
+#[cfg(feature = "locale-ar-eg")]
+pub mod ar_eg;
+#[cfg(feature = "locale-de-de")]
+pub mod de_de;
+#[cfg(feature = "locale-fr-fr")]
+pub mod fr_fr;
+#[cfg(feature = "locale-ja-jp")]
+pub mod ja_jp;
+#[cfg(feature = "locale-ko-kr")]
+pub mod ko_kr;
+#[cfg(feature = "locale-zh-tw")]
+pub mod zh_tw;
+
 pub use en_us::get_default_texts as get_en_us_texts;
 pub use zh_cn::get_default_texts as get_zh_cn_texts;
+
+/// 编译期内置的语言包
+pub fn builtin_packs() -> Vec<LocalePack> {
+    vec![
+        en_us::pack(),
+        zh_cn::pack(),
+        #[cfg(feature = "locale-ar-eg")]
+        ar_eg::pack(),
+        #[cfg(feature = "locale-de-de")]
+        de_de::pack(),
+        #[cfg(feature = "locale-fr-fr")]
+        fr_fr::pack(),
+        #[cfg(feature = "locale-ja-jp")]
+        ja_jp::pack(),
+        #[cfg(feature = "locale-ko-kr")]
+        ko_kr::pack(),
+        #[cfg(feature = "locale-zh-tw")]
+        zh_tw::pack(),
+    ]
+}
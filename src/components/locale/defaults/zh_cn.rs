@@ -1,5 +1,9 @@
 //! 中文（简体）的默认文本定义
 
+use crate::components::config_provider::{
+    ValidateLengthMessages, ValidateMessages, ValidateTypeMessages,
+};
+use crate::components::locale::registry::LocalePack;
 use crate::components::locale::types::*;
 
 /// 获取中文（简体）的默认文本
@@ -22,6 +26,7 @@ pub fn get_default_texts() -> LocaleText {
         form: FormLocaleText {
             optional: "可选".to_string(),
             required: "必填".to_string(),
+            validate_messages: validate_messages(),
         },
         table: TableLocaleText {
             filter_title: "筛选".to_string(),
@@ -36,3 +41,54 @@ pub fn get_default_texts() -> LocaleText {
         },
     }
 }
+
+/// 表单校验信息
+fn validate_messages() -> ValidateMessages {
+    let text = |text: &str| text.to_string();
+    ValidateMessages {
+        default: text("字段验证错误${label}"),
+        required: text("请输入${label}"),
+        enumeration: text("${label}必须是其中一个[${enum}]"),
+        whitespace: text("${label}不能为空字符"),
+        types: ValidateTypeMessages {
+            string: text("${label}不是一个有效的字符串"),
+            number: text("${label}不是一个有效的数字"),
+            boolean: text("${label}不是一个有效的布尔值"),
+            integer: text("${label}不是一个有效的整数"),
+            float: text("${label}不是一个有效的浮点数"),
+            array: text("${label}不是一个有效的数组"),
+            object: text("${label}不是一个有效的对象"),
+            email: text("${label}不是一个有效的邮箱"),
+            url: text("${label}不是一个有效的网址"),
+        },
+        length: ValidateLengthMessages {
+            len: text("${label}须为${len}个字符"),
+            min: text("${label}最少${min}个字符"),
+            max: text("${label}最多${max}个字符"),
+            range: text("${label}须在${min}-${max}字符之间"),
+        },
+        number: ValidateLengthMessages {
+            len: text("${label}必须等于${len}"),
+            min: text("${label}最小值为${min}"),
+            max: text("${label}最大值为${max}"),
+            range: text("${label}须在${min}-${max}之间"),
+        },
+        array: ValidateLengthMessages {
+            len: text("须为${len}个${label}"),
+            min: text("最少${min}个${label}"),
+            max: text("最多${max}个${label}"),
+            range: text("${label}数量须在${min}-${max}之间"),
+        },
+        pattern: text("${label}与模式不匹配${pattern}"),
+    }
+}
+
+/// 中文（简体）语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        number_format: Some(NumberFormatConfig::zh_cn()),
+        date_format: Some(DateFormatConfig::zh_cn()),
+        rtl: Some(false),
+        ..LocalePack::new("zh-CN").with_texts(&get_default_texts())
+    }
+}
@@ -0,0 +1,111 @@
+//! 中文（繁体）的默认语言包
+
+use crate::components::locale::registry::LocalePack;
+use crate::components::locale::types::*;
+use chrono::Weekday;
+use serde_json::json;
+
+/// 中文（繁体）语言包
+pub fn pack() -> LocalePack {
+    LocalePack {
+        texts: json!({
+            "placeholder": "請選擇",
+            "selectPlaceholder": "請選擇",
+            "modal": { "okText": "確定", "cancelText": "取消", "justOkText": "確定" },
+            "popconfirm": { "ok": "確定", "cancel": "取消", "yes": "是", "no": "否" },
+            "form": {
+                "optional": "可選",
+                "required": "必填",
+                "validateMessages": {
+                    "default": "欄位驗證錯誤${label}",
+                    "required": "請輸入${label}",
+                    "enum": "${label}必須是其中一個[${enum}]",
+                    "whitespace": "${label}不能為空字元",
+                    "types": {
+                        "string": "${label}不是一個有效的字串",
+                        "number": "${label}不是一個有效的數字",
+                        "boolean": "${label}不是一個有效的布林值",
+                        "integer": "${label}不是一個有效的整數",
+                        "float": "${label}不是一個有效的浮點數",
+                        "array": "${label}不是一個有效的陣列",
+                        "object": "${label}不是一個有效的物件",
+                        "email": "${label}不是一個有效的電子郵件",
+                        "url": "${label}不是一個有效的網址",
+                    },
+                    "string": {
+                        "len": "${label}須為${len}個字元",
+                        "min": "${label}最少${min}個字元",
+                        "max": "${label}最多${max}個字元",
+                        "range": "${label}須在${min}-${max}字元之間",
+                    },
+                    "number": {
+                        "len": "${label}必須等於${len}",
+                        "min": "${label}最小值為${min}",
+                        "max": "${label}最大值為${max}",
+                        "range": "${label}須在${min}-${max}之間",
+                    },
+                    "array": {
+                        "len": "須為${len}個${label}",
+                        "min": "最少${min}個${label}",
+                        "max": "最多${max}個${label}",
+                        "range": "${label}數量須在${min}-${max}之間",
+                    },
+                    "pattern": "${label}與模式不匹配${pattern}",
+                },
+            },
+            "table": { "filterTitle": "篩選", "emptyText": "暫無資料" },
+            "upload": { "uploadText": "點擊上傳", "removeText": "移除" },
+            "empty": { "description": "暫無資料" },
+        }),
+        number_format: Some(NumberFormatConfig {
+            currency_symbol: "NT$".to_string(),
+            currency_code: "TWD".to_string(),
+            currency_position: CurrencyPosition::Before,
+            currency_spacing: false,
+            currency_fraction_digits: 2,
+            decimal_separator: ".".to_string(),
+            group_separator: ",".to_string(),
+            percent_suffix: "%".to_string(),
+            compact_units: vec![
+                (4, "萬".to_string()),
+                (8, "億".to_string()),
+                (12, "兆".to_string()),
+            ],
+            ..NumberFormatConfig::en_us()
+        }),
+        date_format: Some(DateFormatConfig {
+            months: names("一月_二月_三月_四月_五月_六月_七月_八月_九月_十月_十一月_十二月"),
+            months_short: names("1月_2月_3月_4月_5月_6月_7月_8月_9月_10月_11月_12月"),
+            weekdays: names("星期日_星期一_星期二_星期三_星期四_星期五_星期六"),
+            weekdays_short: names("週日_週一_週二_週三_週四_週五_週六"),
+            weekdays_min: names("日_一_二_三_四_五_六"),
+            meridiem: vec![
+                (0, "凌晨".to_string()),
+                (600, "早上".to_string()),
+                (900, "上午".to_string()),
+                (1100, "中午".to_string()),
+                (1300, "下午".to_string()),
+                (1800, "晚上".to_string()),
+            ],
+            week_start: Weekday::Sun,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "{}後".to_string(),
+                past: "{}前".to_string(),
+                seconds: "幾秒".to_string(),
+                minute: "1 分鐘".to_string(),
+                minutes: "{} 分鐘".to_string(),
+                hour: "1 小時".to_string(),
+                hours: "{} 小時".to_string(),
+                day: "1 天".to_string(),
+                days: "{} 天".to_string(),
+                month: "1 個月".to_string(),
+                months: "{} 個月".to_string(),
+                year: "1 年".to_string(),
+                years: "{} 年".to_string(),
+            },
+        }),
+        rtl: Some(false),
+        ..LocalePack::new("zh-TW").with_fallback("zh-CN")
+    }
+}
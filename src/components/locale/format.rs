@@ -1,8 +1,8 @@
-use super::types::{LocaleConfig, NumberFormatConfig};
-use chrono::{DateTime, Utc};
+use super::types::{CurrencyPosition, DateFormatConfig, LocaleConfig, NumberFormatConfig};
+use chrono::{DateTime, Datelike, NaiveDate, Offset, TimeZone, Timelike, Utc};
 
 /// Number format types
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum NumberFormatType {
     /// Decimal number
     Decimal,
@@ -12,10 +12,474 @@ pub enum NumberFormatType {
     Percentage,
 }
 
+/// Date pattern tokens, longest first so `MMMM` wins over `MM`
+const DATE_TOKENS: [&str; 25] = [
+    "YYYY", "MMMM", "dddd", "SSS", "MMM", "ddd", "YY", "MM", "DD", "dd", "HH", "hh", "mm", "ss",
+    "ZZ", "M", "D", "d", "H", "h", "m", "s", "A", "a", "Z",
+];
+
+fn meridiem(config: &DateFormatConfig, hour: u32, minute: u32) -> &str {
+    let time = hour * 100 + minute;
+    config
+        .meridiem
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= time)
+        .map(|(_, label)| label.as_str())
+        .unwrap_or_default()
+}
+
+fn format_offset(seconds: i32, separator: &str) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!(
+        "{}{:02}{}{:02}",
+        sign,
+        minutes / 60,
+        separator,
+        minutes % 60
+    )
+}
+
+/// Format a date in its own time zone with a dayjs-style pattern
+///
+/// Supports `YYYY YY M MM MMM MMMM D DD d dd ddd dddd H HH h hh m mm s ss SSS
+/// A a Z ZZ`; text in square brackets is copied as is. An empty pattern uses
+/// the locale's default format.
+pub fn format_datetime<Tz: TimeZone>(
+    date: &DateTime<Tz>,
+    pattern: &str,
+    config: &DateFormatConfig,
+) -> String {
+    let pattern = if pattern.is_empty() {
+        config.default_format.as_str()
+    } else {
+        pattern
+    };
+    let month = date.month0() as usize;
+    let weekday = date.weekday().num_days_from_sunday() as usize;
+    let hour12 = match date.hour() % 12 {
+        0 => 12,
+        hour => hour,
+    };
+    let offset = date.offset().fix().local_minus_utc();
+
+    let mut output = String::new();
+    let mut rest = pattern;
+    while let Some(ch) = rest.chars().next() {
+        if ch == '[' {
+            let end = rest.find(']').unwrap_or(rest.len());
+            output.push_str(&rest[1..end]);
+            rest = rest.get(end + 1..).unwrap_or_default();
+            continue;
+        }
+
+        let Some(token) = DATE_TOKENS.iter().find(|token| rest.starts_with(**token)) else {
+            output.push(ch);
+            rest = &rest[ch.len_utf8()..];
+            continue;
+        };
+        let value = match *token {
+            "YYYY" => format!("{:04}", date.year()),
+            "YY" => format!("{:02}", date.year().rem_euclid(100)),
+            "M" => (month + 1).to_string(),
+            "MM" => format!("{:02}", month + 1),
+            "MMM" => config.months_short[month].clone(),
+            "MMMM" => config.months[month].clone(),
+            "D" => date.day().to_string(),
+            "DD" => format!("{:02}", date.day()),
+            "d" => weekday.to_string(),
+            "dd" => config.weekdays_min[weekday].clone(),
+            "ddd" => config.weekdays_short[weekday].clone(),
+            "dddd" => config.weekdays[weekday].clone(),
+            "H" => date.hour().to_string(),
+            "HH" => format!("{:02}", date.hour()),
+            "h" => hour12.to_string(),
+            "hh" => format!("{:02}", hour12),
+            "m" => date.minute().to_string(),
+            "mm" => format!("{:02}", date.minute()),
+            "s" => date.second().to_string(),
+            "ss" => format!("{:02}", date.second()),
+            "SSS" => format!("{:03}", date.timestamp_subsec_millis().min(999)),
+            "A" => meridiem(config, date.hour(), date.minute()).to_string(),
+            "a" => meridiem(config, date.hour(), date.minute()).to_lowercase(),
+            "Z" => format_offset(offset, ":"),
+            _ => format_offset(offset, ""),
+        };
+        output.push_str(&value);
+        rest = &rest[token.len()..];
+    }
+    output
+}
+
 /// Format date according to locale
+///
+/// `format` is a dayjs-style pattern, see [`format_datetime`]. Convert the
+/// date with `with_timezone` first to show it in another time zone.
 pub fn format_date(date: &DateTime<Utc>, format: &str, config: &LocaleConfig) -> String {
-    // TODO: 实现日期格式化
-    date.format(format).to_string()
+    format_datetime(date, format, &config.date_format)
+}
+
+/// Human readable distance between `date` and `now`, e.g. `3 minutes ago`
+///
+/// Uses dayjs' thresholds: under 45 seconds is "a few seconds", under 90
+/// seconds "a minute", under 45 minutes counts minutes, and so on up to years.
+pub fn format_relative_time<Tz: TimeZone, Tz2: TimeZone>(
+    date: &DateTime<Tz>,
+    now: &DateTime<Tz2>,
+    config: &DateFormatConfig,
+) -> String {
+    let text = &config.relative_time;
+    let seconds = (date.timestamp_millis() - now.timestamp_millis()) as f64 / 1000.0;
+    let elapsed = seconds.abs();
+    let minutes = elapsed / 60.0;
+    let hours = minutes / 60.0;
+    let days = hours / 24.0;
+    let count = |value: f64| (value.round() as i64).max(1).to_string();
+
+    let span = if elapsed < 45.0 {
+        text.seconds.clone()
+    } else if elapsed < 90.0 {
+        text.minute.clone()
+    } else if minutes < 45.0 {
+        text.minutes.replace("{}", &count(minutes))
+    } else if minutes < 90.0 {
+        text.hour.clone()
+    } else if hours < 22.0 {
+        text.hours.replace("{}", &count(hours))
+    } else if hours < 36.0 {
+        text.day.clone()
+    } else if days < 26.0 {
+        text.days.replace("{}", &count(days))
+    } else if days < 46.0 {
+        text.month.clone()
+    } else if days < 320.0 {
+        text.months.replace("{}", &count(days / 30.4))
+    } else if days < 548.0 {
+        text.year.clone()
+    } else {
+        text.years.replace("{}", &count(days / 365.25))
+    };
+
+    let template = if seconds > 0.0 {
+        &text.future
+    } else {
+        &text.past
+    };
+    template.replace("{}", &span)
+}
+
+/// First day of the week containing `date`, honoring the locale's week start
+pub fn start_of_week(date: NaiveDate, config: &DateFormatConfig) -> NaiveDate {
+    date.week(config.week_start).first_day()
+}
+
+/// Minimal weekday names ordered from the locale's first day of the week
+pub fn week_day_names(config: &DateFormatConfig) -> Vec<String> {
+    let start = config.week_start.num_days_from_sunday() as usize;
+    (0..7)
+        .map(|index| config.weekdays_min[(start + index) % 7].clone())
+        .collect()
+}
+
+/// Notation used when formatting numbers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Plain digits, e.g. `1,234,567`
+    #[default]
+    Standard,
+    /// Abbreviated with the locale's units, e.g. `1.2M` or `123.5万`
+    Compact,
+}
+
+/// How the currency is shown
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurrencyDisplay {
+    /// Locale currency symbol, e.g. `$`
+    #[default]
+    Symbol,
+    /// ISO 4217 code, e.g. `USD`
+    Code,
+}
+
+/// Rounding modes, named after `Intl.NumberFormat`'s `roundingMode`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Towards positive infinity
+    Ceil,
+    /// Towards negative infinity
+    Floor,
+    /// Away from zero
+    Expand,
+    /// Towards zero
+    Trunc,
+    /// Nearest, ties towards positive infinity
+    HalfCeil,
+    /// Nearest, ties towards negative infinity
+    HalfFloor,
+    /// Nearest, ties away from zero
+    #[default]
+    HalfExpand,
+    /// Nearest, ties towards zero
+    HalfTrunc,
+    /// Nearest, ties to the even digit
+    HalfEven,
+}
+
+/// Options of [`format_number_with`]
+///
+/// Unset fraction digits use the defaults of the format type: 0–3 for
+/// decimals, the currency's digits (usually 2), 0 for percentages and 0–1 in
+/// compact notation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormatOptions {
+    /// Decimal, currency or percentage
+    pub format_type: NumberFormatType,
+    /// Minimum number of fraction digits, padded with zeros
+    pub minimum_fraction_digits: Option<usize>,
+    /// Maximum number of fraction digits, rounded with `rounding_mode`
+    pub maximum_fraction_digits: Option<usize>,
+    /// Whether to insert group separators
+    pub use_grouping: bool,
+    /// Standard or compact notation
+    pub notation: Notation,
+    /// Rounding mode
+    pub rounding_mode: RoundingMode,
+    /// Symbol or code for currencies
+    pub currency_display: CurrencyDisplay,
+}
+
+impl Default for NumberFormatOptions {
+    fn default() -> Self {
+        Self {
+            format_type: NumberFormatType::Decimal,
+            minimum_fraction_digits: None,
+            maximum_fraction_digits: None,
+            use_grouping: true,
+            notation: Notation::Standard,
+            rounding_mode: RoundingMode::default(),
+            currency_display: CurrencyDisplay::default(),
+        }
+    }
+}
+
+impl NumberFormatOptions {
+    /// Options for a format type with its default settings
+    pub fn new(format_type: NumberFormatType) -> Self {
+        Self {
+            format_type,
+            ..Self::default()
+        }
+    }
+
+    fn fraction_digits(&self, config: &NumberFormatConfig) -> (usize, usize) {
+        let currency = config.currency_fraction_digits;
+        let (min, max) = match (self.notation, &self.format_type) {
+            (Notation::Compact, _) => (0, 1),
+            (_, NumberFormatType::Decimal) => (0, 3),
+            (_, NumberFormatType::Currency) => (currency, currency),
+            (_, NumberFormatType::Percentage) => (0, 0),
+        };
+        match (self.minimum_fraction_digits, self.maximum_fraction_digits) {
+            (Some(min), Some(max)) => (min, max.max(min)),
+            (Some(min), None) => (min, max.max(min)),
+            (None, Some(max)) => (min.min(max), max),
+            (None, None) => (min, max),
+        }
+    }
+}
+
+/// Exact decimal digits of a number: `0.d1d2d3… × 10^point`
+///
+/// Built from the shortest round-trip representation of the `f64`, so
+/// scaling by powers of ten and rounding never introduce binary noise.
+#[derive(Clone, Debug, PartialEq)]
+struct Decimal {
+    negative: bool,
+    digits: Vec<u8>,
+    point: i32,
+}
+
+impl Decimal {
+    fn from_f64(number: f64) -> Self {
+        let repr = format!("{:e}", number.abs());
+        let (mantissa, exponent) = repr.split_once('e').unwrap_or((&repr, "0"));
+        let exponent: i32 = exponent.parse().unwrap_or(0);
+        let digits: Vec<u8> = mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|digit| digit - b'0')
+            .collect();
+        Self {
+            negative: number.is_sign_negative(),
+            digits,
+            point: exponent + 1,
+        }
+        .normalized()
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        let leading = self.digits.iter().take_while(|digit| **digit == 0).count();
+        self.digits.drain(..leading);
+        self.point -= leading as i32;
+        if self.digits.is_empty() {
+            self.negative = false;
+            self.point = 0;
+        }
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Multiply by `10^exponent`
+    fn shift(mut self, exponent: i32) -> Self {
+        if !self.is_zero() {
+            self.point += exponent;
+        }
+        self
+    }
+
+    /// Round to `fraction_digits` digits after the point
+    fn round(mut self, fraction_digits: usize, mode: RoundingMode) -> Self {
+        let keep = self.point + fraction_digits as i32;
+        if self.is_zero() || keep >= self.digits.len() as i32 {
+            return self;
+        }
+
+        let kept = keep.max(0) as usize;
+        let dropped = &self.digits[kept..];
+        // 被舍弃部分与 0.5 个单位的比较；keep 为负时首位舍弃数字是补出的 0
+        let half = if keep < 0 {
+            std::cmp::Ordering::Less
+        } else {
+            match dropped[0].cmp(&5) {
+                std::cmp::Ordering::Equal if dropped.len() > 1 => std::cmp::Ordering::Greater,
+                ordering => ordering,
+            }
+        };
+        let last_odd = kept > 0 && self.digits[kept - 1] % 2 == 1;
+        let positive = !self.negative;
+        let away = match mode {
+            RoundingMode::Ceil => positive,
+            RoundingMode::Floor => !positive,
+            RoundingMode::Expand => true,
+            RoundingMode::Trunc => false,
+            _ => match half {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => match mode {
+                    RoundingMode::HalfCeil => positive,
+                    RoundingMode::HalfFloor => !positive,
+                    RoundingMode::HalfTrunc => false,
+                    RoundingMode::HalfEven => last_odd,
+                    _ => true,
+                },
+            },
+        };
+
+        self.digits.truncate(kept);
+        if away {
+            if kept == 0 {
+                self.digits = vec![1];
+                self.point = 1 - fraction_digits as i32;
+            } else {
+                let mut index = kept;
+                loop {
+                    if index == 0 {
+                        self.digits.insert(0, 1);
+                        self.point += 1;
+                        break;
+                    }
+                    index -= 1;
+                    if self.digits[index] == 9 {
+                        self.digits[index] = 0;
+                    } else {
+                        self.digits[index] += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        self.normalized()
+    }
+
+    /// Number of digits before the point
+    fn integer_digits(&self) -> i32 {
+        self.point.max(0)
+    }
+
+    fn integer_part(&self) -> String {
+        if self.point <= 0 {
+            return "0".to_string();
+        }
+        (0..self.point as usize)
+            .map(|index| char::from(b'0' + self.digits.get(index).copied().unwrap_or(0)))
+            .collect()
+    }
+
+    fn fraction_part(&self, min_digits: usize) -> String {
+        let mut fraction: String = (self.point..self.digits.len() as i32)
+            .map(|index| {
+                let digit = if index < 0 {
+                    0
+                } else {
+                    self.digits[index as usize]
+                };
+                char::from(b'0' + digit)
+            })
+            .collect();
+        while fraction.len() < min_digits {
+            fraction.push('0');
+        }
+        fraction
+    }
+}
+
+fn group_digits(integer: &str, separator: &str, size: usize) -> String {
+    if size == 0 || integer.len() <= size {
+        return integer.to_string();
+    }
+    let head = integer.len() % size;
+    let mut groups = Vec::new();
+    if head > 0 {
+        groups.push(&integer[..head]);
+    }
+    groups.extend(
+        integer.as_bytes()[head..]
+            .chunks(size)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default()),
+    );
+    groups.join(separator)
+}
+
+/// Pick the largest compact unit not exceeding the number, moving to the
+/// next unit when rounding carries over (999.95K → 1M)
+fn compact(
+    decimal: Decimal,
+    config: &NumberFormatConfig,
+    max_fraction: usize,
+    mode: RoundingMode,
+) -> (Decimal, Option<String>) {
+    let units = &config.compact_units;
+    let mut index = units
+        .iter()
+        .rposition(|(exponent, _)| decimal.integer_digits() > *exponent);
+    loop {
+        let exponent = index.map(|index| units[index].0).unwrap_or(0);
+        let rounded = decimal.clone().shift(-exponent).round(max_fraction, mode);
+        let next = index.map(|index| index + 1).unwrap_or(0);
+        match units.get(next) {
+            Some((next_exponent, _)) if rounded.integer_digits() > next_exponent - exponent => {
+                index = Some(next);
+            }
+            _ => return (rounded, index.map(|index| units[index].1.clone())),
+        }
+    }
 }
 
 /// Format number according to locale
@@ -24,14 +488,412 @@ pub fn format_number(
     config: &NumberFormatConfig,
     format_type: Option<NumberFormatType>,
 ) -> String {
-    // TODO: 实现数字格式化
-    match format_type {
-        Some(NumberFormatType::Currency) => {
-            format!("{}{}", config.currency_symbol, number)
+    format_number_with(
+        number,
+        config,
+        &NumberFormatOptions::new(format_type.unwrap_or(NumberFormatType::Decimal)),
+    )
+}
+
+/// Format number according to locale with explicit options
+pub fn format_number_with(
+    number: f64,
+    config: &NumberFormatConfig,
+    options: &NumberFormatOptions,
+) -> String {
+    let minus = if number.is_sign_negative() && number != 0.0 {
+        config.minus_sign.as_str()
+    } else {
+        ""
+    };
+    let body = if number.is_nan() {
+        return "NaN".to_string();
+    } else if number.is_infinite() {
+        "∞".to_string()
+    } else {
+        let (min_fraction, max_fraction) = options.fraction_digits(config);
+        let mut decimal = Decimal::from_f64(number);
+        if options.format_type == NumberFormatType::Percentage {
+            decimal = decimal.shift(2);
+        }
+
+        let (decimal, unit) = match options.notation {
+            Notation::Compact => compact(decimal, config, max_fraction, options.rounding_mode),
+            Notation::Standard => (decimal.round(max_fraction, options.rounding_mode), None),
+        };
+
+        let integer = decimal.integer_part();
+        let mut body = if options.use_grouping {
+            group_digits(&integer, &config.group_separator, config.group_size)
+        } else {
+            integer
+        };
+        let fraction = decimal.fraction_part(min_fraction);
+        if !fraction.is_empty() {
+            body.push_str(&config.decimal_separator);
+            body.push_str(&fraction);
+        }
+        if let Some(unit) = unit {
+            body.push_str(&unit);
+        }
+        // 舍入为 0 时不保留负号
+        if decimal.is_zero() {
+            return format_affixes(body, "", config, options);
+        }
+        body
+    };
+
+    format_affixes(body, minus, config, options)
+}
+
+fn format_affixes(
+    body: String,
+    minus: &str,
+    config: &NumberFormatConfig,
+    options: &NumberFormatOptions,
+) -> String {
+    match options.format_type {
+        NumberFormatType::Decimal => format!("{}{}", minus, body),
+        NumberFormatType::Percentage => format!("{}{}{}", minus, body, config.percent_suffix),
+        NumberFormatType::Currency => {
+            let (symbol, spacing) = match options.currency_display {
+                CurrencyDisplay::Symbol => (&config.currency_symbol, config.currency_spacing),
+                CurrencyDisplay::Code => (&config.currency_code, true),
+            };
+            let space = if spacing { "\u{a0}" } else { "" };
+            match config.currency_position {
+                CurrencyPosition::Before => format!("{}{}{}{}", minus, symbol, space, body),
+                CurrencyPosition::After => format!("{}{}{}{}", minus, body, space, symbol),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, FixedOffset};
+
+    /// 2024-03-05 (Tuesday) 14:07:09.045 at UTC-05:30
+    fn date() -> DateTime<FixedOffset> {
+        FixedOffset::west_opt(5 * 3600 + 30 * 60)
+            .unwrap()
+            .with_ymd_and_hms(2024, 3, 5, 14, 7, 9)
+            .unwrap()
+            + Duration::milliseconds(45)
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 5, hour, minute, 0).unwrap()
+    }
+
+    fn options(format_type: NumberFormatType) -> NumberFormatOptions {
+        NumberFormatOptions::new(format_type)
+    }
+
+    fn euro() -> NumberFormatConfig {
+        NumberFormatConfig {
+            currency_symbol: "€".to_string(),
+            currency_code: "EUR".to_string(),
+            currency_position: CurrencyPosition::After,
+            currency_spacing: true,
+            decimal_separator: ",".to_string(),
+            group_separator: ".".to_string(),
+            percent_suffix: "\u{a0}%".to_string(),
+            ..NumberFormatConfig::en_us()
         }
-        Some(NumberFormatType::Percentage) => {
-            format!("{}%", number * 100.0)
+    }
+
+    #[test]
+    fn rounding_modes_at_ties() {
+        use RoundingMode::*;
+
+        let numbers = [2.5, -2.5, 3.5, -3.5, 2.4, -2.6];
+        let table = [
+            (Ceil, ["3", "-2", "4", "-3", "3", "-2"]),
+            (Floor, ["2", "-3", "3", "-4", "2", "-3"]),
+            (Expand, ["3", "-3", "4", "-4", "3", "-3"]),
+            (Trunc, ["2", "-2", "3", "-3", "2", "-2"]),
+            (HalfCeil, ["3", "-2", "4", "-3", "2", "-3"]),
+            (HalfFloor, ["2", "-3", "3", "-4", "2", "-3"]),
+            (HalfExpand, ["3", "-3", "4", "-4", "2", "-3"]),
+            (HalfTrunc, ["2", "-2", "3", "-3", "2", "-3"]),
+            (HalfEven, ["2", "-2", "4", "-4", "2", "-3"]),
+        ];
+        let config = NumberFormatConfig::en_us();
+        for (mode, expected) in table {
+            let options = NumberFormatOptions {
+                maximum_fraction_digits: Some(0),
+                rounding_mode: mode,
+                ..options(NumberFormatType::Decimal)
+            };
+            for (number, expected) in numbers.into_iter().zip(expected) {
+                assert_eq!(
+                    format_number_with(number, &config, &options),
+                    expected,
+                    "{} with {:?}",
+                    number,
+                    mode
+                );
+            }
         }
-        _ => number.to_string(),
+    }
+
+    #[test]
+    fn rounds_decimal_digits_without_binary_noise() {
+        let config = NumberFormatConfig::en_us();
+        let two_digits = |mode| NumberFormatOptions {
+            minimum_fraction_digits: Some(2),
+            maximum_fraction_digits: Some(2),
+            rounding_mode: mode,
+            ..options(NumberFormatType::Decimal)
+        };
+        let table = [
+            (1.005, RoundingMode::HalfExpand, "1.01"),
+            (1.005, RoundingMode::HalfEven, "1.00"),
+            (1.015, RoundingMode::HalfEven, "1.02"),
+            (0.125, RoundingMode::HalfTrunc, "0.12"),
+            (9.995, RoundingMode::HalfExpand, "10.00"),
+            (0.001, RoundingMode::Expand, "0.01"),
+            (0.0001, RoundingMode::Ceil, "0.01"),
+        ];
+        for (number, mode, expected) in table {
+            assert_eq!(
+                format_number_with(number, &config, &two_digits(mode)),
+                expected,
+                "{} with {:?}",
+                number,
+                mode
+            );
+        }
+        assert_eq!(format_number(1234567.891, &config, None), "1,234,567.891");
+        assert_eq!(format_number(0.1 + 0.2, &config, None), "0.3");
+    }
+
+    #[test]
+    fn negative_numbers_rounding_to_zero_have_no_sign() {
+        let config = NumberFormatConfig::en_us();
+        let whole = |mode| NumberFormatOptions {
+            maximum_fraction_digits: Some(0),
+            rounding_mode: mode,
+            ..options(NumberFormatType::Decimal)
+        };
+        assert_eq!(format_number(-0.0, &config, None), "0");
+        assert_eq!(format_number(-0.0001, &config, None), "0");
+        assert_eq!(
+            format_number_with(-0.4, &config, &whole(RoundingMode::HalfExpand)),
+            "0"
+        );
+        assert_eq!(
+            format_number_with(-0.4, &config, &whole(RoundingMode::Floor)),
+            "-1"
+        );
+        assert_eq!(
+            format_number(-0.001, &config, Some(NumberFormatType::Currency)),
+            "$0.00"
+        );
+        assert_eq!(
+            format_number(-0.00001, &config, Some(NumberFormatType::Percentage)),
+            "0%"
+        );
+    }
+
+    #[test]
+    fn formats_percentages() {
+        let config = NumberFormatConfig::en_us();
+        let percent = Some(NumberFormatType::Percentage);
+        assert_eq!(format_number(0.256, &config, percent.clone()), "26%");
+        assert_eq!(format_number(1.5, &config, percent.clone()), "150%");
+        assert_eq!(format_number(-0.125, &config, percent.clone()), "-13%");
+        assert_eq!(format_number(123.45, &config, percent.clone()), "12,345%");
+
+        let one_digit = NumberFormatOptions {
+            maximum_fraction_digits: Some(1),
+            ..options(NumberFormatType::Percentage)
+        };
+        assert_eq!(format_number_with(0.2555, &config, &one_digit), "25.6%");
+        assert_eq!(
+            format_number_with(0.2555, &euro(), &one_digit),
+            "25,6\u{a0}%"
+        );
+    }
+
+    #[test]
+    fn formats_currencies_before_and_after_the_number() {
+        let currency = Some(NumberFormatType::Currency);
+        let code = NumberFormatOptions {
+            currency_display: CurrencyDisplay::Code,
+            ..options(NumberFormatType::Currency)
+        };
+
+        let en = NumberFormatConfig::en_us();
+        assert_eq!(format_number(1234.5, &en, currency.clone()), "$1,234.50");
+        assert_eq!(format_number(-1234.5, &en, currency.clone()), "-$1,234.50");
+        assert_eq!(format_number_with(1234.5, &en, &code), "USD\u{a0}1,234.50");
+
+        let zh = NumberFormatConfig::zh_cn();
+        assert_eq!(format_number(1234.5, &zh, currency.clone()), "¥1,234.50");
+
+        let euro = euro();
+        assert_eq!(
+            format_number(1234.5, &euro, currency.clone()),
+            "1.234,50\u{a0}€"
+        );
+        assert_eq!(
+            format_number(-1234.5, &euro, currency.clone()),
+            "-1.234,50\u{a0}€"
+        );
+        assert_eq!(
+            format_number_with(1234.5, &euro, &code),
+            "1.234,50\u{a0}EUR"
+        );
+    }
+
+    #[test]
+    fn compact_notation_uses_locale_units() {
+        let compact = NumberFormatOptions {
+            notation: Notation::Compact,
+            ..options(NumberFormatType::Decimal)
+        };
+        let en = NumberFormatConfig::en_us();
+        let zh = NumberFormatConfig::zh_cn();
+        let table = [
+            (&en, 999.0, "999"),
+            (&en, 1234.0, "1.2K"),
+            (&en, 999_949.0, "999.9K"),
+            (&en, 999_950.0, "1M"),
+            (&en, 1_500_000.0, "1.5M"),
+            (&en, 2e12, "2T"),
+            (&en, -1234.0, "-1.2K"),
+            (&zh, 1234.0, "1,234"),
+            (&zh, 12_345.0, "1.2万"),
+            (&zh, 99_999_999.0, "1亿"),
+            (&zh, 123_456_789.0, "1.2亿"),
+            (&zh, 3e12, "3万亿"),
+        ];
+        for (config, number, expected) in table {
+            assert_eq!(
+                format_number_with(number, config, &compact),
+                expected,
+                "{}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn date_tokens_match_longest_first() {
+        let en = DateFormatConfig::en_us();
+        let table = [
+            ("YYYY-MM-DD", "2024-03-05"),
+            ("YY", "24"),
+            ("MMMM MMM MM M", "March Mar 03 3"),
+            ("MMMMM", "March3"),
+            ("dddd ddd dd d", "Tuesday Tue Tu 2"),
+            ("D DD", "5 05"),
+            ("HH:mm:ss.SSS", "14:07:09.045"),
+            ("H h hh m s", "14 2 02 7 9"),
+            ("YYYY年M月D日", "2024年3月5日"),
+            ("", "2024-03-05"),
+        ];
+        for (pattern, expected) in table {
+            assert_eq!(
+                format_datetime(&date(), pattern, &en),
+                expected,
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn bracketed_text_is_copied_as_is() {
+        let en = DateFormatConfig::en_us();
+        assert_eq!(format_datetime(&date(), "[YYYY] YYYY", &en), "YYYY 2024");
+        assert_eq!(format_datetime(&date(), "[at] HH[h]", &en), "at 14h");
+        assert_eq!(format_datetime(&date(), "[]D", &en), "5");
+        // 未闭合的 `[` 把余下的内容都当作文本
+        assert_eq!(format_datetime(&date(), "YYYY [MM DD", &en), "2024 MM DD");
+        assert_eq!(format_datetime(&date(), "DD[", &en), "05");
+    }
+
+    #[test]
+    fn meridiem_follows_the_locale() {
+        let en = DateFormatConfig::en_us();
+        assert_eq!(format_datetime(&date(), "h A a", &en), "2 PM pm");
+        assert_eq!(format_datetime(&at(0, 5), "h:mm A", &en), "12:05 AM");
+        assert_eq!(format_datetime(&at(12, 0), "h A", &en), "12 PM");
+
+        let zh = DateFormatConfig::zh_cn();
+        let table = [
+            (5, 59, "凌晨"),
+            (6, 0, "早上"),
+            (10, 0, "上午"),
+            (12, 30, "中午"),
+            (13, 0, "下午"),
+            (23, 0, "晚上"),
+        ];
+        for (hour, minute, expected) in table {
+            assert_eq!(format_datetime(&at(hour, minute), "A", &zh), expected);
+            assert_eq!(format_datetime(&at(hour, minute), "a", &zh), expected);
+        }
+    }
+
+    #[test]
+    fn offsets_keep_their_sign() {
+        let en = DateFormatConfig::en_us();
+        assert_eq!(format_datetime(&date(), "Z ZZ", &en), "-05:30 -0530");
+        assert_eq!(format_datetime(&at(0, 0), "Z ZZ", &en), "+00:00 +0000");
+
+        let tokyo = at(0, 0).with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(format_datetime(&tokyo, "HH Z", &en), "09 +09:00");
+        let baker = at(0, 0).with_timezone(&FixedOffset::west_opt(12 * 3600).unwrap());
+        assert_eq!(format_datetime(&baker, "D HH ZZ", &en), "4 12 -1200");
+    }
+
+    #[test]
+    fn relative_time_uses_dayjs_thresholds() {
+        let en = DateFormatConfig::en_us();
+        let now = at(12, 0);
+        let ago =
+            |seconds: i64| format_relative_time(&(now - Duration::seconds(seconds)), &now, &en);
+        let minute = 60;
+        let hour = 60 * minute;
+        let day = 24 * hour;
+        let table = [
+            (0, "a few seconds ago"),
+            (44, "a few seconds ago"),
+            (45, "a minute ago"),
+            (89, "a minute ago"),
+            (90, "2 minutes ago"),
+            (44 * minute, "44 minutes ago"),
+            (45 * minute, "an hour ago"),
+            (89 * minute, "an hour ago"),
+            (90 * minute, "2 hours ago"),
+            (21 * hour, "21 hours ago"),
+            (22 * hour, "a day ago"),
+            (35 * hour, "a day ago"),
+            (36 * hour, "2 days ago"),
+            (25 * day, "25 days ago"),
+            (26 * day, "a month ago"),
+            (45 * day, "a month ago"),
+            (46 * day, "2 months ago"),
+            (319 * day, "10 months ago"),
+            (320 * day, "a year ago"),
+            (547 * day, "a year ago"),
+            (548 * day, "2 years ago"),
+            (3650 * day, "10 years ago"),
+        ];
+        for (seconds, expected) in table {
+            assert_eq!(ago(seconds), expected, "{} seconds", seconds);
+        }
+
+        let later = now + Duration::minutes(3);
+        assert_eq!(format_relative_time(&later, &now, &en), "in 3 minutes");
+        let zh = DateFormatConfig::zh_cn();
+        assert_eq!(
+            format_relative_time(&(now - Duration::days(3)), &now, &zh),
+            "3 天前"
+        );
     }
 }
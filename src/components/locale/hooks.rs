@@ -1,11 +1,11 @@
 //! 本地化相关的 hooks
 
-use crate::components::locale::format_date;
+use crate::components::locale::format_datetime;
 use crate::components::locale::format_number;
 use crate::components::locale::NumberFormatType;
 use crate::components::locale::{
-    defaults::{get_en_us_texts, get_zh_cn_texts},
-    types::{Language, LocaleContext, LocaleText},
+    registry::resolve_locale,
+    types::{Language, LocaleConfig, LocaleContext, LocaleText},
 };
 use leptos::prelude::*;
 
@@ -14,45 +14,60 @@ pub fn use_locale_config() -> Option<LocaleContext> {
     use_context::<LocaleContext>()
 }
 
-/// 获取当前语言
-pub fn use_language() -> Option<ReadSignal<Language>> {
-    use_locale_config().map(|ctx| {
-        let (read, _) = create_signal(ctx.get().language);
-        read
-    })
+/// 获取当前语言，随语言切换更新
+pub fn use_language() -> Option<Signal<Language>> {
+    use_locale_config()
+        .map(|ctx| Memo::new(move |_| ctx.with(|config| config.language.clone())).into())
 }
 
-/// 获取当前本地化文本
-pub fn use_locale_text() -> Option<ReadSignal<LocaleText>> {
+/// 获取当前本地化文本，随语言切换更新
+pub fn use_locale_text() -> Option<Signal<LocaleText>> {
     use_locale_config().map(|ctx| {
-        let config = ctx.get();
-        let (read, _) = create_signal(config.texts.unwrap_or_else(|| {
-            // 根据当前语言获取默认文本
-            match config.language {
-                Language::EnUS => get_en_us_texts(),
-                Language::ZhCN => get_zh_cn_texts(),
-            }
-        }));
-        read
+        Memo::new(move |_| {
+            ctx.with(|config| {
+                config.texts.clone().unwrap_or_else(|| {
+                    // 根据当前语言从注册表解析文本，缺少的键按回退链补齐
+                    resolve_locale(config.language.tag()).texts
+                })
+            })
+        })
+        .into()
     })
 }
 
 /// Hook to format dates
-pub fn use_date_formatter() -> impl Fn(chrono::DateTime<chrono::Utc>, Option<&str>) -> String {
-    let locale_config = use_locale_config().expect("LocaleProvider not found");
+///
+/// The returned formatter reads the current locale on every call, so calling
+/// it inside a view closure re-renders on language switches. Outside of a
+/// `LocaleProvider` the default locale is used.
+pub fn use_date_formatter(
+) -> impl Fn(chrono::DateTime<chrono::Utc>, Option<&str>) -> String + Clone + Send + Sync + 'static
+{
+    let locale_config = use_locale_config();
+    let date_format = Memo::new(move |_| {
+        locale_config
+            .map(|ctx| ctx.with(|config| config.date_format.clone()))
+            .unwrap_or_else(|| LocaleConfig::default().date_format)
+    });
 
     move |date, format| {
-        let config = locale_config.get();
-        format_date(&date, format.unwrap_or_default(), &config)
+        date_format.with(|config| format_datetime(&date, format.unwrap_or_default(), config))
     }
 }
 
 /// Hook to format numbers
-pub fn use_number_formatter() -> impl Fn(f64, Option<NumberFormatType>) -> String {
-    let locale_config = use_locale_config().expect("LocaleProvider not found");
+///
+/// Like [`use_date_formatter`], the formatter tracks the current locale.
+pub fn use_number_formatter(
+) -> impl Fn(f64, Option<NumberFormatType>) -> String + Clone + Send + Sync + 'static {
+    let locale_config = use_locale_config();
+    let number_format = Memo::new(move |_| {
+        locale_config
+            .map(|ctx| ctx.with(|config| config.number_format.clone()))
+            .unwrap_or_else(|| LocaleConfig::default().number_format)
+    });
 
     move |number, format_type| {
-        let config = locale_config.get();
-        format_number(number, &config.number_format, format_type)
+        number_format.with(|config| format_number(number, config, format_type))
     }
 }
@@ -6,10 +6,12 @@ mod context;
 mod defaults;
 mod format;
 mod hooks;
+pub mod registry;
 pub mod types;
 
 pub use context::*;
 pub use defaults::*;
 pub use format::*;
 pub use hooks::*;
+pub use registry::{register_locale, resolve_locale, LocalePack, ResolvedLocale};
 pub use types::Language;
@@ -0,0 +1,252 @@
+//! 语言包注册表
+//!
+//! 语言包按 BCP-47 标签注册，可以在运行时调用 [`register_locale`] 添加，
+//! 也可以通过 `locale-*` 特性在编译期内置。解析语言时沿回退链逐级查找：
+//! 语言包声明的 `fallback`，其次是同一语言的其他语言包，最后是 `en-US`。
+//! 文本按键合并，语言包缺少的键取自回退链中的下一个语言包。
+
+use super::defaults;
+use super::types::{canonicalize_tag, DateFormatConfig, LocaleText, NumberFormatConfig};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, RwLock};
+
+/// Tag every fallback chain ends with
+pub const BASE_LOCALE: &str = "en-US";
+
+/// A locale pack, possibly incomplete
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalePack {
+    /// BCP-47 tag, e.g. `zh-TW`
+    pub tag: String,
+    /// Tag of the pack to fall back to, e.g. `zh-CN` for `zh-TW`
+    pub fallback: Option<String>,
+    /// Texts in the camelCase JSON shape of [`LocaleText`]; missing keys fall back
+    pub texts: Value,
+    /// Number format, falls back when `None`
+    pub number_format: Option<NumberFormatConfig>,
+    /// Date format, falls back when `None`
+    pub date_format: Option<DateFormatConfig>,
+    /// Whether the language is written right to left, falls back when `None`
+    pub rtl: Option<bool>,
+}
+
+impl LocalePack {
+    /// Create an empty pack for a tag
+    pub fn new(tag: &str) -> Self {
+        Self {
+            tag: canonicalize_tag(tag),
+            texts: Value::Object(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the pack to fall back to
+    pub fn with_fallback(mut self, tag: &str) -> Self {
+        self.fallback = Some(canonicalize_tag(tag));
+        self
+    }
+
+    /// Set the texts from a complete [`LocaleText`]
+    pub fn with_texts(mut self, texts: &LocaleText) -> Self {
+        self.texts = serde_json::to_value(texts).unwrap_or_default();
+        self
+    }
+}
+
+/// A locale with every fallback applied
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedLocale {
+    /// Requested tag, canonicalized
+    pub tag: String,
+    /// Tags consulted, from the requested tag to [`BASE_LOCALE`]
+    pub chain: Vec<String>,
+    /// Texts merged key by key along the chain
+    pub texts: LocaleText,
+    /// Number format of the first pack providing one
+    pub number_format: NumberFormatConfig,
+    /// Date format of the first pack providing one
+    pub date_format: DateFormatConfig,
+    /// Whether the language is written right to left
+    pub rtl: bool,
+}
+
+static REGISTRY: LazyLock<RwLock<BTreeMap<String, LocalePack>>> = LazyLock::new(|| {
+    RwLock::new(
+        defaults::builtin_packs()
+            .into_iter()
+            .map(|pack| (pack.tag.clone(), pack))
+            .collect(),
+    )
+});
+
+/// Register a locale pack, replacing any pack with the same tag
+///
+/// Fails without registering when a text has a different type than in
+/// [`LocaleText`], e.g. a number where a string is expected.
+pub fn register_locale(mut pack: LocalePack) -> Result<(), serde_json::Error> {
+    check_texts(&pack.texts)?;
+    pack.tag = canonicalize_tag(&pack.tag);
+    if let Ok(mut registry) = REGISTRY.write() {
+        registry.insert(pack.tag.clone(), pack);
+    }
+    Ok(())
+}
+
+/// Check that texts merged over the `en-US` defaults still form a [`LocaleText`]
+fn check_texts(texts: &Value) -> Result<(), serde_json::Error> {
+    let mut merged = serde_json::to_value(defaults::get_en_us_texts())?;
+    merge_texts(&mut merged, texts);
+    serde_json::from_value::<LocaleText>(merged).map(|_| ())
+}
+
+/// Tags of all registered packs
+pub fn registered_locales() -> Vec<String> {
+    REGISTRY
+        .read()
+        .map(|registry| registry.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Get a registered pack by tag
+pub fn get_locale_pack(tag: &str) -> Option<LocalePack> {
+    let tag = canonicalize_tag(tag);
+    REGISTRY.read().ok()?.get(&tag).cloned()
+}
+
+/// Find the registered pack closest to a tag
+///
+/// Tries the tag itself, then drops subtags from the end (`zh-Hant-TW` →
+/// `zh-Hant` → `zh`), then any pack of the same language.
+fn closest_pack(registry: &BTreeMap<String, LocalePack>, tag: &str) -> Option<String> {
+    let mut parts: Vec<&str> = tag.split('-').collect();
+    while !parts.is_empty() {
+        let candidate = parts.join("-");
+        if registry.contains_key(&candidate) {
+            return Some(candidate);
+        }
+        parts.pop();
+    }
+
+    let language = tag.split('-').next().unwrap_or_default();
+    registry
+        .keys()
+        .find(|key| key.split('-').next() == Some(language))
+        .cloned()
+}
+
+/// Tags consulted when resolving a locale, ending with [`BASE_LOCALE`]
+pub fn fallback_chain(tag: &str) -> Vec<String> {
+    let Ok(registry) = REGISTRY.read() else {
+        return vec![BASE_LOCALE.to_string()];
+    };
+
+    let mut chain: Vec<String> = Vec::new();
+    let mut next = closest_pack(&registry, &canonicalize_tag(tag));
+    while let Some(tag) = next.take() {
+        // 防止语言包之间循环回退
+        if chain.contains(&tag) {
+            break;
+        }
+        next = registry
+            .get(&tag)
+            .and_then(|pack| pack.fallback.as_deref())
+            .and_then(|fallback| closest_pack(&registry, fallback));
+        chain.push(tag);
+    }
+    if !chain.iter().any(|tag| tag == BASE_LOCALE) {
+        chain.push(BASE_LOCALE.to_string());
+    }
+    chain
+}
+
+fn merge_texts(base: &mut Value, overlay: &Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(key) {
+                    Some(existing) => merge_texts(existing, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (base, overlay) if !overlay.is_null() => *base = overlay.clone(),
+        _ => {}
+    }
+}
+
+/// Resolve a locale by tag, applying the fallback chain
+pub fn resolve_locale(tag: &str) -> ResolvedLocale {
+    let chain = fallback_chain(tag);
+    let packs: Vec<LocalePack> = {
+        let registry = REGISTRY.read().ok();
+        chain
+            .iter()
+            .filter_map(|tag| registry.as_ref()?.get(tag).cloned())
+            .collect()
+    };
+
+    // 从链尾开始逐级覆盖，保证每个键都取最具体的语言包
+    let mut texts = serde_json::to_value(defaults::get_en_us_texts()).unwrap_or_default();
+    for pack in packs.iter().rev() {
+        merge_texts(&mut texts, &pack.texts);
+    }
+    // 注册时已逐个校验语言包，合并结果不会出现类型错误
+    let mut texts: LocaleText =
+        serde_json::from_value(texts).unwrap_or_else(|_| defaults::get_en_us_texts());
+    texts.locale = canonicalize_tag(tag);
+
+    ResolvedLocale {
+        tag: canonicalize_tag(tag),
+        texts,
+        number_format: packs
+            .iter()
+            .find_map(|pack| pack.number_format.clone())
+            .unwrap_or_default(),
+        date_format: packs
+            .iter()
+            .find_map(|pack| pack.date_format.clone())
+            .unwrap_or_default(),
+        rtl: packs.iter().find_map(|pack| pack.rtl).unwrap_or(false),
+        chain,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builtin_packs_have_valid_texts() {
+        for pack in defaults::builtin_packs() {
+            assert!(check_texts(&pack.texts).is_ok(), "{}", pack.tag);
+        }
+    }
+
+    #[test]
+    fn rejects_texts_of_the_wrong_type() {
+        let mut pack = LocalePack::new("x-wrong-type");
+        pack.texts = json!({ "modal": { "okText": 10 } });
+        assert!(register_locale(pack).is_err());
+        assert!(get_locale_pack("x-wrong-type").is_none());
+    }
+
+    #[test]
+    fn merges_partial_texts_over_the_fallback() {
+        let mut pack = LocalePack::new("x-partial").with_fallback("zh-CN");
+        pack.texts = json!({ "modal": { "okText": "Confirm" } });
+        register_locale(pack).unwrap();
+
+        let resolved = resolve_locale("x-partial");
+        let zh_cn = resolve_locale("zh-CN");
+        assert_eq!(resolved.chain, ["x-partial", "zh-CN", BASE_LOCALE]);
+        assert_eq!(resolved.texts.modal.ok_text, "Confirm");
+        assert_eq!(
+            resolved.texts.modal.cancel_text,
+            zh_cn.texts.modal.cancel_text
+        );
+    }
+}
@@ -1,19 +1,95 @@
 //! 本地化相关的类型定义
 
+use super::registry::resolve_locale;
+use crate::components::config_provider::ValidateMessages;
+use chrono::Weekday;
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// 支持的语言类型
+///
+/// 中文（简体）与英文（美国）始终可用，其余内置语言包需要开启对应的
+/// `locale-*` 特性，未开启时按回退链使用其他语言包。
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Language {
     /// 中文（简体）
     ZhCN,
     /// 英文（美国）
     EnUS,
+    /// 中文（繁体）
+    ZhTW,
+    /// 日文
+    JaJP,
+    /// 韩文
+    KoKR,
+    /// 德文
+    DeDE,
+    /// 法文
+    FrFR,
+    /// 阿拉伯文
+    ArEG,
+    /// 运行时注册的其他语言，值为 BCP-47 标签
+    Custom(String),
+}
+
+impl Language {
+    /// BCP-47 标签
+    pub fn tag(&self) -> &str {
+        match self {
+            Language::ZhCN => "zh-CN",
+            Language::EnUS => "en-US",
+            Language::ZhTW => "zh-TW",
+            Language::JaJP => "ja-JP",
+            Language::KoKR => "ko-KR",
+            Language::DeDE => "de-DE",
+            Language::FrFR => "fr-FR",
+            Language::ArEG => "ar-EG",
+            Language::Custom(tag) => tag,
+        }
+    }
+
+    /// 由 BCP-47 标签得到语言，未内置的标签为 `Custom`
+    pub fn from_tag(tag: &str) -> Self {
+        let tag = canonicalize_tag(tag);
+        [
+            Language::ZhCN,
+            Language::EnUS,
+            Language::ZhTW,
+            Language::JaJP,
+            Language::KoKR,
+            Language::DeDE,
+            Language::FrFR,
+            Language::ArEG,
+        ]
+        .into_iter()
+        .find(|language| language.tag() == tag)
+        .unwrap_or(Language::Custom(tag))
+    }
+}
+
+/// 规范化 BCP-47 标签的大小写，如 `zh_tw` → `zh-TW`、`zh-hant-tw` → `zh-Hant-TW`
+pub fn canonicalize_tag(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .enumerate()
+        .map(|(index, part)| match (index, part.len()) {
+            (0, _) => part.to_ascii_lowercase(),
+            (_, 2) => part.to_ascii_uppercase(),
+            // 非 ASCII 的子标签不是合法的文字代码，按字节切分会落在字符中间
+            (_, 4) if part.is_ascii() => {
+                let mut part = part.to_ascii_lowercase();
+                part[..1].make_ascii_uppercase();
+                part
+            }
+            _ => part.to_ascii_lowercase(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
 }
 
 /// 本地化文本内容
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LocaleText {
     /// 当前语言代码
     pub locale: String,
@@ -56,6 +132,7 @@ impl Default for LocaleText {
             form: FormLocaleText {
                 optional: String::new(),
                 required: String::new(),
+                validate_messages: ValidateMessages::default(),
             },
             table: TableLocaleText {
                 filter_title: String::new(),
@@ -73,7 +150,8 @@ impl Default for LocaleText {
 }
 
 /// 模态框本地化文本
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ModalLocaleText {
     /// 确认按钮文本
     pub ok_text: String,
@@ -84,7 +162,8 @@ pub struct ModalLocaleText {
 }
 
 /// Popconfirm 组件本地化文本
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PopconfirmLocaleText {
     /// 确认按钮文本
     pub ok: String,
@@ -97,16 +176,20 @@ pub struct PopconfirmLocaleText {
 }
 
 /// 表单组件本地化文本
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FormLocaleText {
     /// 可选字段文本
     pub optional: String,
     /// 必填字段文本
     pub required: String,
+    /// 校验信息模板
+    pub validate_messages: ValidateMessages,
 }
 
 /// 表格组件本地化文本
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TableLocaleText {
     /// 筛选菜单标题
     pub filter_title: String,
@@ -115,7 +198,8 @@ pub struct TableLocaleText {
 }
 
 /// 上传组件本地化文本
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UploadLocaleText {
     /// 上传按钮文本
     pub upload_text: String,
@@ -124,7 +208,8 @@ pub struct UploadLocaleText {
 }
 
 /// 空状态本地化文本
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EmptyLocaleText {
     /// 空状态描述
     pub description: String,
@@ -137,19 +222,233 @@ pub struct RTLConfig {
     pub enabled: bool,
 }
 
+/// 货币符号位置
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurrencyPosition {
+    /// 符号在数字之前，如 `$1.00`
+    Before,
+    /// 符号在数字之后，如 `1,00 €`
+    After,
+}
+
 /// 数字格式化配置
+///
+/// 每种语言一份，描述分隔符、货币与紧凑记数法单位等区域数据。
 #[derive(Clone, Debug, PartialEq)]
 pub struct NumberFormatConfig {
     /// 货币符号
     pub currency_symbol: String,
+    /// ISO 4217 货币代码，如 `USD`
+    pub currency_code: String,
+    /// 货币符号位置
+    pub currency_position: CurrencyPosition,
+    /// 货币符号与数字之间是否有空格
+    pub currency_spacing: bool,
+    /// 货币默认的小数位数
+    pub currency_fraction_digits: usize,
+    /// 小数点
+    pub decimal_separator: String,
+    /// 千位分隔符
+    pub group_separator: String,
+    /// 每组的位数
+    pub group_size: usize,
+    /// 负号
+    pub minus_sign: String,
+    /// 百分号，包含所需的空格
+    pub percent_suffix: String,
+    /// 紧凑记数法单位，按 10 的指数从小到大排列，如 `(3, "K")`、`(4, "万")`
+    pub compact_units: Vec<(i32, String)>,
+}
+
+impl NumberFormatConfig {
+    /// 英文（美国）数字格式
+    pub fn en_us() -> Self {
+        Self {
+            currency_symbol: "$".to_string(),
+            currency_code: "USD".to_string(),
+            currency_position: CurrencyPosition::Before,
+            currency_spacing: false,
+            currency_fraction_digits: 2,
+            decimal_separator: ".".to_string(),
+            group_separator: ",".to_string(),
+            group_size: 3,
+            minus_sign: "-".to_string(),
+            percent_suffix: "%".to_string(),
+            compact_units: vec![
+                (3, "K".to_string()),
+                (6, "M".to_string()),
+                (9, "B".to_string()),
+                (12, "T".to_string()),
+            ],
+        }
+    }
+
+    /// 中文（简体）数字格式
+    pub fn zh_cn() -> Self {
+        Self {
+            currency_symbol: "¥".to_string(),
+            currency_code: "CNY".to_string(),
+            compact_units: vec![
+                (4, "万".to_string()),
+                (8, "亿".to_string()),
+                (12, "万亿".to_string()),
+            ],
+            ..Self::en_us()
+        }
+    }
+
+    /// 指定语言的数字格式，取自语言包注册表
+    pub fn for_language(language: &Language) -> Self {
+        resolve_locale(language.tag()).number_format
+    }
 }
 
 impl Default for NumberFormatConfig {
     fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+/// 相对时间文本，`{}` 为数量或时间段的占位符
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelativeTimeConfig {
+    /// 将来，如 `{}后`
+    pub future: String,
+    /// 过去，如 `{}前`
+    pub past: String,
+    /// 几秒
+    pub seconds: String,
+    /// 1 分钟
+    pub minute: String,
+    /// 多分钟
+    pub minutes: String,
+    /// 1 小时
+    pub hour: String,
+    /// 多小时
+    pub hours: String,
+    /// 1 天
+    pub day: String,
+    /// 多天
+    pub days: String,
+    /// 1 个月
+    pub month: String,
+    /// 多个月
+    pub months: String,
+    /// 1 年
+    pub year: String,
+    /// 多年
+    pub years: String,
+}
+
+/// 日期格式化配置
+///
+/// 每种语言一份，提供月份与星期名称、上午/下午标记、一周的第一天和相对时间文本。
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateFormatConfig {
+    /// 月份全称，从一月开始
+    pub months: [String; 12],
+    /// 月份简称
+    pub months_short: [String; 12],
+    /// 星期全称，从星期日开始
+    pub weekdays: [String; 7],
+    /// 星期简称
+    pub weekdays_short: [String; 7],
+    /// 星期最短名称
+    pub weekdays_min: [String; 7],
+    /// 时段标记，按起始时间（`小时 * 100 + 分钟`）升序排列
+    pub meridiem: Vec<(u32, String)>,
+    /// 一周的第一天
+    pub week_start: Weekday,
+    /// 未指定格式时使用的格式
+    pub default_format: String,
+    /// 相对时间文本
+    pub relative_time: RelativeTimeConfig,
+}
+
+/// 由 `_` 分隔的名称列表构造定长数组
+pub fn names<const N: usize>(names: &str) -> [String; N] {
+    let mut names = names.split('_').map(str::to_string);
+    std::array::from_fn(|_| names.next().unwrap_or_default())
+}
+
+impl DateFormatConfig {
+    /// 英文（美国）日期格式
+    pub fn en_us() -> Self {
         Self {
-            currency_symbol: "$".to_string(),
+            months: names(
+                "January_February_March_April_May_June_July_August_September_October_November_December",
+            ),
+            months_short: names("Jan_Feb_Mar_Apr_May_Jun_Jul_Aug_Sep_Oct_Nov_Dec"),
+            weekdays: names("Sunday_Monday_Tuesday_Wednesday_Thursday_Friday_Saturday"),
+            weekdays_short: names("Sun_Mon_Tue_Wed_Thu_Fri_Sat"),
+            weekdays_min: names("Su_Mo_Tu_We_Th_Fr_Sa"),
+            meridiem: vec![(0, "AM".to_string()), (1200, "PM".to_string())],
+            week_start: Weekday::Sun,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "in {}".to_string(),
+                past: "{} ago".to_string(),
+                seconds: "a few seconds".to_string(),
+                minute: "a minute".to_string(),
+                minutes: "{} minutes".to_string(),
+                hour: "an hour".to_string(),
+                hours: "{} hours".to_string(),
+                day: "a day".to_string(),
+                days: "{} days".to_string(),
+                month: "a month".to_string(),
+                months: "{} months".to_string(),
+                year: "a year".to_string(),
+                years: "{} years".to_string(),
+            },
         }
     }
+
+    /// 中文（简体）日期格式
+    pub fn zh_cn() -> Self {
+        Self {
+            months: names("一月_二月_三月_四月_五月_六月_七月_八月_九月_十月_十一月_十二月"),
+            months_short: names("1月_2月_3月_4月_5月_6月_7月_8月_9月_10月_11月_12月"),
+            weekdays: names("星期日_星期一_星期二_星期三_星期四_星期五_星期六"),
+            weekdays_short: names("周日_周一_周二_周三_周四_周五_周六"),
+            weekdays_min: names("日_一_二_三_四_五_六"),
+            meridiem: vec![
+                (0, "凌晨".to_string()),
+                (600, "早上".to_string()),
+                (900, "上午".to_string()),
+                (1100, "中午".to_string()),
+                (1300, "下午".to_string()),
+                (1800, "晚上".to_string()),
+            ],
+            week_start: Weekday::Mon,
+            default_format: "YYYY-MM-DD".to_string(),
+            relative_time: RelativeTimeConfig {
+                future: "{}后".to_string(),
+                past: "{}前".to_string(),
+                seconds: "几秒".to_string(),
+                minute: "1 分钟".to_string(),
+                minutes: "{} 分钟".to_string(),
+                hour: "1 小时".to_string(),
+                hours: "{} 小时".to_string(),
+                day: "1 天".to_string(),
+                days: "{} 天".to_string(),
+                month: "1 个月".to_string(),
+                months: "{} 个月".to_string(),
+                year: "1 年".to_string(),
+                years: "{} 年".to_string(),
+            },
+        }
+    }
+
+    /// 指定语言的日期格式，取自语言包注册表
+    pub fn for_language(language: &Language) -> Self {
+        resolve_locale(language.tag()).date_format
+    }
+}
+
+impl Default for DateFormatConfig {
+    fn default() -> Self {
+        Self::en_us()
+    }
 }
 
 /// 本地化配置
@@ -159,6 +458,8 @@ pub struct LocaleConfig {
     pub language: Language,
     /// 数字格式化设置
     pub number_format: NumberFormatConfig,
+    /// 日期格式化设置
+    pub date_format: DateFormatConfig,
     /// 自定义本地化文本
     pub texts: Option<LocaleText>,
     /// RTL 支持配置
@@ -169,7 +470,8 @@ impl Default for LocaleConfig {
     fn default() -> Self {
         Self {
             language: Language::ZhCN,
-            number_format: NumberFormatConfig::default(),
+            number_format: NumberFormatConfig::zh_cn(),
+            date_format: DateFormatConfig::zh_cn(),
             texts: None,
             rtl: RTLConfig::default(),
         }
@@ -178,3 +480,23 @@ impl Default for LocaleConfig {
 
 /// 本地化上下文类型
 pub type LocaleContext = RwSignal<LocaleConfig>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalizes_tag_case() {
+        assert_eq!(canonicalize_tag("zh_tw"), "zh-TW");
+        assert_eq!(canonicalize_tag("ZH-hant-tw"), "zh-Hant-TW");
+        assert_eq!(canonicalize_tag("en--US"), "en-US");
+        assert_eq!(canonicalize_tag("de-DE-1996"), "de-DE-1996");
+    }
+
+    #[test]
+    fn keeps_non_ascii_subtags() {
+        assert_eq!(canonicalize_tag("en-éab"), "en-éab");
+        assert_eq!(canonicalize_tag("en-ÉAB"), "en-Éab");
+        assert_eq!(canonicalize_tag("fr-é"), "fr-é");
+    }
+}
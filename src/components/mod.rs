@@ -1,7 +1,15 @@
+/// Button component
+pub mod button;
 /// Global configuration provider component
 pub mod config_provider;
+/// Form components and validation rules
+pub mod form;
+/// Icon component and built-in icons
+pub mod icon;
 /// Internationalization component
 pub mod locale;
+/// Modal dialog and confirm dialogs
+pub mod modal;
 /// Global style injection component
 pub mod style;
 /// Theme customization component
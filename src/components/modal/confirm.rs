@@ -0,0 +1,400 @@
+use super::{use_modal_texts, Modal};
+use crate::components::button::{Button, ButtonType};
+use crate::components::config_provider::get_component_cls;
+use crate::components::icon::icons::{
+    CHECK_CIRCLE_FILLED, CLOSE_CIRCLE_FILLED, EXCLAMATION_CIRCLE_FILLED, INFO_CIRCLE_FILLED,
+};
+use crate::components::icon::{Icon, IconDefinition};
+use futures::channel::oneshot;
+use leptos::ev::MouseEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Kind of a confirm dialog, deciding its icon and buttons
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConfirmKind {
+    /// Asks the user to confirm, with OK and Cancel buttons
+    #[default]
+    Confirm,
+    /// Information, with an OK button only
+    Info,
+    /// Success, with an OK button only
+    Success,
+    /// Error, with an OK button only
+    Error,
+    /// Warning, with an OK button only
+    Warning,
+}
+
+impl ConfirmKind {
+    /// Name of the kind, used in class names
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfirmKind::Confirm => "confirm",
+            ConfirmKind::Info => "info",
+            ConfirmKind::Success => "success",
+            ConfirmKind::Error => "error",
+            ConfirmKind::Warning => "warning",
+        }
+    }
+
+    fn icon(&self) -> IconDefinition {
+        match self {
+            ConfirmKind::Confirm | ConfirmKind::Warning => EXCLAMATION_CIRCLE_FILLED,
+            ConfirmKind::Info => INFO_CIRCLE_FILLED,
+            ConfirmKind::Success => CHECK_CIRCLE_FILLED,
+            ConfirmKind::Error => CLOSE_CIRCLE_FILLED,
+        }
+    }
+}
+
+/// Action run by the OK button of a confirm dialog
+///
+/// The dialog shows a loading OK button until the future resolves and
+/// closes when it resolves to `true`.
+pub type ConfirmOk = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = bool>>> + Send + Sync>;
+
+/// Options of a confirm dialog
+#[derive(Clone)]
+pub struct ConfirmConfig {
+    /// Kind of the dialog
+    pub kind: ConfirmKind,
+    /// Title
+    pub title: Option<ViewFn>,
+    /// Content below the title
+    pub content: Option<ViewFn>,
+    /// Text of the OK button, taken from the locale when not set
+    pub ok_text: Option<String>,
+    /// Text of the Cancel button, taken from the locale when not set
+    pub cancel_text: Option<String>,
+    /// Whether the OK button is a danger button
+    pub ok_danger: bool,
+    /// Width, as a CSS length
+    pub width: String,
+    /// Whether the dialog is vertically centered
+    pub centered: bool,
+    /// Whether the close button is shown
+    pub closable: bool,
+    /// Whether clicking the mask cancels the dialog
+    pub mask_closable: bool,
+    /// Whether Esc cancels the dialog
+    pub keyboard: bool,
+    /// Action of the OK button
+    pub on_ok: Option<ConfirmOk>,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            kind: ConfirmKind::Confirm,
+            title: None,
+            content: None,
+            ok_text: None,
+            cancel_text: None,
+            ok_danger: false,
+            width: "416px".to_string(),
+            centered: false,
+            closable: false,
+            mask_closable: false,
+            keyboard: true,
+            on_ok: None,
+        }
+    }
+}
+
+impl ConfirmConfig {
+    /// Create options with a title
+    pub fn new<V>(title: V) -> Self
+    where
+        V: IntoView + Clone + Send + Sync + 'static,
+    {
+        Self::default().with_title(title)
+    }
+
+    /// Set the title
+    pub fn with_title<V>(mut self, title: V) -> Self
+    where
+        V: IntoView + Clone + Send + Sync + 'static,
+    {
+        self.title = Some(ViewFn::from(move || title.clone()));
+        self
+    }
+
+    /// Set the content
+    pub fn with_content<V>(mut self, content: V) -> Self
+    where
+        V: IntoView + Clone + Send + Sync + 'static,
+    {
+        self.content = Some(ViewFn::from(move || content.clone()));
+        self
+    }
+
+    /// Set the text of the OK button
+    pub fn with_ok_text(mut self, text: impl Into<String>) -> Self {
+        self.ok_text = Some(text.into());
+        self
+    }
+
+    /// Set the text of the Cancel button
+    pub fn with_cancel_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_text = Some(text.into());
+        self
+    }
+
+    /// Make the OK button a danger button
+    pub fn with_ok_danger(mut self) -> Self {
+        self.ok_danger = true;
+        self
+    }
+
+    /// Center the dialog vertically
+    pub fn with_centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+
+    /// Set the width
+    pub fn with_width(mut self, width: impl Into<String>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Run an action when OK is clicked, keeping the dialog open unless it
+    /// resolves to `true`
+    pub fn with_on_ok<F, Fut>(mut self, on_ok: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + 'static,
+    {
+        self.on_ok = Some(Arc::new(move || Box::pin(on_ok())));
+        self
+    }
+}
+
+#[derive(Default)]
+struct Dialogs {
+    next: usize,
+    owners: HashMap<usize, Owner>,
+}
+
+/// Opens confirm dialogs, see [`use_modal`]
+#[derive(Clone, Copy)]
+pub struct ModalApi {
+    owner: StoredValue<Owner>,
+    dialogs: StoredValue<Dialogs>,
+}
+
+impl ModalApi {
+    /// Open a confirm dialog, resolving to whether the user confirmed
+    ///
+    /// The future resolves to `false` when the dialog is cancelled or
+    /// destroyed.
+    pub fn confirm(&self, config: ConfirmConfig) -> impl Future<Output = bool> + 'static {
+        self.open(ConfirmKind::Confirm, config)
+    }
+
+    /// Open an information dialog, resolving when it is closed
+    pub fn info(&self, config: ConfirmConfig) -> impl Future<Output = bool> + 'static {
+        self.open(ConfirmKind::Info, config)
+    }
+
+    /// Open a success dialog, resolving when it is closed
+    pub fn success(&self, config: ConfirmConfig) -> impl Future<Output = bool> + 'static {
+        self.open(ConfirmKind::Success, config)
+    }
+
+    /// Open an error dialog, resolving when it is closed
+    pub fn error(&self, config: ConfirmConfig) -> impl Future<Output = bool> + 'static {
+        self.open(ConfirmKind::Error, config)
+    }
+
+    /// Open a warning dialog, resolving when it is closed
+    pub fn warning(&self, config: ConfirmConfig) -> impl Future<Output = bool> + 'static {
+        self.open(ConfirmKind::Warning, config)
+    }
+
+    /// Close all open dialogs at once, resolving them to `false`
+    pub fn destroy_all(&self) {
+        let owners = self
+            .dialogs
+            .try_update_value(|dialogs| std::mem::take(&mut dialogs.owners))
+            .unwrap_or_default();
+        for owner in owners.into_values() {
+            owner.cleanup();
+        }
+    }
+
+    fn open(
+        &self,
+        kind: ConfirmKind,
+        config: ConfirmConfig,
+    ) -> impl Future<Output = bool> + 'static {
+        let (sender, receiver) = oneshot::channel();
+        let dialogs = self.dialogs;
+        let key = dialogs.try_update_value(|dialogs| {
+            dialogs.next += 1;
+            dialogs.next
+        });
+        if let (Some(key), Some(parent)) = (key, self.owner.try_get_value()) {
+            // 每个对话框有自己的 owner，关闭后整体销毁
+            let owner = parent.child();
+            owner.with(|| {
+                let after_close = Callback::new(move |_| {
+                    // 关闭回调运行在对话框的 owner 中，推迟到之后再销毁
+                    set_timeout(
+                        move || {
+                            let owner = dialogs
+                                .try_update_value(|dialogs| dialogs.owners.remove(&key))
+                                .flatten();
+                            if let Some(owner) = owner {
+                                owner.cleanup();
+                            }
+                        },
+                        Duration::ZERO,
+                    );
+                });
+                let _ = confirm_dialog(ConfirmConfig { kind, ..config }, sender, after_close);
+            });
+            dialogs.update_value(|dialogs| {
+                dialogs.owners.insert(key, owner);
+            });
+        }
+        async move { receiver.await.unwrap_or(false) }
+    }
+}
+
+/// Get an API opening confirm dialogs that can be awaited
+///
+/// Dialogs live in the current component, so they see its theme and locale
+/// and are destroyed with it.
+pub fn use_modal() -> ModalApi {
+    let api = ModalApi {
+        owner: StoredValue::new(Owner::current().unwrap_or_default()),
+        dialogs: StoredValue::new(Dialogs::default()),
+    };
+    on_cleanup(move || api.destroy_all());
+    api
+}
+
+fn confirm_dialog(
+    config: ConfirmConfig,
+    sender: oneshot::Sender<bool>,
+    after_close: Callback<()>,
+) -> impl IntoView {
+    let cls = get_component_cls("modal");
+    let texts = use_modal_texts();
+    let ConfirmConfig {
+        kind,
+        title,
+        content,
+        ok_text,
+        cancel_text,
+        ok_danger,
+        width,
+        centered,
+        closable,
+        mask_closable,
+        keyboard,
+        on_ok,
+    } = config;
+
+    let open = RwSignal::new(true);
+    let loading = RwSignal::new(false);
+    let sender = StoredValue::new(Some(sender));
+    let close = move |ok: bool| {
+        if let Some(sender) = sender.try_update_value(Option::take).flatten() {
+            let _ = sender.send(ok);
+        }
+        open.try_set(false);
+    };
+    let on_ok_click = move |_: MouseEvent| {
+        if loading.get_untracked() {
+            return;
+        }
+        match on_ok.clone() {
+            Some(on_ok) => {
+                loading.set(true);
+                spawn_local(async move {
+                    let ok = on_ok().await;
+                    loading.try_set(false);
+                    if ok {
+                        close(true);
+                    }
+                });
+            }
+            None => close(true),
+        }
+    };
+
+    let body = {
+        let cls = cls.clone();
+        move || {
+            let title = title.clone().map(|title| {
+                view! { <span class=format!("{}-confirm-title", cls)>{title.run()}</span> }
+            });
+            let content = content.clone().map(|content| {
+                view! { <div class=format!("{}-confirm-content", cls)>{content.run()}</div> }
+            });
+            let cancel_button = (kind == ConfirmKind::Confirm).then(|| {
+                let cancel_text = cancel_text.clone();
+                view! {
+                    <Button on_click=Callback::new(move |_: MouseEvent| close(false))>
+                        {move || cancel_text.clone().unwrap_or_else(|| texts.get().cancel_text)}
+                    </Button>
+                }
+            });
+            let ok_text = ok_text.clone();
+            let ok_label = move || {
+                ok_text.clone().unwrap_or_else(|| {
+                    let texts = texts.get();
+                    if kind == ConfirmKind::Confirm {
+                        texts.ok_text
+                    } else {
+                        texts.just_ok_text
+                    }
+                })
+            };
+            view! {
+                <div class=format!("{}-confirm-body-wrapper", cls)>
+                    <div class=format!("{}-confirm-body", cls)>
+                        <Icon icon=kind.icon() />
+                        <div class=format!("{}-confirm-paragraph", cls)>{title} {content}</div>
+                    </div>
+                    <div class=format!("{}-confirm-btns", cls)>
+                        {cancel_button}
+                        <Button
+                            button_type=ButtonType::Primary
+                            danger=ok_danger
+                            loading=loading
+                            on_click=Callback::new(on_ok_click.clone())
+                        >
+                            {ok_label}
+                        </Button>
+                    </div>
+                </div>
+            }
+        }
+    };
+
+    view! {
+        <Modal
+            open=open
+            hide_footer=true
+            closable=closable
+            mask_closable=mask_closable
+            keyboard=keyboard
+            centered=centered
+            width=width
+            class=format!("{0}-confirm {0}-confirm-{1}", cls, kind.as_str())
+            on_cancel=Callback::new(move |_| close(false))
+            after_close=after_close
+        >
+            {body.clone()}
+        </Modal>
+    }
+}
@@ -0,0 +1,486 @@
+//! 对话框组件
+//!
+//! `Modal` 渲染到 `Config.popup_container` 指定的容器中（未设置时为 `body`），
+//! 打开时锁定页面滚动，焦点限制在对话框内，关闭后回到打开前聚焦的元素。按 Esc、
+//! 点击遮罩或关闭按钮时调用 `on_cancel`，由使用者关闭对话框。
+//!
+//! 内容在第一次打开时才渲染，关闭后默认保留，`destroy_on_close` 时销毁。打开与
+//! 关闭使用缩放动画，时长取自主题的 `motionDurationSlow`。[`use_modal`] 提供
+//! 命令式的确认框，可以等待用户的选择。
+
+mod confirm;
+
+pub use confirm::{use_modal, ConfirmConfig, ConfirmKind, ConfirmOk, ModalApi};
+
+use crate::components::button::{Button, ButtonType};
+use crate::components::config_provider::{
+    get_component_cls, get_prefix_cls, use_config, use_direction, use_token, Config,
+};
+use crate::components::icon::{icons::CLOSE_OUTLINED, Icon};
+use crate::components::locale::types::ModalLocaleText;
+use crate::components::locale::{resolve_locale, use_locale_text, Language};
+use crate::styles::apply_modal_style;
+use crate::utils::dom::document;
+use leptos::children::ToChildren;
+use leptos::ev::{KeyboardEvent, MouseEvent};
+use leptos::html;
+use leptos::portal::{Portal, PortalProps};
+use leptos::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// 打开的对话框数量，全部关闭后才恢复页面滚动
+static OPEN_MODALS: AtomicUsize = AtomicUsize::new(0);
+
+/// 用于生成标题 id
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Style of the invisible elements keeping the focus inside the dialog
+const SENTINEL_STYLE: &str = "width:0;height:0;overflow:hidden;outline:none";
+
+fn lock_scroll() {
+    if OPEN_MODALS.fetch_add(1, Ordering::Relaxed) == 0 {
+        if let Some(body) = document().and_then(|document| document.body()) {
+            let _ = body.style().set_property("overflow", "hidden");
+        }
+    }
+}
+
+fn unlock_scroll() {
+    if OPEN_MODALS.fetch_sub(1, Ordering::Relaxed) == 1 {
+        if let Some(body) = document().and_then(|document| document.body()) {
+            let _ = body.style().remove_property("overflow");
+        }
+    }
+}
+
+/// Milliseconds of a motion duration token such as `0.3s`
+fn motion_millis(duration: &str) -> u64 {
+    let seconds = duration
+        .strip_suffix("ms")
+        .map(|millis| millis.trim().parse::<f64>().unwrap_or(0.0) / 1000.0)
+        .or_else(|| duration.strip_suffix('s')?.trim().parse().ok())
+        .unwrap_or(0.0);
+    (seconds * 1000.0).round() as u64
+}
+
+/// Texts of the modal buttons
+///
+/// Taken from the surrounding `LocaleProvider`, then from `Config.modal`,
+/// then from the English locale.
+pub(crate) fn use_modal_texts() -> Signal<ModalLocaleText> {
+    let locale_text = use_locale_text();
+    let config = use_config();
+    Signal::derive(move || {
+        locale_text
+            .map(|text| text.with(|text| text.modal.clone()))
+            .or_else(|| {
+                config.map(|config| {
+                    config.with(|config| ModalLocaleText {
+                        ok_text: config.modal.ok_text.clone(),
+                        cancel_text: config.modal.close_text.clone(),
+                        just_ok_text: config.modal.ok_text.clone(),
+                    })
+                })
+            })
+            .unwrap_or_else(|| resolve_locale(Language::EnUS.tag()).texts.modal)
+    })
+}
+
+fn active_element() -> Option<Element> {
+    document().and_then(|document| document.active_element())
+}
+
+fn focus(element: Option<impl JsCast>) {
+    if let Some(element) = element.and_then(|element| element.dyn_into::<HtmlElement>().ok()) {
+        let _ = element.focus();
+    }
+}
+
+/// 对话框
+#[component]
+pub fn Modal(
+    /// 是否打开
+    #[prop(into)]
+    open: Signal<bool>,
+    /// 标题
+    #[prop(optional, into)]
+    title: Option<ViewFn>,
+    /// 页脚，替代默认的取消与确定按钮
+    #[prop(optional, into)]
+    footer: Option<ViewFn>,
+    /// 不显示页脚
+    #[prop(optional)]
+    hide_footer: bool,
+    /// 确定按钮文本，未设置时使用语言包
+    #[prop(optional, into)]
+    ok_text: Option<Signal<String>>,
+    /// 取消按钮文本，未设置时使用语言包
+    #[prop(optional, into)]
+    cancel_text: Option<Signal<String>>,
+    /// 确定按钮类型
+    #[prop(default = Signal::stored(ButtonType::Primary), into)]
+    ok_type: Signal<ButtonType>,
+    /// 确定按钮是否为危险按钮
+    #[prop(optional, into)]
+    ok_danger: Signal<bool>,
+    /// 确定按钮的加载状态，例如提交期间
+    #[prop(optional, into)]
+    confirm_loading: Signal<bool>,
+    /// 是否显示右上角的关闭按钮
+    #[prop(default = true)]
+    closable: bool,
+    /// 是否显示遮罩
+    #[prop(default = true)]
+    mask: bool,
+    /// 点击遮罩时是否关闭
+    #[prop(default = true)]
+    mask_closable: bool,
+    /// 按 Esc 时是否关闭
+    #[prop(default = true)]
+    keyboard: bool,
+    /// 是否垂直居中
+    #[prop(optional, into)]
+    centered: Signal<bool>,
+    /// 宽度，CSS 长度
+    #[prop(default = "520px".to_string(), into)]
+    width: String,
+    /// 层级，未设置时使用主题的 `zIndexPopupBase`
+    #[prop(optional)]
+    z_index: Option<i32>,
+    /// 关闭后是否销毁内容
+    #[prop(optional)]
+    destroy_on_close: bool,
+    /// 是否可以拖动标题栏移动对话框
+    #[prop(optional)]
+    draggable: bool,
+    /// 对话框的额外类名
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// 点击确定按钮
+    #[prop(optional, into)]
+    on_ok: Option<Callback<()>>,
+    /// 点击取消按钮、关闭按钮、遮罩或按 Esc
+    #[prop(optional, into)]
+    on_cancel: Option<Callback<()>>,
+    /// 关闭动画结束后调用
+    #[prop(optional, into)]
+    after_close: Option<Callback<()>>,
+    /// 对话框内容
+    children: ChildrenFn,
+) -> impl IntoView {
+    let hash_cls = apply_modal_style();
+    let cls = get_component_cls("modal");
+    let prefix_cls = get_prefix_cls().unwrap_or_else(|| Config::default().prefix_cls);
+    let config = use_config();
+    let direction = use_direction();
+    let token = use_token();
+    let texts = use_modal_texts();
+    let container = config.and_then(|config| config.with_untracked(Config::get_popup_container));
+    let title_id = format!("{}-title-{}", cls, NEXT_ID.fetch_add(1, Ordering::Relaxed));
+
+    // 内容是否已渲染、是否显示、是否正在播放关闭动画
+    let rendered = RwSignal::new(false);
+    let shown = RwSignal::new(false);
+    let leaving = RwSignal::new(false);
+    let offset = RwSignal::new((0.0, 0.0));
+    let close_timeout = StoredValue::new(None::<TimeoutHandle>);
+    let scroll_locked = StoredValue::new(false);
+    let last_focus = StoredValue::new_local(None::<Element>);
+    let drag_listeners = StoredValue::new_local(Vec::<WindowListenerHandle>::new());
+    let wrap_ref = NodeRef::<html::Div>::new();
+    let start_ref = NodeRef::<html::Div>::new();
+    let end_ref = NodeRef::<html::Div>::new();
+
+    let finish_close = move || {
+        shown.try_set(false);
+        leaving.try_set(false);
+        if destroy_on_close {
+            rendered.try_set(false);
+        }
+        if scroll_locked.try_update_value(std::mem::take) == Some(true) {
+            unlock_scroll();
+        }
+        focus(last_focus.try_update_value(Option::take).flatten());
+        if let Some(after_close) = after_close {
+            after_close.run(());
+        }
+    };
+
+    Effect::new(move |_| {
+        let open = open.get();
+        if let Some(handle) = close_timeout.get_value() {
+            handle.clear();
+            close_timeout.set_value(None);
+        }
+        if open {
+            if !shown.get_untracked() {
+                last_focus.set_value(active_element());
+                offset.set((0.0, 0.0));
+            }
+            if !scroll_locked.get_value() {
+                lock_scroll();
+                scroll_locked.set_value(true);
+            }
+            rendered.set(true);
+            shown.set(true);
+            leaving.set(false);
+            request_animation_frame(move || focus(wrap_ref.get_untracked()));
+        } else if shown.get_untracked() {
+            leaving.set(true);
+            let duration = token
+                .with_untracked(|info| motion_millis(&info.token.map.common.motion_duration_slow));
+            let handle = set_timeout_with_handle(
+                move || {
+                    close_timeout.try_set_value(None);
+                    finish_close();
+                },
+                Duration::from_millis(duration),
+            );
+            match handle {
+                Ok(handle) => close_timeout.set_value(Some(handle)),
+                Err(_) => finish_close(),
+            }
+        }
+    });
+    on_cleanup(move || {
+        if let Some(handle) = close_timeout.try_get_value().flatten() {
+            handle.clear();
+        }
+        if scroll_locked.try_get_value() == Some(true) {
+            unlock_scroll();
+        }
+        for listener in drag_listeners
+            .try_update_value(std::mem::take)
+            .unwrap_or_default()
+        {
+            listener.remove();
+        }
+    });
+
+    let cancel = move || {
+        if let Some(on_cancel) = on_cancel {
+            on_cancel.run(());
+        }
+    };
+
+    let on_keydown = move |event: KeyboardEvent| match event.key().as_str() {
+        "Escape" if keyboard => {
+            event.stop_propagation();
+            cancel();
+        }
+        // 在首尾两个哨兵元素之间循环，使 Tab 不会离开对话框
+        "Tab" => {
+            let active = active_element();
+            let is = |node: &NodeRef<html::Div>| {
+                active.is_some() && node.get_untracked().map(Element::from) == active
+            };
+            if event.shift_key() && (is(&start_ref) || is(&wrap_ref)) {
+                focus(end_ref.get_untracked());
+            } else if !event.shift_key() && is(&end_ref) {
+                focus(start_ref.get_untracked());
+            }
+        }
+        _ => {}
+    };
+
+    // 只有按下与松开都在遮罩区域时才算点击遮罩，避免从对话框内拖选到外面时关闭
+    let mouse_down_on_mask = StoredValue::new(false);
+    let on_wrap_mousedown = move |event: MouseEvent| {
+        mouse_down_on_mask.set_value(event.target() == event.current_target());
+    };
+    let on_wrap_click = move |event: MouseEvent| {
+        if mask
+            && mask_closable
+            && mouse_down_on_mask.get_value()
+            && event.target() == event.current_target()
+        {
+            cancel();
+        }
+    };
+
+    let on_header_mousedown = move |event: MouseEvent| {
+        if !draggable || event.button() != 0 {
+            return;
+        }
+        event.prevent_default();
+        let (start_x, start_y) = (f64::from(event.client_x()), f64::from(event.client_y()));
+        let (base_x, base_y) = offset.get_untracked();
+        let moving = window_event_listener(leptos::ev::mousemove, move |event| {
+            offset.set((
+                base_x + f64::from(event.client_x()) - start_x,
+                base_y + f64::from(event.client_y()) - start_y,
+            ));
+        });
+        let up = window_event_listener(leptos::ev::mouseup, move |_| {
+            for listener in drag_listeners
+                .try_update_value(std::mem::take)
+                .unwrap_or_default()
+            {
+                listener.remove();
+            }
+        });
+        drag_listeners.update_value(|listeners| listeners.extend([moving, up]));
+    };
+
+    let overlay_style = move || {
+        let mut style = String::new();
+        if !shown.get() {
+            style.push_str("display:none;");
+        }
+        if let Some(z_index) = z_index {
+            style.push_str(&format!("z-index:{};", z_index));
+        }
+        style
+    };
+
+    let dialog = move || {
+        if !rendered.get() {
+            return None;
+        }
+        let cls = cls.clone();
+        let prefix_cls = prefix_cls.clone();
+        let title_id = title_id.clone();
+        let has_title = title.is_some();
+
+        let mask_view = mask.then(|| {
+            let cls = cls.clone();
+            let prefix_cls = prefix_cls.clone();
+            let class = move || {
+                let motion = if leaving.get() { "leave" } else { "enter" };
+                format!(
+                    "{}-mask {} {}-fade-{}",
+                    cls,
+                    hash_cls.get(),
+                    prefix_cls,
+                    motion
+                )
+            };
+            view! { <div class=class style=overlay_style></div> }
+        });
+        let wrap_class = {
+            let cls = cls.clone();
+            move || {
+                let mut classes = vec![format!("{}-wrap", cls), hash_cls.get()];
+                if centered.get() {
+                    classes.push(format!("{}-centered", cls));
+                }
+                classes.join(" ")
+            }
+        };
+        let modal_class = {
+            let cls = cls.clone();
+            let class = class.clone();
+            move || {
+                let motion = if leaving.get() { "leave" } else { "enter" };
+                let mut classes = vec![
+                    cls.clone(),
+                    hash_cls.get(),
+                    format!("{}-zoom-{}", prefix_cls, motion),
+                ];
+                if draggable {
+                    classes.push(format!("{}-draggable", cls));
+                }
+                classes.extend(class.clone());
+                classes.join(" ")
+            }
+        };
+        let content_style = move || {
+            let (x, y) = offset.get();
+            (x != 0.0 || y != 0.0).then(|| format!("transform:translate({}px,{}px)", x, y))
+        };
+
+        let close_view = closable.then(|| {
+            view! {
+                <button
+                    type="button"
+                    aria-label="Close"
+                    class=format!("{}-close", cls)
+                    on:click=move |_| cancel()
+                >
+                    <span class=format!("{}-close-x", cls)>
+                        <Icon icon=CLOSE_OUTLINED />
+                    </span>
+                </button>
+            }
+        });
+        let header_view = title.clone().map(|title| {
+            view! {
+                <div class=format!("{}-header", cls) on:mousedown=on_header_mousedown>
+                    <div class=format!("{}-title", cls) id=title_id.clone()>
+                        {title.run()}
+                    </div>
+                </div>
+            }
+        });
+        let footer_view = (!hide_footer).then(|| {
+            let content = match footer.clone() {
+                Some(footer) => footer.run(),
+                None => view! {
+                    <Button on_click=Callback::new(move |_: MouseEvent| cancel())>
+                        {move || cancel_text.map(|text| text.get()).unwrap_or_else(|| texts.get().cancel_text)}
+                    </Button>
+                    <Button
+                        button_type=ok_type
+                        danger=ok_danger
+                        loading=confirm_loading
+                        on_click=Callback::new(move |_: MouseEvent| {
+                            if let Some(on_ok) = on_ok {
+                                on_ok.run(());
+                            }
+                        })
+                    >
+                        {move || ok_text.map(|text| text.get()).unwrap_or_else(|| texts.get().ok_text)}
+                    </Button>
+                }
+                .into_any(),
+            };
+            view! { <div class=format!("{}-footer", cls)>{content}</div> }
+        });
+
+        Some(view! {
+            <div
+                class=format!("{}-root {}", cls, hash_cls.get_untracked())
+                dir=move || direction.map(|direction| direction.get().as_str())
+            >
+                {mask_view}
+                <div
+                    node_ref=wrap_ref
+                    tabindex="-1"
+                    class=wrap_class
+                    style=overlay_style
+                    on:keydown=on_keydown
+                    on:mousedown=on_wrap_mousedown
+                    on:click=on_wrap_click
+                >
+                    <div
+                        role="dialog"
+                        aria-modal="true"
+                        aria-labelledby=has_title.then_some(title_id)
+                        class=modal_class
+                        style=format!("width:{}", width)
+                    >
+                        <div node_ref=start_ref tabindex="0" aria-hidden="true" style=SENTINEL_STYLE></div>
+                        <div class=format!("{}-content", cls) style=content_style>
+                            {close_view}
+                            {header_view}
+                            <div class=format!("{}-body", cls)>{children()}</div>
+                            {footer_view}
+                        </div>
+                        <div node_ref=end_ref tabindex="0" aria-hidden="true" style=SENTINEL_STYLE></div>
+                    </div>
+                </div>
+            </div>
+        })
+    };
+
+    // Portal 不跟踪子节点中读取的信号，交给它一个响应式的视图
+    Portal(PortalProps {
+        mount: container,
+        use_shadow: false,
+        is_svg: false,
+        children: ToChildren::to_children(move || dialog.clone()),
+    })
+}
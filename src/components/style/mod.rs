@@ -2,8 +2,10 @@
 //!
 //! 提供全局样式的初始化和管理功能
 
+use crate::components::config_provider::ANT_PREFIX;
+use crate::utils::hash::hash_str;
+use crate::utils::style::register_style;
 use leptos::prelude::*;
-use web_sys::window;
 
 /// 全局样式组件
 #[component]
@@ -23,22 +25,14 @@ pub struct StyleManager;
 impl StyleManager {
     /// 初始化全局样式
     pub fn init_global_style() {
-        let window = window().expect("Failed to get window");
-        let document = window.document().expect("Failed to get document");
-
-        // 创建 style 元素
-        let style = document
-            .create_element("style")
-            .expect("Failed to create style element");
-
-        // 设置样式内容
-        style.set_text_content(Some(include_str!("../../styles/global.css")));
+        Self::init_global_style_with_prefix(ANT_PREFIX);
+    }
 
-        // 将 style 元素添加到 head 中
-        document
-            .head()
-            .expect("Failed to get head element")
-            .append_child(&style.as_ref())
-            .expect("Failed to append style element");
+    /// 使用自定义前缀初始化全局样式
+    pub fn init_global_style_with_prefix(prefix_cls: &str) {
+        let css = include_str!("../../styles/global.css")
+            .replace(".ant-", &format!(".{}-", prefix_cls))
+            .replace("--ant-", &format!("--{}-", prefix_cls));
+        register_style("global", &hash_str(&css), &css);
     }
 }
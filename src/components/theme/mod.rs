@@ -1,37 +1,295 @@
 //! 主题组件
 //!
-//! 提供主题配置和切换功能
+//! 提供主题配置和切换功能。主题模式对应的算法（暗色、紧凑）交给内部的
+//! [`ConfigProvider`]，子组件的设计令牌与样式随之重新生成；模式同时写入
+//! `<html data-theme="...">`，供页面自身的样式使用。`ThemeMode::System` 跟随
+//! 系统的 `prefers-color-scheme` 变化。
+//!
+//! 保存的模式同时写入 localStorage 和 cookie。服务端从请求的 cookie（上下文中的
+//! `http::request::Parts`，`leptos_axum` 会提供）读取模式和上次检测到的系统
+//! 配色，直接按对应的算法渲染；水合时客户端读取同样的 cookie，两边的结果一致。
 
-use crate::components::config_provider::ThemeMode;
+use crate::components::config_provider::{ConfigProvider, ThemeConfig, ThemeMode};
+use crate::utils::dom::{document, local_storage, PREFERS_DARK_QUERY};
 use leptos::prelude::*;
-use web_sys::window;
+
+/// Attribute on `<html>` holding the resolved theme mode
+pub const THEME_ATTR: &str = "data-theme";
 
 /// 主题组件
+///
+/// 子组件使用当前模式的主题算法，其余令牌继承外层的 ConfigProvider。
+/// 服务端渲染时在 `<head>` 中放置 [`ThemeScript`]，可在页面绘制前恢复
+/// `data-theme`，避免页面背景闪烁。设置 `storage_key` 后，检测到的系统配色
+/// 保存在 cookie 中，服务端渲染 `ThemeMode::System` 时使用。
 #[component]
 pub fn Theme(
     /// 主题模式
     #[prop(into)]
-    theme_mode: ReadSignal<ThemeMode>,
+    theme_mode: Signal<ThemeMode>,
+    /// 保存主题模式的 localStorage 键，未设置时不保存
+    #[prop(optional, into)]
+    storage_key: Option<String>,
     /// 子元素
     children: Children,
 ) -> impl IntoView {
+    let prefers_dark = RwSignal::new(initial_prefers_dark(storage_key.as_deref()));
+    #[cfg(not(feature = "ssr"))]
+    watch_color_scheme(prefers_dark);
+
+    // 记录系统配色，供下次服务端渲染使用
+    if let Some(key) = storage_key.clone() {
+        Effect::new(move |_| {
+            let value = if prefers_dark.get() { "1" } else { "0" };
+            write_cookie(&prefers_dark_cookie(&key), value);
+        });
+    }
+
     // 监听主题变化并更新 DOM
-    create_effect(move |_| {
+    Effect::new(move |_| {
+        let mode = theme_mode.get().resolve(prefers_dark.get());
+        let Some(root) = document().and_then(|document| document.document_element()) else {
+            return;
+        };
+        let _ = root.set_attribute(THEME_ATTR, mode.as_str());
+    });
+
+    // 首次运行时的值可能只是默认值，只在用户切换后保存
+    Effect::new(move |initialized: Option<()>| {
         let mode = theme_mode.get();
-        let document = window().unwrap().document().unwrap();
-        let root = document.document_element().unwrap();
-        root.set_attribute(
-            "theme-mode",
-            match mode {
-                ThemeMode::Light => "light",
-                ThemeMode::Dark => "dark",
-                ThemeMode::Compact => "compact",
-            },
-        )
-        .unwrap();
+        if initialized.is_some() {
+            if let Some(key) = &storage_key {
+                store_theme_mode(key, mode);
+            }
+        }
+    });
+
+    let theme = Signal::derive(move || ThemeConfig {
+        algorithm: theme_mode.get().resolve(prefers_dark.get()).algorithm(),
+        ..ThemeConfig::default()
     });
 
     view! {
-        {children()}
+        <ConfigProvider theme=theme>{children()}</ConfigProvider>
+    }
+}
+
+/// System color scheme to render with before the client can check it
+///
+/// The server and the hydrating client both use the value saved in the cookie,
+/// so they render the same theme; client-side rendering checks directly.
+fn initial_prefers_dark(storage_key: Option<&str>) -> bool {
+    cfg_if::cfg_if! {
+        if #[cfg(any(feature = "ssr", feature = "hydrate"))] {
+            storage_key
+                .and_then(|key| read_cookie(&prefers_dark_cookie(key)))
+                .is_some_and(|value| value == "1")
+        } else {
+            let _ = storage_key;
+            crate::utils::dom::prefers_dark_scheme()
+        }
+    }
+}
+
+/// Keep `prefers_dark` in sync with the system color scheme
+#[cfg(not(feature = "ssr"))]
+fn watch_color_scheme(prefers_dark: RwSignal<bool>) {
+    use crate::utils::dom::{match_media, prefers_dark_scheme};
+    use wasm_bindgen::{closure::Closure, JsCast};
+    use web_sys::MediaQueryListEvent;
+
+    // 初始值可能来自 cookie，挂载后以实际的系统配色为准
+    Effect::new(move |_| {
+        let matches = prefers_dark_scheme();
+        if prefers_dark.get_untracked() != matches {
+            prefers_dark.set(matches);
+        }
+    });
+
+    let Some(query) = match_media(PREFERS_DARK_QUERY) else {
+        return;
+    };
+    let listener =
+        Closure::<dyn Fn(MediaQueryListEvent)>::new(move |event: MediaQueryListEvent| {
+            prefers_dark.set(event.matches());
+        });
+    if query
+        .add_event_listener_with_callback("change", listener.as_ref().unchecked_ref())
+        .is_err()
+    {
+        return;
+    }
+
+    let handle = StoredValue::new_local(Some((query, listener)));
+    on_cleanup(move || {
+        handle.update_value(|handle| {
+            if let Some((query, listener)) = handle.take() {
+                let _ = query.remove_event_listener_with_callback(
+                    "change",
+                    listener.as_ref().unchecked_ref(),
+                );
+            }
+        });
+    });
+}
+
+/// Read a saved theme mode from localStorage, or from the cookie on the server
+pub fn load_theme_mode(storage_key: &str) -> Option<ThemeMode> {
+    local_storage()
+        .and_then(|storage| storage.get_item(storage_key).ok().flatten())
+        .or_else(|| read_cookie(storage_key))
+        .and_then(|name| ThemeMode::from_name(&name))
+}
+
+/// Save a theme mode to localStorage and to a cookie the server can read
+pub fn store_theme_mode(storage_key: &str, mode: ThemeMode) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(storage_key, mode.as_str());
+    }
+    write_cookie(storage_key, mode.as_str());
+}
+
+/// Create a theme mode signal restored from the saved mode
+///
+/// When hydrating, the signal starts with the mode saved in the cookie, which
+/// is what the server rendered with, and switches to the mode saved in
+/// localStorage once mounted if the two differ.
+pub fn use_stored_theme_mode(
+    storage_key: impl Into<String>,
+    default: ThemeMode,
+) -> RwSignal<ThemeMode> {
+    let storage_key = storage_key.into();
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hydrate")] {
+            let cookie_mode = read_cookie(&storage_key).and_then(|name| ThemeMode::from_name(&name));
+            let mode = RwSignal::new(cookie_mode.unwrap_or(default));
+            Effect::new(move |_| {
+                if let Some(saved) = load_theme_mode(&storage_key) {
+                    if saved != mode.get_untracked() {
+                        mode.set(saved);
+                    }
+                }
+            });
+            mode
+        } else {
+            RwSignal::new(load_theme_mode(&storage_key).unwrap_or(default))
+        }
+    }
+}
+
+fn prefers_dark_cookie(storage_key: &str) -> String {
+    format!("{}-prefers-dark", storage_key)
+}
+
+/// Cookies of the current request on the server, or of the document
+fn cookies() -> Option<String> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            let parts = use_context::<http::request::Parts>()?;
+            let cookies: Vec<&str> = parts
+                .headers
+                .get_all(http::header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .collect();
+            Some(cookies.join("; "))
+        } else {
+            use wasm_bindgen::JsCast;
+
+            document()?.dyn_into::<web_sys::HtmlDocument>().ok()?.cookie().ok()
+        }
+    }
+}
+
+fn read_cookie(name: &str) -> Option<String> {
+    parse_cookie(&cookies()?, name)
+}
+
+fn parse_cookie(cookies: &str, name: &str) -> Option<String> {
+    cookies.split(';').find_map(|pair| {
+        let (key, value) = pair.trim().split_once('=')?;
+        (key == name).then(|| value.to_string())
+    })
+}
+
+/// Save a cookie for a year, ignored on the server
+fn write_cookie(name: &str, value: &str) {
+    use wasm_bindgen::JsCast;
+
+    if let Some(document) =
+        document().and_then(|document| document.dyn_into::<web_sys::HtmlDocument>().ok())
+    {
+        let _ = document.set_cookie(&format!(
+            "{}={}; path=/; max-age=31536000; SameSite=Lax",
+            name, value
+        ));
+    }
+}
+
+/// JavaScript applying the saved theme mode before the page is painted
+pub fn theme_script(storage_key: Option<&str>, default: ThemeMode) -> String {
+    let key = serde_json::to_string(&storage_key).unwrap_or_else(|_| "null".to_string());
+    format!(
+        "(function(){{try{{var k={key},m=(k&&localStorage.getItem(k))||\"{default}\";\
+         if(m===\"system\"){{m=matchMedia(\"{query}\").matches?\"dark\":\"light\"}}\
+         document.documentElement.setAttribute(\"{attr}\",m)}}catch(e){{}}}})()",
+        key = key,
+        default = default.as_str(),
+        query = PREFERS_DARK_QUERY,
+        attr = THEME_ATTR,
+    )
+    .replace("</", "<\\/")
+}
+
+/// 在页面绘制前恢复主题模式的内联脚本，放在 `<head>` 中
+#[component]
+pub fn ThemeScript(
+    /// 保存主题模式的 localStorage 键
+    #[prop(optional, into)]
+    storage_key: Option<String>,
+    /// 没有保存的模式时使用的模式
+    #[prop(optional)]
+    default_mode: Option<ThemeMode>,
+) -> impl IntoView {
+    let script = theme_script(
+        storage_key.as_deref(),
+        default_mode.unwrap_or(ThemeMode::Light),
+    );
+    view! { <script inner_html=script></script> }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_cookie_pairs() {
+        let cookies = "a=1; theme=dark;theme-prefers-dark=1";
+        assert_eq!(parse_cookie(cookies, "theme").as_deref(), Some("dark"));
+        assert_eq!(
+            parse_cookie(cookies, "theme-prefers-dark").as_deref(),
+            Some("1")
+        );
+        assert_eq!(parse_cookie(cookies, "them"), None);
+        assert_eq!(parse_cookie("", "theme"), None);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn server_reads_saved_mode_from_request_cookies() {
+        let (parts, _) = http::Request::builder()
+            .header(http::header::COOKIE, "theme=system; theme-prefers-dark=1")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_context(parts);
+            let mode = use_stored_theme_mode("theme", ThemeMode::Light);
+            assert_eq!(mode.get_untracked(), ThemeMode::System);
+            assert!(initial_prefers_dark(Some("theme")));
+            assert!(!initial_prefers_dark(None));
+        });
     }
 }
@@ -1,3 +1,5 @@
+use crate::components::config_provider::get_component_cls;
+use crate::styles::apply_version_style;
 use leptos::prelude::*;
 
 /// Current version of the component library
@@ -6,8 +8,11 @@ pub const VERSION: &str = "0.0.1";
 /// Version display component
 #[component]
 pub fn Version() -> impl IntoView {
+    let hash_cls = apply_version_style();
+    let cls = get_component_cls("version");
+
     view! {
-        <span class="ant-version">
+        <span class=move || format!("{} {}", cls, hash_cls.get())>
             {"ant-leptos v"}{VERSION}
         </span>
     }
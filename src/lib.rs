@@ -10,8 +10,12 @@ pub mod utils;
 
 // Re-export commonly used components
 pub use components::{
+    button::Button,
     config_provider::ConfigProvider,
+    form::{Form, FormItem, FormList},
+    icon::Icon,
     locale::LocaleProvider,
+    modal::Modal,
     style::Style,
     theme::Theme, // 现在这个导入应该是正确的
     version::Version,
@@ -0,0 +1,510 @@
+//! 基于令牌的样式生成
+//!
+//! 对应 antd 的 `genComponentStyleHook`：每个组件声明一个从主题令牌与组件令牌
+//! 到样式规则的函数，首次使用时才生成 CSS，同一令牌哈希的样式表在注册表中
+//! 按引用计数共享，不再使用时一并释放。组件样式的选择器
+//! 会带上哈希类名，嵌套的 ConfigProvider 使用不同主题时互不影响。
+
+use crate::components::config_provider::{
+    theme::ComponentToken, ComponentTokenType, ConfigContext, DesignToken, DesignTokenContext,
+    Direction, DirectionContext, ThemeConfig, ThemeToken, ANT_ICON_PREFIX, ANT_PREFIX,
+};
+use crate::utils::hash::hash_str;
+use crate::utils::style::use_dynamic_style;
+use leptos::prelude::*;
+use serde_json::Value;
+use std::fmt::Display;
+
+/// A CSS rule with declarations and nested rules
+///
+/// Nested selectors containing `&` replace it with the parent selector,
+/// other nested selectors are treated as descendants. At-rules such as
+/// `@keyframes` wrap their nested rules instead.
+///
+/// Rules are written for left-to-right; inside a right-to-left subtree the
+/// physical properties are flipped automatically unless [`StyleRule::no_flip`]
+/// is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyleRule {
+    selector: String,
+    declarations: Vec<(String, String)>,
+    children: Vec<StyleRule>,
+    no_flip: bool,
+}
+
+impl StyleRule {
+    /// Create an empty rule for a selector
+    pub fn new(selector: impl Into<String>) -> Self {
+        Self {
+            selector: selector.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a declaration
+    pub fn prop(mut self, name: &str, value: impl Display) -> Self {
+        self.declarations
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add a declaration in pixels
+    pub fn px(self, name: &str, value: f64) -> Self {
+        self.prop(name, format!("{}px", value))
+    }
+
+    /// Add a nested rule
+    pub fn nest(mut self, rule: StyleRule) -> Self {
+        self.children.push(rule);
+        self
+    }
+
+    /// Keep this rule and its nested rules unchanged in right-to-left subtrees
+    pub fn no_flip(mut self) -> Self {
+        self.no_flip = true;
+        self
+    }
+
+    /// Mirror the rule horizontally for right-to-left layouts
+    ///
+    /// Swaps left and right in property names and in `text-align`, `float`
+    /// and similar values, reorders four-sided shorthands and border radii,
+    /// and negates horizontal translations.
+    pub fn flip(mut self) -> Self {
+        if self.no_flip {
+            return self;
+        }
+        self.declarations = self
+            .declarations
+            .into_iter()
+            .map(|(name, value)| flip_declaration(&name, &value))
+            .collect();
+        self.children = self.children.into_iter().map(StyleRule::flip).collect();
+        self
+    }
+
+    /// Render the rule and its nested rules
+    pub fn to_css(&self) -> String {
+        let mut css = String::new();
+        self.write_css(&self.selector, &mut css);
+        css
+    }
+
+    fn write_css(&self, selector: &str, css: &mut String) {
+        if selector.starts_with('@') {
+            css.push_str(selector);
+            css.push('{');
+            for child in &self.children {
+                child.write_css(&child.selector, css);
+            }
+            css.push('}');
+            return;
+        }
+
+        if !self.declarations.is_empty() {
+            css.push_str(selector);
+            css.push('{');
+            for (name, value) in &self.declarations {
+                css.push_str(&format!("{}:{};", name, value));
+            }
+            css.push('}');
+        }
+
+        for child in &self.children {
+            child.write_css(&nest_selector(selector, &child.selector), css);
+        }
+    }
+
+    fn with_hash(mut self, hash_cls: &str) -> Self {
+        self.selector = self
+            .selector
+            .split(',')
+            .map(|selector| {
+                let selector = selector.trim();
+                if selector.starts_with('.') {
+                    format!(":where(.{}){}", hash_cls, selector)
+                } else {
+                    selector.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        self
+    }
+}
+
+fn nest_selector(parent: &str, child: &str) -> String {
+    parent
+        .split(',')
+        .flat_map(|parent| {
+            let parent = parent.trim();
+            child.split(',').map(move |child| {
+                let child = child.trim();
+                if child.contains('&') {
+                    child.replace('&', parent)
+                } else {
+                    format!("{} {}", parent, child)
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn swap_side(word: &str) -> &str {
+    match word {
+        "left" => "right",
+        "right" => "left",
+        word => word,
+    }
+}
+
+/// Split a value on top-level whitespace, keeping parenthesized groups intact
+fn split_value(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for ch in value.chars() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if ch.is_whitespace() && depth == 0 {
+            if !current.is_empty() {
+                parts.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(ch);
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+fn negate(value: &str) -> String {
+    let value = value.trim();
+    if let Some(positive) = value.strip_prefix('-') {
+        positive.to_string()
+    } else if value
+        .trim_start_matches(['0', '.'])
+        .chars()
+        .all(char::is_alphabetic)
+    {
+        // 0、0px 等零值无需取反
+        value.to_string()
+    } else if value.contains('(') {
+        format!("calc(-1 * {})", value)
+    } else {
+        format!("-{}", value)
+    }
+}
+
+/// Negate the horizontal part of `translate`/`translateX` functions
+fn flip_transform(value: &str) -> String {
+    split_value(value)
+        .into_iter()
+        .map(|part| {
+            let Some((function, args)) =
+                part.strip_suffix(')').and_then(|part| part.split_once('('))
+            else {
+                return part;
+            };
+            match function {
+                "translateX" => format!("translateX({})", negate(args)),
+                "translate" | "translate3d" => {
+                    let mut args: Vec<String> =
+                        args.split(',').map(|arg| arg.trim().to_string()).collect();
+                    args[0] = negate(&args[0]);
+                    format!("{}({})", function, args.join(", "))
+                }
+                _ => part,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn flip_declaration(name: &str, value: &str) -> (String, String) {
+    // 自定义属性由使用方决定含义，名称和值都保持不变
+    if name.starts_with("--") {
+        return (name.to_string(), value.to_string());
+    }
+    let name = name.split('-').map(swap_side).collect::<Vec<_>>().join("-");
+
+    let parts = split_value(value);
+    let value = match name.as_str() {
+        "text-align" | "float" | "clear" | "transform-origin" | "background-position" => parts
+            .iter()
+            .map(|part| swap_side(part))
+            .collect::<Vec<_>>()
+            .join(" "),
+        "margin" | "padding" | "border-width" | "border-color" | "border-style" | "inset"
+            if parts.len() == 4 =>
+        {
+            [&parts[0], &parts[3], &parts[2], &parts[1]]
+                .map(String::as_str)
+                .join(" ")
+        }
+        "border-radius" if !value.contains('/') => match parts.len() {
+            2 => format!("{} {}", parts[1], parts[0]),
+            3 => format!("{} {} {} {}", parts[1], parts[0], parts[1], parts[2]),
+            4 => format!("{} {} {} {}", parts[1], parts[0], parts[3], parts[2]),
+            _ => value.to_string(),
+        },
+        "transform" => flip_transform(value),
+        _ => value.to_string(),
+    };
+    (name, value)
+}
+
+/// Inputs of a component style function
+#[derive(Clone, Copy, Debug)]
+pub struct StyleInfo<'a> {
+    /// Resolved theme token
+    pub token: &'a ThemeToken,
+    /// Theme configuration the token was derived from
+    pub theme: &'a ThemeConfig,
+    /// Component token overrides from `ThemeConfig.components`
+    pub component_token: Option<&'a ComponentToken>,
+    /// Global class prefix, e.g. `ant`
+    pub prefix_cls: &'a str,
+    /// Icon class prefix, e.g. `anticon`
+    pub icon_prefix_cls: &'a str,
+    /// Component class, e.g. `ant-version`
+    pub component_cls: &'a str,
+    /// Direction of the subtree; rules are flipped after generation for RTL
+    pub direction: Direction,
+}
+
+impl StyleInfo<'_> {
+    /// Typed component token, derived from the global token and overrides
+    pub fn component<T: ComponentTokenType>(&self) -> T {
+        self.theme.component_token::<T>(self.token)
+    }
+
+    /// Raw component token value, or `default` when not overridden
+    pub fn component_value(&self, key: &str, default: impl Into<String>) -> String {
+        self.component_token
+            .and_then(|component| component.token.get(key))
+            .map(|value| match value {
+                Value::String(value) => value.clone(),
+                other => other.to_string(),
+            })
+            .unwrap_or_else(|| default.into())
+    }
+}
+
+/// Function generating the style rules of a component
+pub type StyleFn = fn(&StyleInfo) -> Vec<StyleRule>;
+
+/// Class prefixes of the current subtree
+#[derive(Clone, Debug, PartialEq)]
+struct Prefixes {
+    cls: String,
+    icon: String,
+}
+
+fn resolve_design() -> (DesignToken, Prefixes, Direction) {
+    let design = use_context::<DesignTokenContext>()
+        .map(|design| design.get())
+        .unwrap_or_default();
+    let prefix_cls = use_context::<ConfigContext>()
+        .map(|config| {
+            config.with(|config| Prefixes {
+                cls: config.prefix_cls.clone(),
+                icon: config.icon_prefix_cls.clone(),
+            })
+        })
+        .unwrap_or_else(|| Prefixes {
+            cls: ANT_PREFIX.to_string(),
+            icon: ANT_ICON_PREFIX.to_string(),
+        });
+    let direction = use_context::<DirectionContext>()
+        .map(|direction| direction.get())
+        .unwrap_or(Direction::Ltr);
+    (design, prefix_cls, direction)
+}
+
+fn style_hash(
+    design: &DesignToken,
+    component: &str,
+    prefix_cls: &Prefixes,
+    direction: Direction,
+) -> String {
+    let component_token = design
+        .theme
+        .components
+        .get(component)
+        .map(|component| format!("{}|{:?}", component.algorithm, component.token))
+        .unwrap_or_default();
+    hash_str(&format!(
+        "{}|{}|{}|{}|{}|{}",
+        prefix_cls.cls,
+        prefix_cls.icon,
+        component,
+        design.hash,
+        component_token,
+        direction.as_str()
+    ))
+}
+
+fn hash_class(prefix_cls: &str, hash: &str) -> String {
+    format!("{}-css-{}", prefix_cls, hash)
+}
+
+fn generate(
+    design: &DesignToken,
+    component: &str,
+    prefix_cls: &Prefixes,
+    hash_cls: Option<&str>,
+    direction: Direction,
+    style: StyleFn,
+) -> String {
+    let component_cls = format!("{}-{}", prefix_cls.cls, component);
+    let info = StyleInfo {
+        token: &design.token,
+        theme: &design.theme,
+        component_token: design.theme.components.get(component),
+        prefix_cls: &prefix_cls.cls,
+        icon_prefix_cls: &prefix_cls.icon,
+        component_cls: &component_cls,
+        direction,
+    };
+    style(&info)
+        .into_iter()
+        .map(|rule| match direction {
+            Direction::Rtl => rule.flip(),
+            Direction::Ltr => rule,
+        })
+        .map(|rule| match hash_cls {
+            Some(hash_cls) => rule.with_hash(hash_cls).to_css(),
+            None => rule.to_css(),
+        })
+        .collect()
+}
+
+/// Register a component's token-driven style
+///
+/// Returns the hash class the component must add to its root element; the
+/// generated selectors only match inside it. The hash covers the direction
+/// too, so right-to-left subtrees get their own flipped rules.
+pub fn use_component_style(component: &'static str, style: StyleFn) -> Signal<String> {
+    let hash_cls = RwSignal::new(untrack(|| {
+        let (design, prefix_cls, direction) = resolve_design();
+        hash_class(
+            &prefix_cls.cls,
+            &style_hash(&design, component, &prefix_cls, direction),
+        )
+    }));
+
+    use_dynamic_style(component, move || {
+        let (design, prefix_cls, direction) = resolve_design();
+        let hash = style_hash(&design, component, &prefix_cls, direction);
+        let cls = hash_class(&prefix_cls.cls, &hash);
+        if hash_cls.get_untracked() != cls {
+            hash_cls.set(cls.clone());
+        }
+
+        Some((hash, move || {
+            generate(
+                &design,
+                component,
+                &prefix_cls,
+                Some(&cls),
+                direction,
+                style,
+            )
+        }))
+    });
+
+    hash_cls.into()
+}
+
+/// Register a token-driven style that applies to the whole document
+///
+/// Global rules are not scoped to a subtree and are therefore never flipped.
+pub fn use_global_style(name: &'static str, style: StyleFn) {
+    use_dynamic_style(name, move || {
+        let (design, prefix_cls, _) = resolve_design();
+        let hash = style_hash(&design, name, &prefix_cls, Direction::Ltr);
+        Some((hash, move || {
+            generate(&design, name, &prefix_cls, None, Direction::Ltr, style)
+        }))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flip_declaration_mirrors_horizontal_values() {
+        let cases = [
+            ("border-radius", "2px 4px", "border-radius", "4px 2px"),
+            (
+                "border-radius",
+                "1px 2px 3px",
+                "border-radius",
+                "2px 1px 2px 3px",
+            ),
+            (
+                "border-radius",
+                "1px 2px 3px 4px",
+                "border-radius",
+                "2px 1px 4px 3px",
+            ),
+            (
+                "border-radius",
+                "1px 2px / 3px",
+                "border-radius",
+                "1px 2px / 3px",
+            ),
+            ("margin", "1px 2px 3px 4px", "margin", "1px 4px 3px 2px"),
+            ("padding", "0 8px 0 16px", "padding", "0 16px 0 8px"),
+            ("inset", "0 auto 0 0", "inset", "0 0 0 auto"),
+            ("margin", "0 8px", "margin", "0 8px"),
+            ("margin-left", "8px", "margin-right", "8px"),
+            (
+                "border-top-left-radius",
+                "2px",
+                "border-top-right-radius",
+                "2px",
+            ),
+            (
+                "transform",
+                "translateX(10px)",
+                "transform",
+                "translateX(-10px)",
+            ),
+            (
+                "transform",
+                "translate(-50%, -50%) rotate(45deg)",
+                "transform",
+                "translate(50%, -50%) rotate(45deg)",
+            ),
+            (
+                "transform",
+                "translateX(var(--ant-offset))",
+                "transform",
+                "translateX(calc(-1 * var(--ant-offset)))",
+            ),
+            ("transform", "translateX(0)", "transform", "translateX(0)"),
+            ("text-align", "left", "text-align", "right"),
+            ("text-align", "center", "text-align", "center"),
+            ("float", "right", "float", "left"),
+            ("--ant-padding-left", "8px", "--ant-padding-left", "8px"),
+            ("--ant-align", "left", "--ant-align", "left"),
+        ];
+
+        for (name, value, flipped_name, flipped_value) in cases {
+            assert_eq!(
+                flip_declaration(name, value),
+                (flipped_name.to_string(), flipped_value.to_string()),
+                "{name}: {value}"
+            );
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use crate::components::config_provider::ButtonToken;
+use crate::styles::builder::{use_component_style, StyleInfo, StyleRule};
+use leptos::prelude::*;
+
+/// Colors of a button variant in one state
+#[derive(Default)]
+struct Look<'a> {
+    color: Option<&'a str>,
+    background: Option<&'a str>,
+    border_color: Option<&'a str>,
+    shadow: Option<&'a str>,
+}
+
+impl Look<'_> {
+    fn apply(&self, mut rule: StyleRule) -> StyleRule {
+        for (name, value) in [
+            ("color", self.color),
+            ("background", self.background),
+            ("border-color", self.border_color),
+            ("box-shadow", self.shadow),
+        ] {
+            if let Some(value) = value {
+                rule = rule.prop(name, value);
+            }
+        }
+        rule
+    }
+}
+
+/// A variant rule with its hover, active and disabled states
+fn variant(cls: &str, selector: String, states: [&Look; 4]) -> StyleRule {
+    let [base, hover, active, disabled] = states;
+    let enabled = format!("&:not(:disabled):not({}-disabled)", cls);
+    base.apply(StyleRule::new(selector))
+        .nest(hover.apply(StyleRule::new(format!("{}:hover", enabled))))
+        .nest(active.apply(StyleRule::new(format!("{}:active", enabled))))
+        .nest(disabled.apply(StyleRule::new(format!("&:disabled, &{}-disabled", cls))))
+}
+
+/// Generates the button styles
+pub fn gen_button_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    let button = info.component::<ButtonToken>();
+    let cls = format!(".{}", info.component_cls);
+    let colors = &token.map.colors;
+    let alias = &token.alias;
+    let height = &token.map.height;
+    let common = &token.map.common;
+    let wave_color = format!("--{}-wave-color", info.prefix_cls);
+    let wave_cls = format!(".{}-wave", info.prefix_cls);
+
+    let size =
+        |suffix: &str, height: f64, padding: f64, font_size: f64, icon_size: f64, radius: f64| {
+            StyleRule::new(format!("{}{}", cls, suffix))
+                .px("height", height)
+                .prop("padding", format!("0 {}px", padding))
+                .px("font-size", font_size)
+                .px("border-radius", radius)
+                .nest(
+                    StyleRule::new(format!("&{}-icon-only", cls))
+                        .px("width", height)
+                        .prop("padding-inline", "0")
+                        .px("font-size", icon_size),
+                )
+                .nest(
+                    StyleRule::new(format!("&{}-circle", cls))
+                        .px("min-width", height)
+                        .prop("padding-inline", "0")
+                        .prop("border-radius", "50%"),
+                )
+                .nest(StyleRule::new(format!("&{}-round", cls)).px("border-radius", height / 2.0))
+        };
+
+    let bordered_disabled = Look {
+        color: Some(&alias.color_text_disabled),
+        background: Some(&alias.color_bg_container_disabled),
+        border_color: Some(&button.border_color_disabled),
+        shadow: Some("none"),
+    };
+    let borderless_disabled = Look {
+        color: Some(&alias.color_text_disabled),
+        background: Some("transparent"),
+        border_color: Some("transparent"),
+        shadow: Some("none"),
+    };
+
+    let default_look = Look {
+        color: Some(&button.default_color),
+        background: Some(&button.default_bg),
+        border_color: Some(&button.default_border_color),
+        shadow: Some(&button.default_shadow),
+    };
+    let default_hover = Look {
+        color: Some(&button.default_hover_color),
+        background: Some(&button.default_hover_bg),
+        border_color: Some(&button.default_hover_border_color),
+        ..Default::default()
+    };
+    let default_active = Look {
+        color: Some(&button.default_active_color),
+        background: Some(&button.default_active_bg),
+        border_color: Some(&button.default_active_border_color),
+        ..Default::default()
+    };
+    let default_danger = Look {
+        color: Some(&colors.color_error),
+        border_color: Some(&colors.color_error),
+        shadow: Some(&button.danger_shadow),
+        ..Default::default()
+    };
+    let default_danger_hover = Look {
+        color: Some(&colors.color_error_hover),
+        border_color: Some(&colors.color_error_border_hover),
+        ..Default::default()
+    };
+    let default_danger_active = Look {
+        color: Some(&colors.color_error_active),
+        border_color: Some(&colors.color_error_active),
+        ..Default::default()
+    };
+
+    let primary_look = Look {
+        color: Some(&button.primary_color),
+        background: Some(&colors.color_primary),
+        border_color: Some("transparent"),
+        shadow: Some(&button.primary_shadow),
+    };
+    let primary_hover = Look {
+        background: Some(&colors.color_primary_hover),
+        ..Default::default()
+    };
+    let primary_active = Look {
+        background: Some(&colors.color_primary_active),
+        ..Default::default()
+    };
+    let primary_danger = Look {
+        color: Some(&button.danger_color),
+        background: Some(&colors.color_error),
+        shadow: Some(&button.danger_shadow),
+        ..Default::default()
+    };
+    let primary_danger_hover = Look {
+        background: Some(&colors.color_error_hover),
+        ..Default::default()
+    };
+    let primary_danger_active = Look {
+        background: Some(&colors.color_error_active),
+        ..Default::default()
+    };
+
+    let text_look = Look {
+        color: Some(&colors.color_text),
+        background: Some("transparent"),
+        border_color: Some("transparent"),
+        shadow: Some("none"),
+    };
+    let text_hover = Look {
+        background: Some(&button.text_hover_bg),
+        ..Default::default()
+    };
+    let text_active = Look {
+        background: Some(&alias.color_bg_text_active),
+        ..Default::default()
+    };
+    let text_danger = Look {
+        color: Some(&colors.color_error),
+        ..Default::default()
+    };
+    let text_danger_hover = Look {
+        color: Some(&colors.color_error_hover),
+        background: Some(&colors.color_error_bg),
+        ..Default::default()
+    };
+    let text_danger_active = Look {
+        color: Some(&colors.color_error_active),
+        background: Some(&colors.color_error_bg_active),
+        ..Default::default()
+    };
+
+    let link_look = Look {
+        color: Some(&colors.color_link),
+        background: Some("transparent"),
+        border_color: Some("transparent"),
+        shadow: Some("none"),
+    };
+    let link_hover = Look {
+        color: Some(&colors.color_link_hover),
+        background: Some(&button.link_hover_bg),
+        ..Default::default()
+    };
+    let link_active = Look {
+        color: Some(&colors.color_link_active),
+        ..Default::default()
+    };
+    let link_danger_hover = Look {
+        color: Some(&colors.color_error_hover),
+        ..Default::default()
+    };
+    let link_danger_active = Look {
+        color: Some(&colors.color_error_active),
+        ..Default::default()
+    };
+
+    let ghost_look = Look {
+        color: Some(&button.default_ghost_color),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&button.default_ghost_border_color),
+        shadow: Some("none"),
+    };
+    let ghost_primary = Look {
+        color: Some(&colors.color_primary),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&colors.color_primary),
+        shadow: Some("none"),
+    };
+    let ghost_primary_hover = Look {
+        color: Some(&colors.color_primary_hover),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&colors.color_primary_hover),
+        ..Default::default()
+    };
+    let ghost_primary_active = Look {
+        color: Some(&colors.color_primary_active),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&colors.color_primary_active),
+        ..Default::default()
+    };
+    let ghost_danger = Look {
+        color: Some(&colors.color_error),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&colors.color_error),
+        shadow: Some("none"),
+    };
+    let ghost_danger_hover = Look {
+        color: Some(&colors.color_error_hover),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&colors.color_error_hover),
+        ..Default::default()
+    };
+    let ghost_danger_active = Look {
+        color: Some(&colors.color_error_active),
+        background: Some(&button.ghost_bg),
+        border_color: Some(&colors.color_error_active),
+        ..Default::default()
+    };
+    let default_selector = format!("{0}-default, {0}-dashed", cls);
+    let default_danger_selector = format!("{0}-default{0}-dangerous, {0}-dashed{0}-dangerous", cls);
+
+    vec![
+        StyleRule::new(cls.clone())
+            .prop("outline", "none")
+            .prop("position", "relative")
+            .prop("display", "inline-flex")
+            .prop("gap", format!("{}px", alias.margin_xs))
+            .prop("align-items", "center")
+            .prop("justify-content", "center")
+            .prop("font-weight", button.font_weight)
+            .prop("white-space", "nowrap")
+            .prop("text-align", "center")
+            .prop("text-decoration", "none")
+            .prop("background-image", "none")
+            .prop("background", "transparent")
+            .prop(
+                "border",
+                format!(
+                    "{}px {} transparent",
+                    token.seed.line_width, token.seed.line_type
+                ),
+            )
+            .prop("cursor", "pointer")
+            .prop(
+                "transition",
+                format!(
+                    "all {} {}",
+                    common.motion_duration_mid, token.seed.motion_ease_in_out
+                ),
+            )
+            .prop("user-select", "none")
+            .prop("touch-action", "manipulation")
+            .prop("line-height", token.map.font.line_height)
+            .prop(&wave_color, &colors.color_primary)
+            .nest(StyleRule::new("> span").prop("display", "inline-flex"))
+            .nest(StyleRule::new(format!("&{}-block", cls)).prop("width", "100%"))
+            .nest(
+                StyleRule::new(format!("&{}-dangerous", cls))
+                    .prop(&wave_color, &colors.color_error),
+            )
+            .nest(
+                StyleRule::new(format!("&{}-loading", cls))
+                    .prop("opacity", alias.opacity_loading)
+                    .prop("cursor", "default"),
+            )
+            .nest(
+                StyleRule::new(format!(
+                    "&{}-two-chinese-chars > span:not({}-icon)",
+                    cls, cls
+                ))
+                .prop("letter-spacing", "0.34em")
+                .prop("margin-inline-end", "-0.34em"),
+            )
+            .nest(
+                StyleRule::new("&:disabled, &[aria-disabled='true']").prop("cursor", "not-allowed"),
+            ),
+        size(
+            "",
+            height.control_height,
+            button.padding_inline,
+            button.content_font_size,
+            button.only_icon_size,
+            common.border_radius,
+        ),
+        size(
+            "-sm",
+            height.control_height_sm,
+            button.padding_inline_sm,
+            button.content_font_size_sm,
+            button.only_icon_size_sm,
+            common.border_radius_sm,
+        ),
+        size(
+            "-lg",
+            height.control_height_lg,
+            button.padding_inline_lg,
+            button.content_font_size_lg,
+            button.only_icon_size_lg,
+            common.border_radius_lg,
+        ),
+        // 默认与虚线按钮
+        variant(
+            &cls,
+            default_selector,
+            [
+                &default_look,
+                &default_hover,
+                &default_active,
+                &bordered_disabled,
+            ],
+        ),
+        StyleRule::new(format!("{}-dashed", cls)).prop("border-style", "dashed"),
+        variant(
+            &cls,
+            default_danger_selector,
+            [
+                &default_danger,
+                &default_danger_hover,
+                &default_danger_active,
+                &bordered_disabled,
+            ],
+        ),
+        // 主按钮
+        variant(
+            &cls,
+            format!("{}-primary", cls),
+            [
+                &primary_look,
+                &primary_hover,
+                &primary_active,
+                &bordered_disabled,
+            ],
+        ),
+        variant(
+            &cls,
+            format!("{0}-primary{0}-dangerous", cls),
+            [
+                &primary_danger,
+                &primary_danger_hover,
+                &primary_danger_active,
+                &bordered_disabled,
+            ],
+        ),
+        // 文本与链接按钮
+        variant(
+            &cls,
+            format!("{}-text", cls),
+            [&text_look, &text_hover, &text_active, &borderless_disabled],
+        ),
+        variant(
+            &cls,
+            format!("{0}-text{0}-dangerous", cls),
+            [
+                &text_danger,
+                &text_danger_hover,
+                &text_danger_active,
+                &borderless_disabled,
+            ],
+        ),
+        variant(
+            &cls,
+            format!("{}-link", cls),
+            [&link_look, &link_hover, &link_active, &borderless_disabled],
+        ),
+        variant(
+            &cls,
+            format!("{0}-link{0}-dangerous", cls),
+            [
+                &text_danger,
+                &link_danger_hover,
+                &link_danger_active,
+                &borderless_disabled,
+            ],
+        ),
+        // 幽灵按钮
+        variant(
+            &cls,
+            format!(
+                "{0}-background-ghost{0}-default, {0}-background-ghost{0}-dashed",
+                cls
+            ),
+            [
+                &ghost_look,
+                &ghost_primary_hover,
+                &ghost_primary_active,
+                &bordered_disabled,
+            ],
+        ),
+        variant(
+            &cls,
+            format!("{0}-background-ghost{0}-primary", cls),
+            [
+                &ghost_primary,
+                &ghost_primary_hover,
+                &ghost_primary_active,
+                &bordered_disabled,
+            ],
+        ),
+        variant(
+            &cls,
+            format!("{0}-background-ghost{0}-dangerous", cls),
+            [
+                &ghost_danger,
+                &ghost_danger_hover,
+                &ghost_danger_active,
+                &bordered_disabled,
+            ],
+        ),
+        // 点击波纹
+        StyleRule::new(format!("{} {}", cls, wave_cls))
+            .prop("position", "absolute")
+            .px("inset", -token.seed.line_width)
+            .prop("border-radius", "inherit")
+            .prop("pointer-events", "none")
+            .prop("opacity", "0.2")
+            .prop("box-shadow", format!("0 0 0 0 var({})", wave_color))
+            .prop("transition-property", "box-shadow, opacity")
+            .nest(
+                StyleRule::new("&-active")
+                    .prop("opacity", "0")
+                    .prop("box-shadow", format!("0 0 0 6px var({})", wave_color)),
+            ),
+        StyleRule::new(format!("{0}-loading, {0}:disabled", cls))
+            .nest(StyleRule::new(wave_cls).prop("display", "none")),
+    ]
+}
+
+/// Applies styles for the button component, returning its hash class
+pub fn apply_button_style() -> Signal<String> {
+    use_component_style("btn", gen_button_style)
+}
@@ -1,40 +1,25 @@
-use crate::utils::style::create_style_sheet;
+use crate::styles::builder::{use_global_style, StyleInfo, StyleRule};
 
-/// Applies the configuration provider styles
-pub fn apply_styles() {
-    let style = r#"
-        .ant-config-provider {
-            width: 100%;
-            height: 100%;
-            display: contents;
-        }
-
-        .ant-config-provider-disabled * {
-            cursor: not-allowed !important;
-            user-select: none !important;
-            pointer-events: none !important;
-        }
-
-        .ant-config-provider-sm {
-            font-size: 12px;
-        }
+/// Generates the configuration provider styles
+pub fn gen_config_provider_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    let cls = format!(".{}", info.component_cls);
 
-        .ant-config-provider-lg {
-            font-size: 16px;
-        }
-
-        [data-theme='dark'] {
-            --ant-primary-color: #177ddc;
-            --ant-success-color: #49aa19;
-            --ant-warning-color: #d89614;
-            --ant-error-color: #a61d24;
-            color-scheme: dark;
-        }
+    vec![
+        StyleRule::new(cls.clone())
+            .prop("width", "100%")
+            .prop("height", "100%")
+            .prop("display", "contents"),
+        // 控件通过 DisabledContext 设置真实的 disabled 属性，这里只补充光标
+        StyleRule::new(format!("{}-disabled [disabled]", cls)).prop("cursor", "not-allowed"),
+        StyleRule::new(format!("{}-sm", cls)).px("font-size", token.map.font.font_size_sm),
+        StyleRule::new(format!("{}-lg", cls)).px("font-size", token.map.font.font_size_lg),
+        // 颜色由 Theme 切换的暗色算法生成，这里只让滚动条等原生控件跟随
+        StyleRule::new("[data-theme='dark']").prop("color-scheme", "dark"),
+    ]
+}
 
-        [data-theme='compact'] {
-            --ant-font-size-base: 12px;
-            --ant-line-height-base: 1.66667;
-        }
-    "#;
-    create_style_sheet("config-provider", style);
+/// Applies the configuration provider styles
+pub fn apply_styles() {
+    use_global_style("config-provider", gen_config_provider_style);
 }
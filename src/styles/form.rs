@@ -0,0 +1,174 @@
+use crate::components::config_provider::FormToken;
+use crate::styles::builder::{use_component_style, StyleInfo, StyleRule};
+use leptos::prelude::*;
+
+/// Generates the form styles
+///
+/// Items carry the hash class themselves, so the item rules also apply to
+/// items rendered outside of a `Form`.
+pub fn gen_form_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    let form = info.component::<FormToken>();
+    let cls = format!(".{}", info.component_cls);
+    let item = format!("{}-item", cls);
+    let colors = &token.map.colors;
+    let alias = &token.alias;
+    let font = &token.map.font;
+    let height = &token.map.height;
+
+    let label_height = |suffix: &str, height: f64| {
+        StyleRule::new(format!("{}{} {}-label > label", cls, suffix, item)).px("height", height)
+    };
+
+    vec![
+        StyleRule::new(cls.clone())
+            .prop("box-sizing", "border-box")
+            .prop("margin", "0")
+            .prop("padding", "0")
+            .prop("color", &colors.color_text)
+            .px("font-size", font.font_size)
+            .prop("line-height", font.line_height)
+            .prop("list-style", "none"),
+        StyleRule::new(item.clone())
+            .prop("box-sizing", "border-box")
+            .prop("margin", "0")
+            .px("margin-bottom", form.item_margin_bottom)
+            .prop("padding", "0")
+            .prop("color", &colors.color_text)
+            .px("font-size", font.font_size)
+            .prop("vertical-align", "top")
+            .nest(
+                StyleRule::new(format!("{}-row", item))
+                    .prop("display", "flex")
+                    .prop("flex-flow", "row nowrap"),
+            )
+            .nest(
+                StyleRule::new(format!("{}-label", item))
+                    .prop("flex", "0 0 auto")
+                    .prop("overflow", "hidden")
+                    .prop("white-space", "nowrap")
+                    .prop("text-align", "end")
+                    .prop("vertical-align", "middle")
+                    .nest(
+                        StyleRule::new("> label")
+                            .prop("position", "relative")
+                            .prop("display", "inline-flex")
+                            .prop("align-items", "center")
+                            .prop("max-width", "100%")
+                            .px("height", form.label_height)
+                            .prop("color", &form.label_color)
+                            .px("font-size", form.label_font_size)
+                            .nest(
+                                StyleRule::new(format!(
+                                    "&{0}-required:not({0}-required-mark-optional)::before",
+                                    item
+                                ))
+                                .prop("display", "inline-block")
+                                .px("margin-inline-end", alias.margin_xxs)
+                                .prop("color", &form.label_required_mark_color)
+                                .px("font-size", font.font_size)
+                                .prop("font-family", "SimSun, sans-serif")
+                                .prop("line-height", "1")
+                                .prop("content", "\"*\""),
+                            )
+                            .nest(
+                                StyleRule::new("&::after")
+                                    .prop("content", "\":\"")
+                                    .prop("position", "relative")
+                                    .prop("margin-block", "0")
+                                    .px("margin-inline-start", form.label_colon_margin_inline_start)
+                                    .px("margin-inline-end", form.label_colon_margin_inline_end),
+                            )
+                            .nest(
+                                StyleRule::new(format!("&{}-no-colon::after", item))
+                                    .prop("content", "\"\\a0\""),
+                            ),
+                    )
+                    .nest(
+                        StyleRule::new(format!("{}-optional", item))
+                            .prop("display", "inline-block")
+                            .px("margin-inline-start", alias.margin_xxs)
+                            .prop("color", &alias.color_text_description),
+                    ),
+            )
+            .nest(
+                StyleRule::new(format!("{}-control", item))
+                    .prop("display", "flex")
+                    .prop("flex-direction", "column")
+                    .prop("flex", "1 1 0")
+                    .prop("min-width", "0"),
+            )
+            .nest(
+                StyleRule::new(format!("{}-control-input", item))
+                    .prop("position", "relative")
+                    .prop("display", "flex")
+                    .prop("align-items", "center")
+                    .px("min-height", height.control_height),
+            )
+            .nest(
+                StyleRule::new(format!("{}-control-input-content", item))
+                    .prop("flex", "auto")
+                    .prop("max-width", "100%"),
+            )
+            .nest(
+                StyleRule::new(format!("{0}-explain, {0}-extra", item))
+                    .prop("clear", "both")
+                    .prop("color", &alias.color_text_description)
+                    .px("font-size", font.font_size)
+                    .prop("line-height", font.line_height),
+            )
+            .nest(
+                StyleRule::new(format!("{}-explain-error", item))
+                    .prop("color", &colors.color_error),
+            )
+            .nest(
+                StyleRule::new(format!("{}-explain-warning", item))
+                    .prop("color", &colors.color_warning),
+            )
+            .nest(
+                StyleRule::new(format!("{}-extra", item)).px("min-height", form.item_margin_bottom),
+            ),
+        StyleRule::new(format!(
+            "{}-hide-required-mark {}-required::before",
+            cls, item
+        ))
+        .prop("display", "none"),
+        label_height("-small", height.control_height_sm),
+        label_height("-large", height.control_height_lg),
+        StyleRule::new(format!("{}-vertical", cls))
+            .nest(StyleRule::new(format!("{}-row", item)).prop("flex-direction", "column"))
+            .nest(
+                StyleRule::new(format!("{}-label", item))
+                    .prop("padding", &form.vertical_label_padding)
+                    .px("margin", form.vertical_label_margin)
+                    .prop("line-height", font.line_height)
+                    .prop("white-space", "initial")
+                    .prop("text-align", "start")
+                    .nest(
+                        StyleRule::new("> label")
+                            .prop("margin", "0")
+                            .prop("height", "auto")
+                            .nest(StyleRule::new("&::after").prop("visibility", "hidden")),
+                    ),
+            ),
+        StyleRule::new(format!("{}-inline", cls))
+            .prop("display", "flex")
+            .prop("flex-wrap", "wrap")
+            .nest(
+                StyleRule::new(item.clone())
+                    .prop("flex", "none")
+                    .px("margin-inline-end", alias.margin)
+                    .px("margin-bottom", form.inline_item_margin_bottom),
+            )
+            .nest(
+                StyleRule::new(format!("{}-control", item))
+                    .prop("flex", "none")
+                    .prop("min-width", "0"),
+            ),
+    ]
+}
+
+/// Applies styles for the form components, returning their hash class
+pub fn apply_form_style() -> Signal<String> {
+    use_component_style("form", gen_form_style)
+}
@@ -0,0 +1,37 @@
+use crate::styles::builder::{use_component_style, StyleInfo, StyleRule};
+use leptos::prelude::*;
+
+/// Generates the icon styles
+///
+/// Icons use `Config.icon_prefix_cls` rather than the component prefix.
+pub fn gen_icon_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let cls = format!(".{}", info.icon_prefix_cls);
+    let keyframes = format!("{}-loading-circle", info.icon_prefix_cls);
+    vec![
+        StyleRule::new(cls.clone())
+            .prop("display", "inline-flex")
+            .prop("align-items", "center")
+            .prop("color", "inherit")
+            .prop("font-style", "normal")
+            .prop("line-height", "0")
+            .prop("text-align", "center")
+            .prop("text-transform", "none")
+            .prop("vertical-align", "-0.125em")
+            .prop("text-rendering", "optimizeLegibility")
+            .prop("-webkit-font-smoothing", "antialiased")
+            .nest(StyleRule::new("> *").prop("line-height", "1"))
+            .nest(StyleRule::new("svg").prop("display", "inline-block")),
+        StyleRule::new(format!("{}-spin", cls))
+            .prop("display", "inline-block")
+            .prop("animation", format!("{} 1s infinite linear", keyframes))
+            .no_flip(),
+        StyleRule::new(format!("@keyframes {}", keyframes))
+            .nest(StyleRule::new("100%").prop("transform", "rotate(360deg)"))
+            .no_flip(),
+    ]
+}
+
+/// Applies styles for the icon component, returning its hash class
+pub fn apply_icon_style() -> Signal<String> {
+    use_component_style("icon", gen_icon_style)
+}
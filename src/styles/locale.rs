@@ -1,36 +1,47 @@
-use crate::utils::style::create_style_sheet;
+use crate::styles::builder::{use_component_style, StyleInfo, StyleRule};
+use leptos::prelude::*;
 
-/// Applies locale-specific styles
-pub fn apply_locale() {
-    let style = r#"
-        .ant-locale {
-            display: contents;
-        }
-
-        [dir="rtl"] .ant-locale {
-            direction: rtl;
-        }
-
-        .ant-language-switcher {
-            display: inline-flex;
-            gap: 8px;
-        }
-
-        .ant-language-switcher button {
-            padding: 4px 8px;
-            border: 1px solid #d9d9d9;
-            border-radius: 2px;
-            background: transparent;
-            cursor: pointer;
-        }
-
-        .ant-language-switcher button:hover {
-            color: var(--ant-primary-color);
-            border-color: var(--ant-primary-color);
-        }
-    "#;
+/// Generates the locale and language switcher styles
+pub fn gen_locale_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    let switcher_cls = format!(".{}-language-switcher", info.prefix_cls);
+    vec![
+        StyleRule::new(format!(".{}", info.component_cls))
+            .prop("display", "contents")
+            .nest(StyleRule::new("[dir=\"rtl\"] &").prop("direction", "rtl")),
+        StyleRule::new(switcher_cls)
+            .prop("display", "inline-flex")
+            .px("gap", token.alias.margin_xs)
+            .nest(
+                StyleRule::new("button")
+                    .prop(
+                        "padding",
+                        format!("{}px {}px", token.alias.padding_xxs, token.alias.padding_xs),
+                    )
+                    .prop(
+                        "border",
+                        format!(
+                            "{}px {} {}",
+                            token.seed.line_width,
+                            token.seed.line_type,
+                            token.map.colors.color_border
+                        ),
+                    )
+                    .px("border-radius", token.map.common.border_radius_sm)
+                    .prop("background", "transparent")
+                    .prop("cursor", "pointer")
+                    .nest(
+                        StyleRule::new("&:hover")
+                            .prop("color", &token.map.colors.color_primary_hover)
+                            .prop("border-color", &token.map.colors.color_primary_hover),
+                    ),
+            ),
+    ]
+}
 
-    create_style_sheet("locale", style);
+/// Applies locale-specific styles, returning their hash class
+pub fn apply_locale() -> Signal<String> {
+    use_component_style("locale", gen_locale_style)
 }
 
 pub use apply_locale as apply_locale_styles;
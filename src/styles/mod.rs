@@ -1,7 +1,17 @@
+/// Token-driven style builder
+pub mod builder;
+/// Button styles
+pub mod button;
 /// Configuration provider styles
 pub mod config_provider;
+/// Form styles
+pub mod form;
+/// Icon styles
+pub mod icon;
 /// Locale styles
 pub mod locale;
+/// Modal styles
+pub mod modal;
 /// Global styles
 pub mod style;
 /// Theme styles
@@ -9,8 +19,13 @@ pub mod theme;
 /// Version styles
 pub mod version;
 
+pub use builder::{use_component_style, use_global_style, StyleFn, StyleInfo, StyleRule};
+pub use button::apply_button_style;
 pub use config_provider::apply_styles;
+pub use form::apply_form_style;
+pub use icon::apply_icon_style;
 pub use locale::apply_locale_styles as apply_locale;
+pub use modal::apply_modal_style;
 pub use style::global_style;
 pub use theme::apply_theme;
 pub use version::apply_version_style;
@@ -0,0 +1,284 @@
+use crate::components::config_provider::ModalToken;
+use crate::styles::builder::{use_component_style, StyleInfo, StyleRule};
+use leptos::prelude::*;
+
+/// Generates the modal styles
+///
+/// The root, mask, wrap and dialog elements carry the hash class. The zoom
+/// and fade animations are applied through the `{prefix}-zoom-*` and
+/// `{prefix}-fade-*` classes while the modal opens and closes.
+pub fn gen_modal_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    let modal = info.component::<ModalToken>();
+    let cls = format!(".{}", info.component_cls);
+    let prefix = info.prefix_cls;
+    let btn = format!(".{}-btn", prefix);
+    let colors = &token.map.colors;
+    let alias = &token.alias;
+    let font = &token.map.font;
+    let common = &token.map.common;
+    let z_index = token.seed.z_index_popup_base;
+    let zoom_duration = &common.motion_duration_slow;
+    let fade_duration = &common.motion_duration_mid;
+
+    let keyframes = |name: &str, from: StyleRule, to: StyleRule| {
+        StyleRule::new(format!("@keyframes {}-{}", prefix, name))
+            .nest(from)
+            .nest(to)
+            .no_flip()
+    };
+    let animation = |selector: String, name: &str, duration: &str, easing: &str| {
+        StyleRule::new(selector)
+            .prop(
+                "animation",
+                format!("{}-{} {} {} both", prefix, name, duration, easing),
+            )
+            .no_flip()
+    };
+    let confirm_icon = |kind: &str, color: &str| {
+        StyleRule::new(format!(
+            "{0}-confirm-{1} {0}-confirm-body > .{2}",
+            cls, kind, info.icon_prefix_cls
+        ))
+        .prop("color", color)
+    };
+
+    vec![
+        StyleRule::new(format!("{0}-mask, {0}-wrap", cls))
+            .prop("position", "fixed")
+            .prop("inset", "0")
+            .prop("z-index", z_index),
+        StyleRule::new(format!("{}-mask", cls))
+            .prop("height", "100%")
+            .prop("background-color", &colors.color_bg_mask)
+            .prop("pointer-events", "none"),
+        StyleRule::new(format!("{}-wrap", cls))
+            .prop("overflow", "auto")
+            .prop("outline", "0")
+            .prop("-webkit-overflow-scrolling", "touch"),
+        StyleRule::new(format!("{}-centered", cls))
+            .prop("text-align", "center")
+            .nest(
+                StyleRule::new("&::before")
+                    .prop("display", "inline-block")
+                    .prop("width", "0")
+                    .prop("height", "100%")
+                    .prop("vertical-align", "middle")
+                    .prop("content", "\"\""),
+            )
+            .nest(
+                StyleRule::new(cls.clone())
+                    .prop("top", "0")
+                    .prop("display", "inline-block")
+                    .prop("padding-bottom", "0")
+                    .prop("text-align", "start")
+                    .prop("vertical-align", "middle"),
+            ),
+        StyleRule::new(cls.clone())
+            .prop("box-sizing", "border-box")
+            .prop("margin", "0 auto")
+            .prop("padding", format!("0 0 {}px", alias.margin_xl))
+            .prop("color", &colors.color_text)
+            .px("font-size", font.font_size)
+            .prop("line-height", font.line_height)
+            .prop("list-style", "none")
+            .prop("position", "relative")
+            .prop("top", "100px")
+            .prop(
+                "max-width",
+                format!("calc(100vw - {}px)", alias.margin * 2.0),
+            )
+            .prop("pointer-events", "none")
+            .nest(
+                StyleRule::new(format!("{}-content", cls))
+                    .prop("position", "relative")
+                    .prop("padding", &modal.content_padding)
+                    .prop("background-color", &modal.content_bg)
+                    .prop("background-clip", "padding-box")
+                    .prop("border", "0")
+                    .px("border-radius", common.border_radius_lg)
+                    .prop("box-shadow", &alias.box_shadow)
+                    .prop("pointer-events", "auto"),
+            )
+            .nest(
+                StyleRule::new(format!("{}-close", cls))
+                    .prop("position", "absolute")
+                    .px("top", alias.padding_md - 3.0)
+                    .px("inset-inline-end", alias.padding_md - 3.0)
+                    .prop("z-index", "1")
+                    .prop("display", "flex")
+                    .prop("align-items", "center")
+                    .prop("justify-content", "center")
+                    .px("width", modal.close_btn_size)
+                    .px("height", modal.close_btn_size)
+                    .prop("padding", "0")
+                    .prop("color", &alias.color_icon)
+                    .prop("font-weight", alias.font_weight_strong)
+                    .prop("line-height", "1")
+                    .prop("background", "transparent")
+                    .prop("border", "0")
+                    .px("border-radius", common.border_radius_sm)
+                    .prop("outline", "0")
+                    .prop("cursor", "pointer")
+                    .prop(
+                        "transition",
+                        format!(
+                            "color {0}, background-color {0}",
+                            common.motion_duration_mid
+                        ),
+                    )
+                    .nest(
+                        StyleRule::new("&:hover")
+                            .prop("color", &alias.color_icon_hover)
+                            .prop("background-color", &alias.color_bg_text_hover),
+                    )
+                    .nest(
+                        StyleRule::new("&:active")
+                            .prop("background-color", &alias.color_bg_text_active),
+                    ),
+            )
+            .nest(
+                StyleRule::new(format!("{}-header", cls))
+                    .prop("color", &colors.color_text)
+                    .prop("background", &modal.header_bg)
+                    .px("margin-bottom", modal.header_margin_bottom),
+            )
+            .nest(
+                StyleRule::new(format!("{}-title", cls))
+                    .prop("margin", "0")
+                    .prop("color", &modal.title_color)
+                    .prop("font-weight", alias.font_weight_strong)
+                    .px("font-size", modal.title_font_size)
+                    .prop("line-height", modal.title_line_height)
+                    .prop("word-wrap", "break-word"),
+            )
+            .nest(
+                StyleRule::new(format!("{}-body", cls))
+                    .px("font-size", font.font_size)
+                    .prop("line-height", font.line_height)
+                    .prop("word-wrap", "break-word"),
+            )
+            .nest(
+                StyleRule::new(format!("{}-footer", cls))
+                    .px("margin-top", modal.footer_margin_top)
+                    .prop("text-align", "end")
+                    .prop("background", &modal.footer_bg),
+            )
+            .nest(
+                StyleRule::new(format!("{0} + {0}", btn))
+                    .px("margin-inline-start", alias.margin_xs),
+            ),
+        StyleRule::new(format!("{0}-draggable {0}-header", cls))
+            .prop("cursor", "move")
+            .prop("user-select", "none"),
+        StyleRule::new(format!("{}-confirm", cls))
+            .nest(StyleRule::new(format!("{}-header", cls)).prop("display", "none"))
+            .nest(
+                StyleRule::new(format!("{}-confirm-body", cls))
+                    .prop("display", "flex")
+                    .prop("flex-wrap", "nowrap")
+                    .prop("align-items", "start")
+                    .nest(
+                        StyleRule::new(format!("> .{}", info.icon_prefix_cls))
+                            .prop("flex", "none")
+                            .px("margin-inline-end", alias.margin_sm)
+                            .px("font-size", modal.confirm_icon_size),
+                    ),
+            )
+            .nest(
+                StyleRule::new(format!("{}-confirm-paragraph", cls))
+                    .prop("display", "flex")
+                    .prop("flex", "auto")
+                    .prop("flex-direction", "column")
+                    .px("row-gap", alias.margin_xs)
+                    .prop(
+                        "max-width",
+                        format!(
+                            "calc(100% - {}px)",
+                            modal.confirm_icon_size + alias.margin_sm
+                        ),
+                    ),
+            )
+            .nest(
+                StyleRule::new(format!("{}-confirm-title", cls))
+                    .prop("color", &modal.title_color)
+                    .prop("font-weight", alias.font_weight_strong)
+                    .px("font-size", modal.title_font_size)
+                    .prop("line-height", modal.title_line_height),
+            )
+            .nest(
+                StyleRule::new(format!("{}-confirm-content", cls))
+                    .prop("color", &colors.color_text)
+                    .px("font-size", font.font_size),
+            )
+            .nest(
+                StyleRule::new(format!("{}-confirm-btns", cls))
+                    .px("margin-top", alias.margin_lg)
+                    .prop("text-align", "end")
+                    .nest(
+                        StyleRule::new(format!("{0} + {0}", btn))
+                            .px("margin-inline-start", alias.margin_xs),
+                    ),
+            ),
+        confirm_icon("confirm", &colors.color_warning),
+        confirm_icon("warning", &colors.color_warning),
+        confirm_icon("info", &colors.color_info),
+        confirm_icon("success", &colors.color_success),
+        confirm_icon("error", &colors.color_error),
+        animation(
+            format!("{}.{}-zoom-enter", cls, prefix),
+            "zoom-in",
+            zoom_duration,
+            &token.seed.motion_ease_out_circ,
+        ),
+        animation(
+            format!("{}.{}-zoom-leave", cls, prefix),
+            "zoom-out",
+            zoom_duration,
+            &token.seed.motion_ease_in_out_circ,
+        ),
+        animation(
+            format!("{}-mask.{}-fade-enter", cls, prefix),
+            "fade-in",
+            fade_duration,
+            "linear",
+        ),
+        animation(
+            format!("{}-mask.{}-fade-leave", cls, prefix),
+            "fade-out",
+            fade_duration,
+            "linear",
+        ),
+        keyframes(
+            "zoom-in",
+            StyleRule::new("0%")
+                .prop("transform", "scale(0.2)")
+                .prop("opacity", "0"),
+            StyleRule::new("100%")
+                .prop("transform", "scale(1)")
+                .prop("opacity", "1"),
+        ),
+        keyframes(
+            "zoom-out",
+            StyleRule::new("0%").prop("transform", "scale(1)"),
+            StyleRule::new("100%")
+                .prop("transform", "scale(0.2)")
+                .prop("opacity", "0"),
+        ),
+        keyframes(
+            "fade-in",
+            StyleRule::new("0%").prop("opacity", "0"),
+            StyleRule::new("100%").prop("opacity", "1"),
+        ),
+        keyframes(
+            "fade-out",
+            StyleRule::new("0%").prop("opacity", "1"),
+            StyleRule::new("100%").prop("opacity", "0"),
+        ),
+    ]
+}
+
+/// Applies styles for the modal component, returning its hash class
+pub fn apply_modal_style() -> Signal<String> {
+    use_component_style("modal", gen_modal_style)
+}
@@ -1,22 +1,22 @@
-use crate::utils::style::create_style_sheet;
+use crate::styles::builder::{use_global_style, StyleInfo, StyleRule};
+
+/// Generates the global styles for the component library
+pub fn gen_global_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    vec![
+        StyleRule::new("*")
+            .prop("margin", 0)
+            .prop("padding", 0)
+            .prop("box-sizing", "border-box"),
+        StyleRule::new("body")
+            .prop("font-family", &token.seed.font_family)
+            .px("font-size", token.map.font.font_size)
+            .prop("line-height", token.map.font.line_height)
+            .prop("color", &token.map.colors.color_text),
+    ]
+}
 
 /// Applies global styles for the component library
 pub fn global_style() {
-    let style = r#"
-        * {
-            margin: 0;
-            padding: 0;
-            box-sizing: border-box;
-        }
-
-        body {
-            font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto,
-                'Helvetica Neue', Arial, 'Noto Sans', sans-serif;
-            font-size: var(--ant-font-size-base);
-            line-height: 1.5715;
-            color: rgba(0, 0, 0, 0.85);
-        }
-    "#;
-
-    create_style_sheet("global", style);
+    use_global_style("global", gen_global_style);
 }
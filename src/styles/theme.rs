@@ -1,17 +1,19 @@
-use crate::utils::style::create_style_sheet;
+use crate::styles::builder::{use_global_style, StyleInfo, StyleRule};
+
+/// Generates the legacy theme CSS variables
+pub fn gen_theme_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    let var = |name: &str| format!("--{}-{}", info.prefix_cls, name);
+    vec![StyleRule::new(":root")
+        .prop(&var("primary-color"), &token.map.colors.color_primary)
+        .prop(&var("success-color"), &token.map.colors.color_success)
+        .prop(&var("warning-color"), &token.map.colors.color_warning)
+        .prop(&var("error-color"), &token.map.colors.color_error)
+        .px(&var("font-size-base"), token.map.font.font_size)
+        .px(&var("border-radius-base"), token.map.common.border_radius)]
+}
 
 /// Applies the theme styles with CSS variables
 pub fn apply_theme() {
-    let style = r#"
-        :root {
-            --ant-primary-color: #1890ff;
-            --ant-success-color: #52c41a;
-            --ant-warning-color: #faad14;
-            --ant-error-color: #f5222d;
-            --ant-font-size-base: 14px;
-            --ant-border-radius-base: 4px;
-        }
-    "#;
-
-    create_style_sheet("theme", style);
+    use_global_style("theme", gen_theme_style);
 }
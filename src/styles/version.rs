@@ -1,13 +1,15 @@
-use crate::utils::style::create_style_sheet;
+use crate::styles::builder::{use_component_style, StyleInfo, StyleRule};
+use leptos::prelude::*;
 
-/// Applies styles for the version component
-pub fn apply_version_style() {
-    let style = r#"
-        .ant-version {
-            font-size: 14px;
-            color: rgba(0, 0, 0, 0.45);
-        }
-    "#;
+/// Generates the version component styles
+pub fn gen_version_style(info: &StyleInfo) -> Vec<StyleRule> {
+    let token = info.token;
+    vec![StyleRule::new(format!(".{}", info.component_cls))
+        .px("font-size", token.map.font.font_size)
+        .prop("color", &token.alias.color_text_description)]
+}
 
-    create_style_sheet("version", style);
+/// Applies styles for the version component, returning its hash class
+pub fn apply_version_style() -> Signal<String> {
+    use_component_style("version", gen_version_style)
 }
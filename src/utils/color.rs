@@ -0,0 +1,899 @@
+//! 颜色工具
+//!
+//! `@ctrl/tinycolor` 与 `@ant-design/colors` 的纯 Rust 移植，
+//! 浮点截断与取整方式与 JS 实现保持一致，保证输出逐字节相同。
+
+const HUE_STEP: f64 = 2.0;
+const SATURATION_STEP: f64 = 0.16;
+const SATURATION_STEP2: f64 = 0.05;
+const BRIGHTNESS_STEP1: f64 = 0.05;
+const BRIGHTNESS_STEP2: f64 = 0.15;
+const LIGHT_COLOR_COUNT: u32 = 5;
+const DARK_COLOR_COUNT: u32 = 4;
+
+/// 暗色色板的 `(浅色色板下标, 混合比例)` 映射
+const DARK_COLOR_MAP: [(usize, f64); 10] = [
+    (7, 15.0),
+    (6, 25.0),
+    (5, 30.0),
+    (5, 45.0),
+    (5, 65.0),
+    (5, 85.0),
+    (4, 90.0),
+    (3, 95.0),
+    (2, 97.0),
+    (1, 98.0),
+];
+
+/// RGBA color with unrounded channels in `0..=255` and alpha in `0..=1`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    /// Red channel
+    pub r: f64,
+    /// Green channel
+    pub g: f64,
+    /// Blue channel
+    pub b: f64,
+    /// Alpha channel
+    pub a: f64,
+}
+
+/// HSV representation, hue in degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsv {
+    /// Hue in `0..360`
+    pub h: f64,
+    /// Saturation in `0..=1`
+    pub s: f64,
+    /// Value in `0..=1`
+    pub v: f64,
+}
+
+/// HSL representation, hue in degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    /// Hue in `0..360`
+    pub h: f64,
+    /// Saturation in `0..=1`
+    pub s: f64,
+    /// Lightness in `0..=1`
+    pub l: f64,
+}
+
+/// Palette theme for [`generate`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum PaletteTheme {
+    /// Light palette
+    #[default]
+    Default,
+    /// Dark palette, mixed into the background color
+    Dark,
+}
+
+/// Options for [`generate`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GenerateOptions {
+    /// Palette theme
+    pub theme: PaletteTheme,
+    /// Background of the dark palette, `#141414` when unset
+    pub background_color: Option<String>,
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        }
+    }
+}
+
+/// 数值或百分比形式的颜色分量
+#[derive(Clone, Copy)]
+enum Unit {
+    Number(f64),
+    Percent(f64),
+}
+
+impl Unit {
+    fn parse(input: &str) -> Option<Self> {
+        let (number, percent) = match input.strip_suffix('%') {
+            Some(number) => (number, true),
+            None => (input, false),
+        };
+        let value: f64 = number.parse().ok()?;
+        if !value.is_finite() {
+            return None;
+        }
+        // tinycolor 把 "1.0" 这样的写法视为 100%
+        if !percent && value == 1.0 && number.contains('.') {
+            return Some(Unit::Percent(100.0));
+        }
+        Some(if percent {
+            Unit::Percent(value)
+        } else {
+            Unit::Number(value)
+        })
+    }
+
+    /// tinycolor 的 `convertToPercentage`
+    fn from_fraction(value: f64) -> Self {
+        if value <= 1.0 {
+            Unit::Percent(value * 100.0)
+        } else {
+            Unit::Number(value)
+        }
+    }
+}
+
+/// tinycolor 的 `bound01`，将分量换算到 `0..=1`
+fn bound01(n: Unit, max: f64) -> f64 {
+    let (mut n, percent) = match n {
+        Unit::Number(n) => (n, false),
+        Unit::Percent(n) => (n, true),
+    };
+    if max != 360.0 {
+        n = n.max(0.0).min(max);
+    }
+    if percent {
+        // parseInt(String(n * max), 10) / 100
+        n = (n * max).trunc() / 100.0;
+    }
+    if (n - max).abs() < 0.000001 {
+        return 1.0;
+    }
+    if max == 360.0 {
+        (if n < 0.0 { n % max + max } else { n % max }) / max
+    } else {
+        (n % max) / max
+    }
+}
+
+fn bound_alpha(a: f64) -> f64 {
+    if a.is_nan() || !(0.0..=1.0).contains(&a) {
+        1.0
+    } else {
+        a
+    }
+}
+
+/// JS `Math.round`
+pub(crate) fn js_round(x: f64) -> f64 {
+    let floor = x.floor();
+    if x - floor >= 0.5 {
+        floor + 1.0
+    } else {
+        floor
+    }
+}
+
+/// JS `Number(x.toFixed(2))`
+fn to_fixed2(x: f64) -> f64 {
+    let scaled = x.abs() * 100.0;
+    // 恰好处于两位小数中点时 JS 远离零取整，Rust 则向偶数取整
+    if (x * 8.0).fract() == 0.0 && scaled.fract() == 0.5 {
+        return x.signum() * (scaled.floor() + 1.0) / 100.0;
+    }
+    format!("{:.2}", x).parse().unwrap_or(x)
+}
+
+fn hue_to_rgb(p: f64, q: f64, mut t: f64) -> f64 {
+    if t < 0.0 {
+        t += 1.0;
+    }
+    if t > 1.0 {
+        t -= 1.0;
+    }
+    if t < 1.0 / 6.0 {
+        p + (q - p) * (6.0 * t)
+    } else if t < 1.0 / 2.0 {
+        q
+    } else if t < 2.0 / 3.0 {
+        p + (q - p) * (2.0 / 3.0 - t) * 6.0
+    } else {
+        p
+    }
+}
+
+fn hsl_to_rgb(h: Unit, s: Unit, l: Unit) -> (f64, f64, f64) {
+    let h = bound01(h, 360.0);
+    let s = bound01(s, 100.0);
+    let l = bound01(l, 100.0);
+    let (r, g, b) = if s == 0.0 {
+        (l, l, l)
+    } else {
+        let q = if l < 0.5 {
+            l * (1.0 + s)
+        } else {
+            l + s - l * s
+        };
+        let p = 2.0 * l - q;
+        (
+            hue_to_rgb(p, q, h + 1.0 / 3.0),
+            hue_to_rgb(p, q, h),
+            hue_to_rgb(p, q, h - 1.0 / 3.0),
+        )
+    };
+    (r * 255.0, g * 255.0, b * 255.0)
+}
+
+fn hsv_to_rgb(h: Unit, s: Unit, v: Unit) -> (f64, f64, f64) {
+    let h = bound01(h, 360.0) * 6.0;
+    let s = bound01(s, 100.0);
+    let v = bound01(v, 100.0);
+    let i = h.floor();
+    let f = h - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+    let (r, g, b) = match i as i64 % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+    (r * 255.0, g * 255.0, b * 255.0)
+}
+
+impl Color {
+    /// Create a color from RGB channels in `0..=255`
+    pub fn from_rgb(r: f64, g: f64, b: f64) -> Self {
+        Self::from_rgba(r, g, b, 1.0)
+    }
+
+    /// Create a color from RGB channels in `0..=255` and an alpha in `0..=1`
+    pub fn from_rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self::from_units(
+            Unit::Number(r),
+            Unit::Number(g),
+            Unit::Number(b),
+            bound_alpha(a),
+        )
+    }
+
+    /// Create a color from HSV, hue in degrees and the rest in `0..=1`
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        let (r, g, b) = hsv_to_rgb(
+            Unit::Number(h),
+            Unit::from_fraction(s),
+            Unit::from_fraction(v),
+        );
+        Self::clamped(r, g, b, 1.0)
+    }
+
+    /// Create a color from HSL, hue in degrees and the rest in `0..=1`
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        Self::from_hsla(h, s, l, 1.0)
+    }
+
+    /// Create a color from HSL with alpha
+    pub fn from_hsla(h: f64, s: f64, l: f64, a: f64) -> Self {
+        let (r, g, b) = hsl_to_rgb(
+            Unit::Number(h),
+            Unit::from_fraction(s),
+            Unit::from_fraction(l),
+        );
+        Self::clamped(r, g, b, bound_alpha(a))
+    }
+
+    fn from_units(r: Unit, g: Unit, b: Unit, a: f64) -> Self {
+        Self::clamped(
+            bound01(r, 255.0) * 255.0,
+            bound01(g, 255.0) * 255.0,
+            bound01(b, 255.0) * 255.0,
+            a,
+        )
+    }
+
+    fn clamped(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self {
+            r: r.clamp(0.0, 255.0),
+            g: g.clamp(0.0, 255.0),
+            b: b.clamp(0.0, 255.0),
+            a,
+        }
+    }
+
+    /// Parse a CSS color
+    ///
+    /// Accepts `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa` (the `#` is optional),
+    /// `rgb()`/`rgba()`, `hsl()`/`hsla()`, `hsv()`/`hsva()` and `transparent`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim().to_ascii_lowercase();
+        if input == "transparent" {
+            return Some(Self::from_rgba(0.0, 0.0, 0.0, 0.0));
+        }
+
+        if let Some(open) = input.find('(') {
+            let name = input[..open].trim();
+            let args = input[open + 1..].trim_end_matches(')');
+            let parts: Vec<&str> = args
+                .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .collect();
+            let expected = if name.ends_with('a') { 4 } else { 3 };
+            if parts.len() != expected {
+                return None;
+            }
+
+            let units = parts[..3]
+                .iter()
+                .map(|part| Unit::parse(part))
+                .collect::<Option<Vec<_>>>()?;
+            let a = match parts.get(3) {
+                Some(alpha) => bound_alpha(alpha.parse().ok()?),
+                None => 1.0,
+            };
+
+            return match name {
+                "rgb" | "rgba" => Some(Self::from_units(units[0], units[1], units[2], a)),
+                "hsl" | "hsla" => {
+                    let (r, g, b) = hsl_to_rgb(units[0], units[1], units[2]);
+                    Some(Self::clamped(r, g, b, a))
+                }
+                "hsv" | "hsva" => {
+                    let (r, g, b) = hsv_to_rgb(units[0], units[1], units[2]);
+                    Some(Self::clamped(r, g, b, a))
+                }
+                _ => None,
+            };
+        }
+
+        let hex = input.strip_prefix('#').unwrap_or(&input);
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok().map(f64::from);
+        let (r, g, b, a) = match hex.len() {
+            3 | 4 => {
+                let digits: Vec<String> = hex.chars().map(|c| format!("{c}{c}")).collect();
+                let a = match digits.get(3) {
+                    Some(a) => channel(a)? / 255.0,
+                    None => 1.0,
+                };
+                (
+                    channel(&digits[0])?,
+                    channel(&digits[1])?,
+                    channel(&digits[2])?,
+                    a,
+                )
+            }
+            6 | 8 => {
+                let a = match hex.get(6..8) {
+                    Some(a) => channel(a)? / 255.0,
+                    None => 1.0,
+                };
+                (
+                    channel(&hex[0..2])?,
+                    channel(&hex[2..4])?,
+                    channel(&hex[4..6])?,
+                    a,
+                )
+            }
+            _ => return None,
+        };
+        Some(Self::from_rgba(r, g, b, a))
+    }
+
+    /// Convert to HSV
+    pub fn to_hsv(&self) -> Hsv {
+        let r = bound01(Unit::Number(self.r), 255.0);
+        let g = bound01(Unit::Number(self.g), 255.0);
+        let b = bound01(Unit::Number(self.b), 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let d = max - min;
+        let s = if max == 0.0 { 0.0 } else { d / max };
+        let h = if max == min {
+            0.0
+        } else if max == r {
+            (g - b) / d + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        Hsv {
+            h: h / 6.0 * 360.0,
+            s,
+            v: max,
+        }
+    }
+
+    /// Convert to HSL
+    pub fn to_hsl(&self) -> Hsl {
+        let r = bound01(Unit::Number(self.r), 255.0);
+        let g = bound01(Unit::Number(self.g), 255.0);
+        let b = bound01(Unit::Number(self.b), 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let (h, s) = if max == min {
+            (0.0, 0.0)
+        } else {
+            let d = max - min;
+            let s = if l > 0.5 {
+                d / (2.0 - max - min)
+            } else {
+                d / (max + min)
+            };
+            let h = if max == r {
+                (g - b) / d + if g < b { 6.0 } else { 0.0 }
+            } else if max == g {
+                (b - r) / d + 2.0
+            } else {
+                (r - g) / d + 4.0
+            };
+            (h / 6.0, s)
+        };
+        Hsl { h: h * 360.0, s, l }
+    }
+
+    /// Format as `#rrggbb`
+    pub fn to_hex_string(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            js_round(self.r) as u8,
+            js_round(self.g) as u8,
+            js_round(self.b) as u8
+        )
+    }
+
+    /// Format as `rgb(r, g, b)`, or `rgba(r, g, b, a)` when translucent
+    pub fn to_rgb_string(&self) -> String {
+        let (r, g, b) = (js_round(self.r), js_round(self.g), js_round(self.b));
+        if self.a == 1.0 {
+            format!("rgb({}, {}, {})", r, g, b)
+        } else {
+            format!(
+                "rgba({}, {}, {}, {})",
+                r,
+                g,
+                b,
+                js_round(self.a * 100.0) / 100.0
+            )
+        }
+    }
+
+    /// Replace the alpha channel
+    pub fn with_alpha(self, a: f64) -> Self {
+        Self {
+            a: bound_alpha(a),
+            ..self
+        }
+    }
+
+    /// Decrease HSL lightness by `amount` percent
+    pub fn darken(&self, amount: f64) -> Self {
+        let hsl = self.to_hsl();
+        Self::from_hsla(
+            hsl.h,
+            hsl.s,
+            (hsl.l - amount / 100.0).clamp(0.0, 1.0),
+            self.a,
+        )
+    }
+
+    /// Increase HSL lightness by `amount` percent
+    pub fn lighten(&self, amount: f64) -> Self {
+        let hsl = self.to_hsl();
+        Self::from_hsla(
+            hsl.h,
+            hsl.s,
+            (hsl.l + amount / 100.0).clamp(0.0, 1.0),
+            self.a,
+        )
+    }
+
+    /// Mix `amount` percent of `other` into this color
+    pub fn mix(&self, other: &Color, amount: f64) -> Self {
+        let p = amount / 100.0;
+        Self {
+            r: (other.r - self.r) * p + self.r,
+            g: (other.g - self.g) * p + self.g,
+            b: (other.b - self.b) * p + self.b,
+            a: (other.a - self.a) * p + self.a,
+        }
+    }
+}
+
+fn get_hue(hsv: &Hsv, i: u32, light: bool) -> f64 {
+    let h = js_round(hsv.h);
+    let step = HUE_STEP * f64::from(i);
+    let hue = if (60.0..=240.0).contains(&h) {
+        if light {
+            h - step
+        } else {
+            h + step
+        }
+    } else if light {
+        h + step
+    } else {
+        h - step
+    };
+    if hue < 0.0 {
+        hue + 360.0
+    } else if hue >= 360.0 {
+        hue - 360.0
+    } else {
+        hue
+    }
+}
+
+fn get_saturation(hsv: &Hsv, i: u32, light: bool) -> f64 {
+    // 灰色不调整饱和度
+    if hsv.h == 0.0 && hsv.s == 0.0 {
+        return hsv.s;
+    }
+    let mut saturation = if light {
+        hsv.s - SATURATION_STEP * f64::from(i)
+    } else if i == DARK_COLOR_COUNT {
+        hsv.s + SATURATION_STEP
+    } else {
+        hsv.s + SATURATION_STEP2 * f64::from(i)
+    };
+    if saturation > 1.0 {
+        saturation = 1.0;
+    }
+    // 第一格亮色的饱和度最高为 0.1
+    if light && i == LIGHT_COLOR_COUNT && saturation > 0.1 {
+        saturation = 0.1;
+    }
+    if saturation < 0.06 {
+        saturation = 0.06;
+    }
+    to_fixed2(saturation)
+}
+
+fn get_value(hsv: &Hsv, i: u32, light: bool) -> f64 {
+    let value = if light {
+        hsv.v + BRIGHTNESS_STEP1 * f64::from(i)
+    } else {
+        hsv.v - BRIGHTNESS_STEP2 * f64::from(i)
+    };
+    to_fixed2(value.min(1.0))
+}
+
+/// Generate the ten-step antd palette of a color
+///
+/// Port of `generate` from `@ant-design/colors`. The sixth entry is the
+/// input color itself; unparsable input is treated as black like the JS
+/// library does.
+pub fn generate(color: &str, options: &GenerateOptions) -> Vec<String> {
+    let base = Color::parse(color).unwrap_or_default();
+    let hsv = base.to_hsv();
+
+    let mut patterns = Vec::with_capacity(10);
+    for i in (1..=LIGHT_COLOR_COUNT).rev() {
+        let color = Color::from_hsv(
+            get_hue(&hsv, i, true),
+            get_saturation(&hsv, i, true),
+            get_value(&hsv, i, true),
+        );
+        patterns.push(color.to_hex_string());
+    }
+    patterns.push(base.to_hex_string());
+    for i in 1..=DARK_COLOR_COUNT {
+        let color = Color::from_hsv(
+            get_hue(&hsv, i, false),
+            get_saturation(&hsv, i, false),
+            get_value(&hsv, i, false),
+        );
+        patterns.push(color.to_hex_string());
+    }
+
+    if options.theme == PaletteTheme::Dark {
+        let background = options
+            .background_color
+            .as_deref()
+            .and_then(Color::parse)
+            .unwrap_or_else(|| Color::from_rgb(20.0, 20.0, 20.0));
+        return DARK_COLOR_MAP
+            .iter()
+            .map(|&(index, amount)| {
+                let color = Color::parse(&patterns[index]).unwrap_or_default();
+                background.mix(&color, amount).to_hex_string()
+            })
+            .collect();
+    }
+
+    patterns
+}
+
+/// Find the translucent color that looks like `front` over `background`
+///
+/// Port of antd's `getAlphaColor` theme helper; returns `front` unchanged
+/// when it is already translucent.
+pub fn blend_alpha(front: &str, background: &str) -> String {
+    let f = Color::parse(front).unwrap_or_default();
+    if f.a < 1.0 {
+        return front.to_string();
+    }
+    let b = Color::parse(background).unwrap_or_default();
+    let stable = |v: f64| (0.0..=255.0).contains(&v);
+
+    let mut fa = 0.01;
+    while fa <= 1.0 {
+        let r = js_round((f.r - b.r * (1.0 - fa)) / fa);
+        let g = js_round((f.g - b.g * (1.0 - fa)) / fa);
+        let bl = js_round((f.b - b.b * (1.0 - fa)) / fa);
+        if stable(r) && stable(g) && stable(bl) {
+            return Color::from_rgba(r, g, bl, js_round(fa * 100.0) / 100.0).to_rgb_string();
+        }
+        fa += 0.01;
+    }
+    Color::from_rgb(f.r, f.g, f.b).to_rgb_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Preset colors of `@ant-design/colors` with their light and dark palettes
+    const PRESETS: [(&str, [&str; 10], [&str; 10]); 13] = [
+        (
+            "#F5222D",
+            [
+                "#fff1f0", "#ffccc7", "#ffa39e", "#ff7875", "#ff4d4f", "#f5222d", "#cf1322",
+                "#a8071a", "#820014", "#5c0011",
+            ],
+            [
+                "#2a1215", "#431418", "#58181c", "#791a1f", "#a61d24", "#d32029", "#e84749",
+                "#f37370", "#f89f9a", "#fac8c3",
+            ],
+        ),
+        (
+            "#FA541C",
+            [
+                "#fff2e8", "#ffd8bf", "#ffbb96", "#ff9c6e", "#ff7a45", "#fa541c", "#d4380d",
+                "#ad2102", "#871400", "#610b00",
+            ],
+            [
+                "#2b1611", "#441d12", "#592716", "#7c3118", "#aa3e19", "#d84a1b", "#e87040",
+                "#f3956a", "#f8b692", "#fad4bc",
+            ],
+        ),
+        (
+            "#FA8C16",
+            [
+                "#fff7e6", "#ffe7ba", "#ffd591", "#ffc069", "#ffa940", "#fa8c16", "#d46b08",
+                "#ad4e00", "#873800", "#612500",
+            ],
+            [
+                "#2b1d11", "#442a11", "#593815", "#7c4a15", "#aa6215", "#d87a16", "#e89a3c",
+                "#f3b765", "#f8cf8d", "#fae3b7",
+            ],
+        ),
+        (
+            "#FAAD14",
+            [
+                "#fffbe6", "#fff1b8", "#ffe58f", "#ffd666", "#ffc53d", "#faad14", "#d48806",
+                "#ad6800", "#874d00", "#613400",
+            ],
+            [
+                "#2b2111", "#443111", "#594214", "#7c5914", "#aa7714", "#d89614", "#e8b339",
+                "#f3cc62", "#f8df8b", "#faedb5",
+            ],
+        ),
+        (
+            "#FADB14",
+            [
+                "#feffe6", "#ffffb8", "#fffb8f", "#fff566", "#ffec3d", "#fadb14", "#d4b106",
+                "#ad8b00", "#876800", "#614700",
+            ],
+            [
+                "#2b2611", "#443b11", "#595014", "#7c6e14", "#aa9514", "#d8bd14", "#e8d639",
+                "#f3ea62", "#f8f48b", "#fafab5",
+            ],
+        ),
+        (
+            "#A0D911",
+            [
+                "#fcffe6", "#f4ffb8", "#eaff8f", "#d3f261", "#bae637", "#a0d911", "#7cb305",
+                "#5b8c00", "#3f6600", "#254000",
+            ],
+            [
+                "#1f2611", "#2e3c10", "#3e4f13", "#536d13", "#6f9412", "#8bbb11", "#a9d134",
+                "#c9e75d", "#e4f88b", "#f0fab5",
+            ],
+        ),
+        (
+            "#52C41A",
+            [
+                "#f6ffed", "#d9f7be", "#b7eb8f", "#95de64", "#73d13d", "#52c41a", "#389e0d",
+                "#237804", "#135200", "#092b00",
+            ],
+            [
+                "#162312", "#1d3712", "#274916", "#306317", "#3c8618", "#49aa19", "#6abe39",
+                "#8fd460", "#b2e58b", "#d5f2bb",
+            ],
+        ),
+        (
+            "#13C2C2",
+            [
+                "#e6fffb", "#b5f5ec", "#87e8de", "#5cdbd3", "#36cfc9", "#13c2c2", "#08979c",
+                "#006d75", "#00474f", "#002329",
+            ],
+            [
+                "#112123", "#113536", "#144848", "#146262", "#138585", "#13a8a8", "#33bcb7",
+                "#58d1c9", "#84e2d8", "#b2f1e8",
+            ],
+        ),
+        (
+            "#1677FF",
+            [
+                "#e6f4ff", "#bae0ff", "#91caff", "#69b1ff", "#4096ff", "#1677ff", "#0958d9",
+                "#003eb3", "#002c8c", "#001d66",
+            ],
+            [
+                "#111a2c", "#112545", "#15325b", "#15417e", "#1554ad", "#1668dc", "#3c89e8",
+                "#65a9f3", "#8dc5f8", "#b7dcfa",
+            ],
+        ),
+        (
+            "#2F54EB",
+            [
+                "#f0f5ff", "#d6e4ff", "#adc6ff", "#85a5ff", "#597ef7", "#2f54eb", "#1d39c4",
+                "#10239e", "#061178", "#030852",
+            ],
+            [
+                "#131629", "#161d40", "#1c2755", "#203175", "#263ea0", "#2b4acb", "#5273e0",
+                "#7f9ef3", "#a8c1f8", "#d2e0fa",
+            ],
+        ),
+        (
+            "#722ED1",
+            [
+                "#f9f0ff", "#efdbff", "#d3adf7", "#b37feb", "#9254de", "#722ed1", "#531dab",
+                "#391085", "#22075e", "#120338",
+            ],
+            [
+                "#1a1325", "#24163a", "#301c4d", "#3e2069", "#51258f", "#642ab5", "#854eca",
+                "#ab7ae0", "#cda8f0", "#ebd7fa",
+            ],
+        ),
+        (
+            "#EB2F96",
+            [
+                "#fff0f6", "#ffd6e7", "#ffadd2", "#ff85c0", "#f759ab", "#eb2f96", "#c41d7f",
+                "#9e1068", "#780650", "#520339",
+            ],
+            [
+                "#291321", "#40162f", "#551c3b", "#75204f", "#a02669", "#cb2b83", "#e0529c",
+                "#f37fb7", "#f8a8cc", "#fad2e3",
+            ],
+        ),
+        (
+            "#666666",
+            [
+                "#a6a6a6", "#999999", "#8c8c8c", "#808080", "#737373", "#666666", "#404040",
+                "#1a1a1a", "#000000", "#000000",
+            ],
+            [
+                "#151515", "#1f1f1f", "#2d2d2d", "#393939", "#494949", "#5a5a5a", "#6a6a6a",
+                "#7b7b7b", "#888888", "#969696",
+            ],
+        ),
+    ];
+
+    fn dark() -> GenerateOptions {
+        GenerateOptions {
+            theme: PaletteTheme::Dark,
+            background_color: Some("#141414".to_string()),
+        }
+    }
+
+    #[test]
+    fn generates_the_published_light_palettes() {
+        for (color, light, _) in PRESETS {
+            assert_eq!(
+                generate(color, &GenerateOptions::default()),
+                light,
+                "{color}"
+            );
+        }
+    }
+
+    #[test]
+    fn generates_the_published_dark_palettes() {
+        for (color, _, dark_palette) in PRESETS {
+            assert_eq!(generate(color, &dark()), dark_palette, "{color}");
+        }
+    }
+
+    #[test]
+    fn dark_palette_defaults_to_the_antd_background() {
+        let options = GenerateOptions {
+            theme: PaletteTheme::Dark,
+            background_color: None,
+        };
+        assert_eq!(generate("#1677ff", &options), generate("#1677ff", &dark()));
+    }
+
+    #[test]
+    fn parses_short_hex() {
+        assert_eq!(Color::parse("#f00"), Some(Color::from_rgb(255.0, 0.0, 0.0)));
+        assert_eq!(Color::parse("0f0"), Some(Color::from_rgb(0.0, 255.0, 0.0)));
+        let color = Color::parse("#00f8").unwrap();
+        assert_eq!((color.r, color.g, color.b), (0.0, 0.0, 255.0));
+        assert!((color.a - 136.0 / 255.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parses_long_hex() {
+        assert_eq!(
+            Color::parse("#1677FF"),
+            Some(Color::from_rgb(22.0, 119.0, 255.0))
+        );
+        let color = Color::parse("#1677ff80").unwrap();
+        assert_eq!(color.to_hex_string(), "#1677ff");
+        assert!((color.a - 128.0 / 255.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parses_functional_notations() {
+        assert_eq!(
+            Color::parse("rgb(22, 119, 255)"),
+            Some(Color::from_rgb(22.0, 119.0, 255.0))
+        );
+        assert_eq!(
+            Color::parse("rgba(0, 0, 0, 0.45)").map(|color| color.to_rgb_string()),
+            Some("rgba(0, 0, 0, 0.45)".to_string())
+        );
+        assert_eq!(
+            Color::parse("rgb(100%, 0%, 0%)").map(|color| color.to_hex_string()),
+            Some("#ff0000".to_string())
+        );
+        assert_eq!(
+            Color::parse("hsl(120, 100%, 50%)").map(|color| color.to_hex_string()),
+            Some("#00ff00".to_string())
+        );
+        assert_eq!(
+            Color::parse("HSLA(240, 100%, 50%, 0.5)").map(|color| color.to_rgb_string()),
+            Some("rgba(0, 0, 255, 0.5)".to_string())
+        );
+        assert_eq!(
+            Color::parse(" transparent ").map(|color| color.a),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn clamps_out_of_range_channels() {
+        assert_eq!(
+            Color::parse("rgba(300, -20, 0, 2)"),
+            Some(Color::from_rgb(255.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        for input in [
+            "",
+            "#",
+            "#12",
+            "#12345",
+            "#1234567",
+            "#ggg",
+            "red",
+            "rgb(1, 2)",
+            "rgba(1, 2, 3)",
+            "rgb(a, b, c)",
+            "cmyk(1, 2, 3)",
+        ] {
+            assert_eq!(Color::parse(input), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn unparsable_palette_input_is_black() {
+        assert_eq!(
+            generate("not a color", &GenerateOptions::default()),
+            generate("#000000", &GenerateOptions::default())
+        );
+    }
+}
@@ -1,9 +1,16 @@
-use leptos::prelude::*;
-use web_sys::{Document, Element, Window};
+use web_sys::{Document, Element, MediaQueryList, Storage, Window};
 
 /// Returns the global window object
+///
+/// Always `None` when rendering on the server, where there is no DOM.
 pub fn window() -> Option<Window> {
-    web_sys::window()
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            None
+        } else {
+            web_sys::window()
+        }
+    }
 }
 
 /// Returns the document object
@@ -19,3 +26,21 @@ pub fn create_style_element(css: &str) -> Option<Element> {
         Some(element)
     })
 }
+
+/// Media query matching a dark color scheme preference
+pub const PREFERS_DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Returns the media query list for a query
+pub fn match_media(query: &str) -> Option<MediaQueryList> {
+    window().and_then(|win| win.match_media(query).ok().flatten())
+}
+
+/// Whether the operating system prefers a dark color scheme
+pub fn prefers_dark_scheme() -> bool {
+    match_media(PREFERS_DARK_QUERY).is_some_and(|query| query.matches())
+}
+
+/// Returns the local storage, if available
+pub fn local_storage() -> Option<Storage> {
+    window().and_then(|win| win.local_storage().ok().flatten())
+}
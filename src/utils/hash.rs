@@ -0,0 +1,14 @@
+/// Hash a string into a short, stable identifier
+///
+/// Uses 64-bit FNV-1a so the result is identical across builds and targets,
+/// which keeps server-rendered and hydrated class names in sync.
+pub fn hash_str(input: &str) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = input.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+
+    format!("{:x}", hash)
+}
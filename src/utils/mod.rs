@@ -1,4 +1,10 @@
+/// Color parsing and palette generation
+pub mod color;
 /// DOM manipulation utilities
 pub mod dom;
+/// Stable string hashing
+pub mod hash;
 /// Style injection utilities
 pub mod style;
+/// Field paths and validation rules, usable without the reactive runtime
+pub mod validate;
@@ -1,21 +1,474 @@
-use crate::utils::dom;
+//! 样式注册表
+//!
+//! 每个样式表以 `名称-哈希` 作为键，对应 `<head>` 中唯一一个
+//! `<style data-ant-key="...">` 元素。相同的键只会注入一次，并按引用计数管理，
+//! 最后一个使用者卸载时移除对应的元素。
+//!
+//! 服务端渲染时没有 DOM，样式表同样登记在注册表中，当前请求用到的键记录在
+//! [`StyleCollector`] 里，由它生成 `<style>` 标签插入 `<head>`；客户端水合时
+//! 直接复用这些元素。
+//!
+//! 样式表按首次注册的先后输出：客户端把新元素追加到 `<head>` 末尾，服务端
+//! 按收集的先后生成标签，两边的层叠顺序保持一致。
+
+use crate::utils::{dom, hash::hash_str};
 use leptos::prelude::*;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use web_sys::Element;
 
-/// Injects a CSS style string into the document
-pub fn inject_style(css: &str) {
-    if let Some(style_element) = dom::create_style_element(css) {
-        if let Some(document) = dom::document() {
-            if let Some(head) = document.head() {
-                let _ = head.append_child(&style_element);
-            }
+/// Attribute identifying registered style elements
+pub const STYLE_KEY_ATTR: &str = "data-ant-key";
+
+/// A style sheet tracked by the registry
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisteredStyle {
+    /// Registry key, `{name}-{hash}`
+    pub key: String,
+    /// Style sheet name
+    pub name: String,
+    /// CSS content
+    pub css: String,
+    /// Number of active users
+    pub ref_count: usize,
+    /// Registration sequence, later sheets come after earlier ones
+    pub order: usize,
+}
+
+/// Collects the style sheets rendered during one server request
+///
+/// Collecting a key holds one reference to its sheet, so the CSS is still
+/// registered after the request's components are dropped. The references are
+/// released together with the last clone of the collector.
+#[derive(Clone, Debug, Default)]
+pub struct StyleCollector {
+    keys: Arc<CollectedKeys>,
+}
+
+#[derive(Debug, Default)]
+struct CollectedKeys(Mutex<Vec<String>>);
+
+impl Drop for CollectedKeys {
+    fn drop(&mut self) {
+        let keys = match self.0.get_mut() {
+            Ok(keys) => std::mem::take(keys),
+            Err(_) => return,
+        };
+        for key in keys {
+            unregister_style(&key);
+        }
+    }
+}
+
+impl StyleCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a registered style sheet, returning `false` if it was already
+    /// collected or is not registered
+    pub fn collect(&self, key: &str) -> bool {
+        let Ok(mut keys) = self.keys.0.lock() else {
+            return false;
+        };
+        if keys.iter().any(|collected| collected == key) || !retain_style(key) {
+            return false;
+        }
+        keys.push(key.to_string());
+        true
+    }
+
+    /// Collected `(key, css)` pairs that are still registered, in the order
+    /// they were collected
+    pub fn styles(&self) -> Vec<(String, String)> {
+        let Ok(keys) = self.keys.0.lock() else {
+            return Vec::new();
+        };
+        // 注册表跨请求共享，全局序号可能早于本次请求，这里按本次收集的先后排列，
+        // 与客户端追加元素的顺序一致
+        with_registry(|registry| {
+            keys.iter()
+                .filter_map(|key| {
+                    let style = registry.get(key)?;
+                    Some((style.key.clone(), style.css.clone()))
+                })
+                .collect()
+        })
+    }
+
+    /// Render the collected sheets as `<style>` tags for the document head
+    pub fn to_html(&self) -> String {
+        self.styles()
+            .into_iter()
+            .map(|(key, css)| style_tag(&key, &css))
+            .collect()
+    }
+
+    /// Insert the collected sheets before `</head>` in the first chunk of an
+    /// HTML stream, like `leptos_meta` does for its tags
+    ///
+    /// Only sheets registered while the first chunk rendered are included.
+    pub async fn inject_styles(
+        self,
+        mut stream: impl futures::Stream<Item = String> + Send + Unpin,
+    ) -> impl futures::Stream<Item = String> + Send {
+        use futures::StreamExt;
+
+        let mut first_chunk = stream.next().await.unwrap_or_default();
+        let tags = self.to_html();
+        if let Some(head_end) = first_chunk.find("</head>") {
+            first_chunk.insert_str(head_end, &tags);
+        }
+        futures::stream::once(async move { first_chunk }).chain(stream)
+    }
+}
+
+/// Get the style collector of the current request, providing one if missing
+pub fn provide_style_collector() -> StyleCollector {
+    use_context::<StyleCollector>().unwrap_or_else(|| {
+        let collector = StyleCollector::new();
+        provide_context(collector.clone());
+        collector
+    })
+}
+
+/// Render a registered style sheet as a `<style>` tag
+pub fn style_tag(key: &str, css: &str) -> String {
+    format!(
+        "<style id=\"{key}\" {}=\"{key}\">{}</style>",
+        STYLE_KEY_ATTR,
+        css.replace("</", "<\\/"),
+    )
+}
+
+// 服务端渲染可能跨线程，注册表需要进程内共享
+static REGISTRY: Mutex<BTreeMap<String, RegisteredStyle>> = Mutex::new(BTreeMap::new());
+static NEXT_ORDER: AtomicUsize = AtomicUsize::new(0);
+
+fn with_registry<T>(f: impl FnOnce(&mut BTreeMap<String, RegisteredStyle>) -> T) -> T {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    f(&mut registry)
+}
+
+/// Add one reference to a registered style sheet
+fn retain_style(key: &str) -> bool {
+    with_registry(|registry| match registry.get_mut(key) {
+        Some(style) => {
+            style.ref_count += 1;
+            true
+        }
+        None => false,
+    })
+}
+
+/// Build the registry key for a style sheet
+pub fn style_key(name: &str, hash: &str) -> String {
+    format!("{}-{}", name, hash)
+}
+
+/// Register a style sheet and return its key
+///
+/// Registering an existing key increases its reference count and replaces
+/// its CSS in place instead of adding another `<style>` element. On the
+/// server the key is recorded in the request's [`StyleCollector`] instead.
+pub fn register_style(name: &str, hash: &str, css: &str) -> String {
+    let key = style_key(name, hash);
+    with_registry(|registry| {
+        registry
+            .entry(key.clone())
+            .and_modify(|style| {
+                style.css = css.to_string();
+                style.ref_count += 1;
+            })
+            .or_insert_with(|| RegisteredStyle {
+                key: key.clone(),
+                name: name.to_string(),
+                css: css.to_string(),
+                ref_count: 1,
+                order: NEXT_ORDER.fetch_add(1, Ordering::Relaxed),
+            });
+    });
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            provide_style_collector().collect(&key);
+        } else {
+            mount_style(&key, css);
+        }
+    }
+    key
+}
+
+/// Release one reference to a style sheet, removing it when unused
+pub fn unregister_style(key: &str) {
+    let removed = with_registry(|registry| match registry.get_mut(key) {
+        Some(style) if style.ref_count > 1 => {
+            style.ref_count -= 1;
+            false
+        }
+        Some(_) => registry.remove(key).is_some(),
+        None => false,
+    });
+    if removed {
+        unmount_style(key);
+    }
+}
+
+/// CSS of a registered style sheet
+pub fn registered_css(key: &str) -> Option<String> {
+    with_registry(|registry| registry.get(key).map(|style| style.css.clone()))
+}
+
+/// List all registered style sheets in registration order
+pub fn registered_styles() -> Vec<RegisteredStyle> {
+    let mut styles: Vec<RegisteredStyle> =
+        with_registry(|registry| registry.values().cloned().collect());
+    styles.sort_by_key(|style| style.order);
+    styles
+}
+
+/// Remove every registered style sheet
+pub fn clear_styles() {
+    let keys: Vec<String> =
+        with_registry(|registry| std::mem::take(registry).into_keys().collect());
+    for key in keys {
+        unmount_style(&key);
+    }
+}
+
+/// Register a style sheet for the lifetime of the current reactive owner
+///
+/// The sheet is released automatically when the owner is cleaned up.
+pub fn use_style(name: &str, hash: &str, css: &str) -> String {
+    let key = register_style(name, hash, css);
+    let cleanup_key = key.clone();
+    on_cleanup(move || unregister_style(&cleanup_key));
+    key
+}
+
+/// Register a style sheet whose content follows reactive inputs
+///
+/// `source` returns the current hash together with a function producing the
+/// CSS, or `None` when no sheet is needed. The CSS is only generated when the
+/// hash changes and no sheet with the same key is registered yet; the previous
+/// sheet is released at that point and the current one when the owner is
+/// cleaned up.
+pub fn use_dynamic_style<S, F>(name: &'static str, source: S)
+where
+    S: Fn() -> Option<(String, F)> + 'static,
+    F: FnOnce() -> String,
+{
+    let current = StoredValue::new(None::<String>);
+    on_cleanup(move || {
+        if let Some(key) = current.get_value() {
+            unregister_style(&key);
+        }
+    });
+
+    let sync = move || {
+        let next = source();
+        let previous = current.get_value();
+        let next_key = next.as_ref().map(|(hash, _)| style_key(name, hash));
+        if previous == next_key {
+            return;
+        }
+
+        current.set_value(next.map(|(hash, css)| {
+            // 已注册的样式表直接复用，不再重新生成
+            let css = registered_css(&style_key(name, &hash)).unwrap_or_else(css);
+            register_style(name, &hash, &css)
+        }));
+        if let Some(previous) = previous {
+            unregister_style(&previous);
+        }
+    };
+
+    // 服务端不会运行 Effect，需要在渲染时同步收集样式
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            untrack(sync);
+        } else {
+            Effect::new(move |_| sync());
         }
     }
 }
 
+/// Injects a CSS style string into the document
+pub fn inject_style(css: &str) {
+    register_style("style", &hash_str(css), css);
+}
+
 /// Creates a style sheet with the given name and CSS content
-pub fn create_style_sheet(_name: &str, css: impl Into<String> + 'static) {
+pub fn create_style_sheet(name: &str, css: impl Into<String> + 'static) {
     let css = css.into();
-    Effect::new(move |_| {
-        inject_style(&css);
-    });
+    use_style(name, &hash_str(&css), &css);
+}
+
+fn find_style_element(key: &str) -> Option<Element> {
+    dom::document()?
+        .query_selector(&format!("style[{}=\"{}\"]", STYLE_KEY_ATTR, key))
+        .ok()
+        .flatten()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn mount_style(key: &str, css: &str) {
+    if let Some(element) = find_style_element(key) {
+        if element.text_content().as_deref() != Some(css) {
+            element.set_text_content(Some(css));
+        }
+        return;
+    }
+
+    let Some(element) = dom::create_style_element(css) else {
+        return;
+    };
+    let _ = element.set_attribute(STYLE_KEY_ATTR, key);
+    if let Some(head) = dom::document().and_then(|document| document.head()) {
+        let _ = head.append_child(&element);
+    }
+}
+
+fn unmount_style(key: &str) {
+    if let Some(element) = find_style_element(key) {
+        element.remove();
+    }
+}
+
+// 客户端的注册表会操作 DOM，只在服务端下测试
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    fn ref_count(key: &str) -> Option<usize> {
+        registered_styles()
+            .into_iter()
+            .find(|style| style.key == key)
+            .map(|style| style.ref_count)
+    }
+
+    #[test]
+    fn collector_keeps_styles_until_dropped() {
+        let owner = Owner::new();
+        let (collector, key) = owner.with(|| {
+            let collector = provide_style_collector();
+            (
+                collector,
+                register_style("test-collector", "a", ".a{color:red}"),
+            )
+        });
+        assert_eq!(ref_count(&key), Some(2));
+        assert!(!collector.collect(&key));
+
+        unregister_style(&key);
+        assert_eq!(ref_count(&key), Some(1));
+        assert_eq!(collector.to_html(), style_tag(&key, ".a{color:red}"),);
+
+        drop(owner);
+        drop(collector);
+        assert_eq!(ref_count(&key), None);
+    }
+
+    #[test]
+    fn collector_emits_styles_in_collection_order() {
+        let earlier = register_style("test-order-z", "a", ".z{}");
+        let owner = Owner::new();
+        let (collector, keys) = owner.with(|| {
+            let collector = provide_style_collector();
+            let keys = [
+                register_style("test-order-b", "a", ".b{}"),
+                register_style("test-order-a", "a", ".a{}"),
+                register_style("test-order-z", "a", ".z{}"),
+            ];
+            (collector, keys)
+        });
+        assert_eq!(
+            collector.to_html(),
+            keys.iter()
+                .map(|key| style_tag(key, &registered_css(key).unwrap()))
+                .collect::<String>()
+        );
+
+        let registered: Vec<String> = registered_styles()
+            .into_iter()
+            .map(|style| style.key)
+            .filter(|key| key.starts_with("test-order-"))
+            .collect();
+        assert_eq!(
+            registered,
+            [earlier.clone(), keys[0].clone(), keys[1].clone()]
+        );
+
+        for key in keys {
+            unregister_style(&key);
+        }
+        unregister_style(&earlier);
+        drop(owner);
+        drop(collector);
+        assert!(registered_css(&earlier).is_none());
+    }
+
+    #[test]
+    fn collector_skips_unregistered_keys() {
+        let collector = StyleCollector::new();
+        assert!(!collector.collect("test-missing-a"));
+        assert!(collector.styles().is_empty());
+    }
+
+    #[test]
+    fn inject_styles_inserts_before_head_end() {
+        let collector = StyleCollector::new();
+        let key = register_style("test-inject", "b", ".b{}");
+        collector.collect(&key);
+        let chunks = futures::stream::iter(vec![
+            "<html><head></head>".to_string(),
+            "<body></body></html>".to_string(),
+        ]);
+        let html = futures::executor::block_on(async {
+            use futures::StreamExt;
+            collector
+                .clone()
+                .inject_styles(chunks)
+                .await
+                .collect::<String>()
+                .await
+        });
+        assert_eq!(
+            html,
+            format!(
+                "<html><head>{}</head><body></body></html>",
+                style_tag(&key, ".b{}")
+            )
+        );
+        unregister_style(&key);
+    }
+
+    #[test]
+    fn dynamic_style_reuses_registered_css_and_releases_it() {
+        let key = register_style("test-dynamic", "c", ".c{}");
+        let owner = Owner::new();
+        owner.with(|| {
+            use_dynamic_style("test-dynamic", || {
+                Some(("c".to_string(), || -> String {
+                    unreachable!("the registered sheet is reused")
+                }))
+            })
+        });
+        // 注册一次，再被请求的收集器固定一次
+        assert_eq!(ref_count(&key), Some(3));
+
+        drop(owner);
+        unregister_style(&key);
+        assert_eq!(registered_css(&key), None);
+    }
+
+    #[test]
+    fn style_tag_escapes_closing_tags() {
+        assert_eq!(
+            style_tag("k", "a::after{content:\"</style>\"}"),
+            "<style id=\"k\" data-ant-key=\"k\">a::after{content:\"<\\/style>\"}</style>"
+        );
+    }
 }
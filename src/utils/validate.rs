@@ -0,0 +1,1106 @@
+//! 字段路径与校验规则
+//!
+//! 值保存为一棵 `serde_json::Value`，字段通过 [`NamePath`] 定位。
+//! 规则与 async-validator 的语义一致：非必填字段为空时跳过内置校验，
+//! 失败信息使用 [`ValidateMessages`] 中的模板并替换其中的 `${...}` 变量。
+//!
+//! 本模块不依赖响应式运行时，`Form` 与服务端函数共用同一套规则：用
+//! [`Schema`] 声明字段与规则，[`Schema::validate`] 返回出错的字段。非 wasm
+//! 目标上自定义校验器返回的 future 要求 `Send`，因此校验可以在多线程的
+//! 服务端运行时中进行。
+
+use crate::components::config_provider::{ValidateLengthMessages, ValidateMessages};
+use crate::components::locale::resolve_locale;
+use regex::Regex;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock};
+
+/// One segment of a field path
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NameSegment {
+    /// Object key
+    Key(String),
+    /// Array index
+    Index(usize),
+}
+
+impl From<&str> for NameSegment {
+    fn from(key: &str) -> Self {
+        NameSegment::Key(key.to_string())
+    }
+}
+
+impl From<String> for NameSegment {
+    fn from(key: String) -> Self {
+        NameSegment::Key(key)
+    }
+}
+
+impl From<usize> for NameSegment {
+    fn from(index: usize) -> Self {
+        NameSegment::Index(index)
+    }
+}
+
+impl fmt::Display for NameSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameSegment::Key(key) => f.write_str(key),
+            NameSegment::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+/// Path of a field in the form values
+///
+/// Converting from a string splits it on `.`, with numeric segments becoming
+/// array indices, so `"users.0.name"` is the `name` of the first user.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NamePath(pub Vec<NameSegment>);
+
+impl NamePath {
+    /// Path with one more segment
+    pub fn join(&self, segment: impl Into<NameSegment>) -> Self {
+        let mut path = self.clone();
+        path.0.push(segment.into());
+        path
+    }
+
+    /// Path with all segments of `other` appended
+    pub fn concat(&self, other: &NamePath) -> Self {
+        let mut path = self.clone();
+        path.0.extend(other.0.iter().cloned());
+        path
+    }
+
+    /// Whether the path is `prefix` or below it
+    pub fn starts_with(&self, prefix: &NamePath) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// Whether the path has no segments
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Segments joined with `_`, usable in element ids
+    pub fn to_id(&self) -> String {
+        self.0
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+}
+
+impl From<&str> for NamePath {
+    fn from(path: &str) -> Self {
+        NamePath(
+            path.split('.')
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| match segment.parse::<usize>() {
+                    Ok(index) => NameSegment::Index(index),
+                    Err(_) => NameSegment::Key(segment.to_string()),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<String> for NamePath {
+    fn from(path: String) -> Self {
+        NamePath::from(path.as_str())
+    }
+}
+
+impl From<Vec<NameSegment>> for NamePath {
+    fn from(segments: Vec<NameSegment>) -> Self {
+        NamePath(segments)
+    }
+}
+
+impl fmt::Display for NamePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
+
+/// Value at `path`, `None` when any segment is missing
+pub fn get_value<'a>(values: &'a Value, path: &NamePath) -> Option<&'a Value> {
+    path.0
+        .iter()
+        .try_fold(values, |value, segment| match segment {
+            NameSegment::Key(key) => value.get(key.as_str()),
+            NameSegment::Index(index) => value.get(*index),
+        })
+}
+
+/// Set the value at `path`, creating missing objects and arrays on the way
+///
+/// Arrays are padded with `null` up to the index.
+pub fn set_value(values: &mut Value, path: &NamePath, value: Value) {
+    let mut current = values;
+    for segment in &path.0 {
+        current = match segment {
+            NameSegment::Key(key) => {
+                if !current.is_object() {
+                    *current = Value::Object(Map::new());
+                }
+                current
+                    .as_object_mut()
+                    .expect("just made an object")
+                    .entry(key.clone())
+                    .or_insert(Value::Null)
+            }
+            NameSegment::Index(index) => {
+                if !current.is_array() {
+                    *current = Value::Array(Vec::new());
+                }
+                let array = current.as_array_mut().expect("just made an array");
+                if array.len() <= *index {
+                    array.resize(index + 1, Value::Null);
+                }
+                &mut array[*index]
+            }
+        };
+    }
+    *current = value;
+}
+
+/// Deep merge `patch` into `values`, objects key by key
+pub fn merge_values(values: &mut Value, patch: Value) {
+    match (values, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge_values(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+/// Whether a value counts as empty for `required` rules
+///
+/// `null`, empty strings and empty arrays are empty.
+pub fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Replace the `${name}` variables of a message template
+///
+/// Unknown variables are left as they are.
+pub fn interpolate(template: &str, vars: &[(&str, String)]) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        message.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match vars.iter().find(|(var, _)| *var == name) {
+                    Some((_, value)) => message.push_str(value),
+                    None => message.push_str(&rest[start..start + end + 3]),
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                message.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    message.push_str(rest);
+    message
+}
+
+/// Expected type of a value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleType {
+    /// String
+    String,
+    /// Number
+    Number,
+    /// Boolean
+    Boolean,
+    /// Number without a fractional part
+    Integer,
+    /// Number with a fractional part
+    Float,
+    /// Array
+    Array,
+    /// Object
+    Object,
+    /// String that is an email address
+    Email,
+    /// String that is an http(s) or ftp URL
+    Url,
+}
+
+static EMAIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").expect("valid email pattern"));
+
+static URL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?i)(https?|ftp)://[^\s/$.?#][^\s]*$").expect("valid url pattern")
+});
+
+impl RuleType {
+    /// Name used in the `${type}` variable
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RuleType::String => "string",
+            RuleType::Number => "number",
+            RuleType::Boolean => "boolean",
+            RuleType::Integer => "integer",
+            RuleType::Float => "float",
+            RuleType::Array => "array",
+            RuleType::Object => "object",
+            RuleType::Email => "email",
+            RuleType::Url => "url",
+        }
+    }
+
+    /// Whether a value is of this type
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            RuleType::String => value.is_string(),
+            RuleType::Number => value.is_number(),
+            RuleType::Boolean => value.is_boolean(),
+            RuleType::Integer => value.as_f64().is_some_and(|number| number.fract() == 0.0),
+            RuleType::Float => value.as_f64().is_some_and(|number| number.fract() != 0.0),
+            RuleType::Array => value.is_array(),
+            RuleType::Object => value.is_object(),
+            RuleType::Email => value.as_str().is_some_and(|text| EMAIL.is_match(text)),
+            RuleType::Url => value.as_str().is_some_and(|text| URL.is_match(text)),
+        }
+    }
+
+    fn message<'a>(&self, messages: &'a ValidateMessages) -> &'a str {
+        let types = &messages.types;
+        match self {
+            RuleType::String => &types.string,
+            RuleType::Number => &types.number,
+            RuleType::Boolean => &types.boolean,
+            RuleType::Integer => &types.integer,
+            RuleType::Float => &types.float,
+            RuleType::Array => &types.array,
+            RuleType::Object => &types.object,
+            RuleType::Email => &types.email,
+            RuleType::Url => &types.url,
+        }
+    }
+}
+
+/// `Send` on native targets, where validation may run on a multi-threaded
+/// runtime, and nothing on wasm
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+
+/// `Send` on native targets, where validation may run on a multi-threaded
+/// runtime, and nothing on wasm
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// Future returned by a custom validator
+#[cfg(not(target_arch = "wasm32"))]
+pub type ValidatorFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Future returned by a custom validator
+#[cfg(target_arch = "wasm32")]
+pub type ValidatorFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// Custom validator, called with the field value and all form values
+///
+/// An `Err` message is shown as is, without template interpolation.
+pub type Validator = Arc<dyn Fn(Value, Value) -> ValidatorFuture + Send + Sync>;
+
+/// Event validating a field
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ValidateTrigger {
+    /// The value changed
+    #[default]
+    Change,
+    /// The control lost focus
+    Blur,
+}
+
+/// A validation rule of a field
+///
+/// Build rules with the constructors and combine them with the `with_*`
+/// methods, e.g. `Rule::min(3.0).with_message("Too short")`. Each field may
+/// have several rules; all of them are checked and every failure is reported.
+#[derive(Clone, Default)]
+pub struct Rule {
+    /// The value must not be empty
+    pub required: bool,
+    /// Expected value type
+    pub rule_type: Option<RuleType>,
+    /// Exact length of strings and arrays, or exact value of numbers
+    pub len: Option<f64>,
+    /// Minimum length of strings and arrays, or minimum value of numbers
+    pub min: Option<f64>,
+    /// Maximum length of strings and arrays, or maximum value of numbers
+    pub max: Option<f64>,
+    /// Pattern strings must match
+    pub pattern: Option<Regex>,
+    /// Allowed values
+    pub enumeration: Option<Vec<Value>>,
+    /// Treat whitespace-only strings as empty, used with `required`
+    pub whitespace: bool,
+    /// Message replacing the default one, may use template variables
+    pub message: Option<String>,
+    /// Custom validator
+    pub validator: Option<Validator>,
+    /// Report failures as warnings, which do not fail the validation
+    pub warning_only: bool,
+    /// Events running the rule, the field's own triggers when `None`
+    pub validate_trigger: Option<Vec<ValidateTrigger>>,
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rule")
+            .field("required", &self.required)
+            .field("rule_type", &self.rule_type)
+            .field("len", &self.len)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("pattern", &self.pattern)
+            .field("enumeration", &self.enumeration)
+            .field("whitespace", &self.whitespace)
+            .field("message", &self.message)
+            .field("validator", &self.validator.is_some())
+            .field("warning_only", &self.warning_only)
+            .field("validate_trigger", &self.validate_trigger)
+            .finish()
+    }
+}
+
+impl Rule {
+    /// The value must not be empty
+    pub fn required() -> Self {
+        Self {
+            required: true,
+            ..Default::default()
+        }
+    }
+
+    /// The value must be of `rule_type`
+    pub fn type_of(rule_type: RuleType) -> Self {
+        Self {
+            rule_type: Some(rule_type),
+            ..Default::default()
+        }
+    }
+
+    /// Exact length, or exact value of numbers
+    pub fn len(len: f64) -> Self {
+        Self {
+            len: Some(len),
+            ..Default::default()
+        }
+    }
+
+    /// Minimum length, or minimum value of numbers
+    pub fn min(min: f64) -> Self {
+        Self {
+            min: Some(min),
+            ..Default::default()
+        }
+    }
+
+    /// Maximum length, or maximum value of numbers
+    pub fn max(max: f64) -> Self {
+        Self {
+            max: Some(max),
+            ..Default::default()
+        }
+    }
+
+    /// Length, or value of numbers, between `min` and `max` inclusive
+    pub fn range(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            ..Default::default()
+        }
+    }
+
+    /// Strings must match `pattern`
+    ///
+    /// Returns `None` when the pattern is not a valid regular expression.
+    pub fn pattern(pattern: &str) -> Option<Self> {
+        Some(Self {
+            pattern: Some(Regex::new(pattern).ok()?),
+            ..Default::default()
+        })
+    }
+
+    /// The value must be one of `values`
+    pub fn one_of(values: impl IntoIterator<Item = Value>) -> Self {
+        Self {
+            enumeration: Some(values.into_iter().collect()),
+            ..Default::default()
+        }
+    }
+
+    /// Custom validator, called with the field value and all form values
+    pub fn validator<F, Fut>(validator: F) -> Self
+    where
+        F: Fn(Value, Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + MaybeSend + 'static,
+    {
+        Self {
+            validator: Some(Arc::new(move |value, values| {
+                Box::pin(validator(value, values)) as ValidatorFuture
+            })),
+            ..Default::default()
+        }
+    }
+
+    /// Also require the value to be non-empty
+    pub fn with_required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Also require the value to be of `rule_type`
+    pub fn with_type(mut self, rule_type: RuleType) -> Self {
+        self.rule_type = Some(rule_type);
+        self
+    }
+
+    /// Treat whitespace-only strings as empty
+    pub fn with_whitespace(mut self) -> Self {
+        self.whitespace = true;
+        self
+    }
+
+    /// Replace the default failure message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Report failures as warnings
+    pub fn with_warning_only(mut self) -> Self {
+        self.warning_only = true;
+        self
+    }
+
+    /// Run the rule only on `triggers`, and always on submit
+    pub fn with_trigger(mut self, triggers: impl IntoIterator<Item = ValidateTrigger>) -> Self {
+        self.validate_trigger = Some(triggers.into_iter().collect());
+        self
+    }
+
+    /// Whether `trigger` runs the rule, given the triggers of its field
+    pub fn is_triggered_by(&self, trigger: ValidateTrigger, field: &[ValidateTrigger]) -> bool {
+        self.validate_trigger
+            .as_deref()
+            .unwrap_or(field)
+            .contains(&trigger)
+    }
+
+    /// Check everything but the custom validator
+    ///
+    /// Returns the failure message, interpolated with `label` and the rule's
+    /// parameters.
+    pub fn check(&self, label: &str, value: &Value, messages: &ValidateMessages) -> Option<String> {
+        let template = self.check_template(value, messages)?;
+        let template = self.message.as_deref().unwrap_or(template);
+        Some(interpolate(template, &self.vars(label)))
+    }
+
+    fn check_template<'a>(&self, value: &Value, messages: &'a ValidateMessages) -> Option<&'a str> {
+        let blank = self.whitespace && value.as_str().is_some_and(|text| text.trim().is_empty());
+        if is_empty_value(value) || blank {
+            return if self.required {
+                Some(if blank {
+                    &messages.whitespace
+                } else {
+                    &messages.required
+                })
+            } else {
+                None
+            };
+        }
+
+        if let Some(rule_type) = self.rule_type {
+            if !rule_type.matches(value) {
+                return Some(rule_type.message(messages));
+            }
+        }
+
+        let measured = match value {
+            Value::String(text) => Some((text.chars().count() as f64, &messages.length)),
+            Value::Array(items) => Some((items.len() as f64, &messages.array)),
+            Value::Number(number) => number.as_f64().map(|number| (number, &messages.number)),
+            _ => None,
+        };
+        if let Some((size, length)) = measured {
+            if let Some(template) = self.check_size(size, length) {
+                return Some(template);
+            }
+        }
+
+        if let (Some(pattern), Some(text)) = (&self.pattern, value.as_str()) {
+            if !pattern.is_match(text) {
+                return Some(&messages.pattern);
+            }
+        }
+
+        if let Some(values) = &self.enumeration {
+            if !values.contains(value) {
+                return Some(&messages.enumeration);
+            }
+        }
+        None
+    }
+
+    fn check_size<'a>(&self, size: f64, messages: &'a ValidateLengthMessages) -> Option<&'a str> {
+        if let Some(len) = self.len {
+            return (size != len).then_some(messages.len.as_str());
+        }
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if size < min || size > max => Some(&messages.range),
+            (Some(min), None) if size < min => Some(&messages.min),
+            (None, Some(max)) if size > max => Some(&messages.max),
+            _ => None,
+        }
+    }
+
+    fn vars(&self, label: &str) -> Vec<(&'static str, String)> {
+        let number =
+            |number: Option<f64>| number.map(|number| number.to_string()).unwrap_or_default();
+        vec![
+            ("label", label.to_string()),
+            (
+                "type",
+                self.rule_type
+                    .map(|rule_type| rule_type.as_str().to_string())
+                    .unwrap_or_default(),
+            ),
+            ("len", number(self.len)),
+            ("min", number(self.min)),
+            ("max", number(self.max)),
+            (
+                "pattern",
+                self.pattern
+                    .as_ref()
+                    .map(|pattern| pattern.as_str().to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "enum",
+                self.enumeration
+                    .iter()
+                    .flatten()
+                    .map(|value| match value {
+                        Value::String(text) => text.clone(),
+                        value => value.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        ]
+    }
+}
+
+/// Failures of one field
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldError {
+    /// Field path
+    pub name: NamePath,
+    /// Failure messages, in rule order
+    pub errors: Vec<String>,
+    /// Failure messages of warning-only rules, in rule order
+    pub warnings: Vec<String>,
+}
+
+/// Failures of a value checked against its rules
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidateResult {
+    /// Failure messages, in rule order
+    pub errors: Vec<String>,
+    /// Failure messages of warning-only rules, in rule order
+    pub warnings: Vec<String>,
+}
+
+impl ValidateResult {
+    /// Whether no rule failed, warnings included
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty()
+    }
+}
+
+/// Check a value against all rules, returning every failure message
+///
+/// `values` are all form values, passed to custom validators. A rule's
+/// custom validator only runs when the rest of the rule passed.
+pub async fn validate_rules(
+    label: &str,
+    value: &Value,
+    values: &Value,
+    rules: &[Rule],
+    messages: &ValidateMessages,
+) -> ValidateResult {
+    let mut result = ValidateResult::default();
+    for rule in rules {
+        let failure = match rule.check(label, value, messages) {
+            Some(error) => Some(error),
+            None => match &rule.validator {
+                Some(validator) => validator(value.clone(), values.clone()).await.err(),
+                None => None,
+            },
+        };
+        if let Some(failure) = failure {
+            if rule.warning_only {
+                result.warnings.push(failure);
+            } else {
+                result.errors.push(failure);
+            }
+        }
+    }
+    result
+}
+
+/// Validation messages of a locale, following its fallback chain
+///
+/// Uses the built-in and registered locale packs, so it also works outside
+/// of a `LocaleProvider`, e.g. in server functions.
+pub fn locale_messages(tag: &str) -> ValidateMessages {
+    resolve_locale(tag).texts.form.validate_messages
+}
+
+/// Rules of one field of a [`Schema`]
+#[derive(Clone, Debug)]
+pub struct FieldRules {
+    /// Field path
+    pub name: NamePath,
+    /// Label used in messages, the path when `None`
+    pub label: Option<String>,
+    /// Rules of the field
+    pub rules: Vec<Rule>,
+}
+
+/// Rules of a set of fields, validated without a form
+///
+/// Declare the fields with [`Schema::field`] or [`Schema::labeled_field`],
+/// then check a whole value tree, e.g. the arguments of a server function,
+/// with [`Schema::validate`].
+#[derive(Clone, Debug, Default)]
+pub struct Schema {
+    /// Fields in validation order
+    pub fields: Vec<FieldRules>,
+    /// Message templates
+    pub messages: ValidateMessages,
+}
+
+impl Schema {
+    /// Empty schema with the default English messages
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field labeled by its path
+    pub fn field(mut self, name: impl Into<NamePath>, rules: Vec<Rule>) -> Self {
+        self.fields.push(FieldRules {
+            name: name.into(),
+            label: None,
+            rules,
+        });
+        self
+    }
+
+    /// Add a field with a label used in messages
+    pub fn labeled_field(
+        mut self,
+        name: impl Into<NamePath>,
+        label: impl Into<String>,
+        rules: Vec<Rule>,
+    ) -> Self {
+        self.fields.push(FieldRules {
+            name: name.into(),
+            label: Some(label.into()),
+            rules,
+        });
+        self
+    }
+
+    /// Use `messages` as message templates
+    pub fn with_messages(mut self, messages: ValidateMessages) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Use the message templates of a locale
+    pub fn with_locale(self, tag: &str) -> Self {
+        self.with_messages(locale_messages(tag))
+    }
+
+    /// Validate `values` against every rule
+    ///
+    /// Returns the fields with errors or warnings, in field order. The
+    /// values are valid when none of the returned fields has errors.
+    pub async fn validate(&self, values: &Value) -> Vec<FieldError> {
+        let mut failed = Vec::new();
+        for field in &self.fields {
+            let value = get_value(values, &field.name).unwrap_or(&Value::Null);
+            let label = field
+                .label
+                .clone()
+                .unwrap_or_else(|| field.name.to_string());
+            let result = validate_rules(&label, value, values, &field.rules, &self.messages).await;
+            if !result.is_empty() {
+                failed.push(FieldError {
+                    name: field.name.clone(),
+                    errors: result.errors,
+                    warnings: result.warnings,
+                });
+            }
+        }
+        failed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    fn check(rule: &Rule, label: &str, value: Value) -> Option<String> {
+        rule.check(label, &value, &ValidateMessages::default())
+    }
+
+    fn set(mut values: Value, path: &str, value: Value) -> Value {
+        set_value(&mut values, &NamePath::from(path), value);
+        values
+    }
+
+    #[test]
+    fn name_paths_parse_indices() {
+        let path = NamePath::from("users.0.name");
+        assert_eq!(
+            path.0,
+            vec![
+                NameSegment::from("users"),
+                NameSegment::from(0),
+                NameSegment::from("name")
+            ]
+        );
+        assert_eq!(path.to_string(), "users.0.name");
+        assert_eq!(path.to_id(), "users_0_name");
+        assert!(path.starts_with(&NamePath::from("users.0")));
+        assert!(!path.starts_with(&NamePath::from("users.1")));
+        assert_eq!(NamePath::from("a..b"), NamePath::from("a.b"));
+    }
+
+    #[test]
+    fn set_value_creates_missing_objects_and_arrays() {
+        assert_eq!(
+            set(Value::Null, "a.b", json!(1)),
+            json!({ "a": { "b": 1 } })
+        );
+        assert_eq!(
+            set(json!({}), "users.2.name", json!("x")),
+            json!({ "users": [null, null, { "name": "x" }] })
+        );
+        assert_eq!(
+            set(
+                json!({ "users": [{ "name": "a" }] }),
+                "users.1.name",
+                json!("b")
+            ),
+            json!({ "users": [{ "name": "a" }, { "name": "b" }] })
+        );
+        assert_eq!(
+            set(json!({ "a": { "b": 1, "c": 2 } }), "a.b", json!(3)),
+            json!({ "a": { "b": 3, "c": 2 } })
+        );
+        assert_eq!(set(json!(1), "", json!(2)), json!(2));
+    }
+
+    #[test]
+    fn set_value_replaces_values_of_the_wrong_shape() {
+        // 路径上的标量、数组与对象会被替换为路径需要的容器
+        assert_eq!(
+            set(json!({ "a": 5 }), "a.b", json!(1)),
+            json!({ "a": { "b": 1 } })
+        );
+        assert_eq!(
+            set(json!({ "a": [1] }), "a.b", json!(1)),
+            json!({ "a": { "b": 1 } })
+        );
+        assert_eq!(
+            set(json!({ "a": { "x": 1 } }), "a.0", json!(1)),
+            json!({ "a": [1] })
+        );
+        assert_eq!(set(json!("text"), "1", json!(true)), json!([null, true]));
+    }
+
+    #[test]
+    fn get_value_follows_keys_and_indices() {
+        let values = json!({ "users": [{ "name": "a" }] });
+        assert_eq!(
+            get_value(&values, &NamePath::from("users.0.name")),
+            Some(&json!("a"))
+        );
+        assert_eq!(get_value(&values, &NamePath::from("users.1.name")), None);
+        assert_eq!(get_value(&values, &NamePath::from("users.name")), None);
+        assert_eq!(get_value(&values, &NamePath::default()), Some(&values));
+    }
+
+    #[test]
+    fn merge_values_merges_objects_key_by_key() {
+        let mut values = json!({ "a": { "b": 1, "c": [1, 2] }, "d": 1 });
+        merge_values(&mut values, json!({ "a": { "c": [3] }, "e": null }));
+        assert_eq!(
+            values,
+            json!({ "a": { "b": 1, "c": [3] }, "d": 1, "e": null })
+        );
+    }
+
+    #[test]
+    fn interpolates_message_variables() {
+        let vars = [("label", "Name".to_string()), ("min", "3".to_string())];
+        assert_eq!(
+            interpolate("${label} needs ${min}, ${min}!", &vars),
+            "Name needs 3, 3!"
+        );
+        assert_eq!(interpolate("${label} ${unknown}", &vars), "Name ${unknown}");
+        assert_eq!(interpolate("${label} ${min", &vars), "Name ${min");
+        assert_eq!(interpolate("$label {min}", &vars), "$label {min}");
+    }
+
+    #[test]
+    fn each_rule_type_matches_its_values() {
+        let table = [
+            (RuleType::String, json!("a"), json!(1)),
+            (RuleType::Number, json!(1.5), json!("1")),
+            (RuleType::Boolean, json!(false), json!(0)),
+            (RuleType::Integer, json!(3), json!(3.5)),
+            (RuleType::Integer, json!(3.0), json!("3")),
+            (RuleType::Float, json!(3.5), json!(3)),
+            (RuleType::Array, json!([1]), json!({})),
+            (RuleType::Object, json!({ "a": 1 }), json!([])),
+            (RuleType::Email, json!("a.b@c.dev"), json!("a@b")),
+            (RuleType::Email, json!("a@b.co"), json!("a b@c.co")),
+            (
+                RuleType::Url,
+                json!("https://example.com/a?b"),
+                json!("mailto:a@b.co"),
+            ),
+            (
+                RuleType::Url,
+                json!("FTP://files.example.com"),
+                json!("http://"),
+            ),
+        ];
+        for (rule_type, valid, invalid) in table {
+            assert!(rule_type.matches(&valid), "{:?} {}", rule_type, valid);
+            assert!(!rule_type.matches(&invalid), "{:?} {}", rule_type, invalid);
+        }
+
+        let email = Rule::type_of(RuleType::Email);
+        assert_eq!(
+            check(&email, "Email", json!("nope")),
+            Some("Email is not a valid email".to_string())
+        );
+        // 非必填字段为空时跳过其它校验
+        assert_eq!(check(&email, "Email", json!("")), None);
+    }
+
+    #[test]
+    fn required_and_whitespace() {
+        let required = Rule::required();
+        for empty in [Value::Null, json!(""), json!([])] {
+            assert_eq!(
+                check(&required, "Name", empty),
+                Some("Name is required".to_string())
+            );
+        }
+        for filled in [json!(0), json!(false), json!({}), json!(" ")] {
+            assert_eq!(check(&required, "Name", filled), None);
+        }
+
+        let blank = Rule::required().with_whitespace();
+        assert_eq!(
+            check(&blank, "Name", json!(" \t")),
+            Some("Name cannot be empty".to_string())
+        );
+        assert_eq!(check(&blank, "Name", json!(" a ")), None);
+        assert_eq!(
+            check(&Rule::min(2.0).with_whitespace(), "Name", json!("  ")),
+            None
+        );
+    }
+
+    #[test]
+    fn sizes_measure_strings_numbers_and_arrays() {
+        let table = [
+            (
+                Rule::min(6.0),
+                json!("héllo"),
+                Some("Name must be at least 6 characters"),
+            ),
+            (Rule::len(5.0), json!("héllo"), None),
+            (
+                Rule::max(4.0),
+                json!("héllo"),
+                Some("Name cannot be longer than 4 characters"),
+            ),
+            (
+                Rule::range(1.0, 3.0),
+                json!("four"),
+                Some("Name must be between 1 and 3 characters"),
+            ),
+            (
+                Rule::len(3.0),
+                json!("ab"),
+                Some("Name must be exactly 3 characters"),
+            ),
+            (Rule::min(6.0), json!(5), Some("Name cannot be less than 6")),
+            (Rule::max(6.0), json!(6), None),
+            (
+                Rule::range(1.0, 3.0),
+                json!(3.5),
+                Some("Name must be between 1 and 3"),
+            ),
+            (Rule::len(2.5), json!(2), Some("Name must equal 2.5")),
+            (Rule::min(1.0), json!([]), None),
+            (Rule::max(1.0), json!([1, 2]), Some("At most 1 Name")),
+            (Rule::min(3.0), json!([1]), Some("At least 3 Name")),
+            (Rule::len(3.0), json!([1]), Some("Must be exactly 3 Name")),
+            (
+                Rule::range(1.0, 2.0),
+                json!([1, 2, 3]),
+                Some("The amount of Name must be between 1-2"),
+            ),
+            (Rule::min(3.0), json!(true), None),
+        ];
+        for (rule, value, expected) in table {
+            assert_eq!(
+                check(&rule, "Name", value.clone()),
+                expected.map(str::to_string),
+                "{:?} {}",
+                rule,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn pattern_and_enumeration_messages() {
+        let digits = Rule::pattern(r"^\d+$").unwrap();
+        assert_eq!(
+            check(&digits, "Code", json!("12a")),
+            Some(r"Code does not match pattern ^\d+$".to_string())
+        );
+        assert_eq!(check(&digits, "Code", json!(12)), None);
+        assert!(Rule::pattern("(").is_none());
+
+        let kind = Rule::one_of([json!("a"), json!(1)]);
+        assert_eq!(
+            check(&kind, "Kind", json!("b")),
+            Some("Kind must be one of [a, 1]".to_string())
+        );
+        assert_eq!(check(&kind, "Kind", json!(1)), None);
+    }
+
+    #[test]
+    fn custom_messages_use_the_rule_variables() {
+        let rule = Rule::range(2.0, 4.0)
+            .with_type(RuleType::String)
+            .with_message("${label}: ${type} of ${min} to ${max}, not ${len}");
+        assert_eq!(
+            check(&rule, "Nick", json!("a")),
+            Some("Nick: string of 2 to 4, not ".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_rules_reports_every_failure() {
+        let called = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = called.clone();
+        let rules = vec![
+            Rule::min(3.0),
+            Rule::pattern("^[a-z]+$").unwrap().with_warning_only(),
+            Rule::max(10.0).with_message("unused"),
+            Rule::validator(move |value, values| {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async move {
+                    if value == values["other"] {
+                        Err("Must differ".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }
+            }),
+            Rule::len(4.0).with_required(),
+        ];
+        let values = json!({ "name": "A1", "other": "A1" });
+        let result = block_on(validate_rules(
+            "Name",
+            &values["name"],
+            &values,
+            &rules,
+            &ValidateMessages::default(),
+        ));
+        assert_eq!(
+            result.errors,
+            [
+                "Name must be at least 3 characters",
+                "Must differ",
+                "Name must be exactly 4 characters"
+            ]
+        );
+        assert_eq!(result.warnings, [r"Name does not match pattern ^[a-z]+$"]);
+        assert_eq!(called.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn schema_validates_nested_fields() {
+        let schema = Schema::new()
+            .labeled_field("user.name", "Name", vec![Rule::required()])
+            .field("user.tags", vec![Rule::min(1.0)])
+            .field("user.age", vec![Rule::type_of(RuleType::Integer)]);
+        let failed = block_on(schema.validate(&json!({ "user": { "tags": [], "age": 1.5 } })));
+        assert_eq!(
+            failed,
+            [
+                FieldError {
+                    name: NamePath::from("user.name"),
+                    errors: vec!["Name is required".to_string()],
+                    warnings: Vec::new(),
+                },
+                FieldError {
+                    name: NamePath::from("user.age"),
+                    errors: vec!["user.age is not a valid integer".to_string()],
+                    warnings: Vec::new(),
+                },
+            ]
+        );
+    }
+}